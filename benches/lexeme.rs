@@ -1,10 +1,6 @@
 use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
-use opengloss_rs::{LexemeIndex, SearchConfig};
-use std::io::{Cursor, Read};
+use opengloss_rs::{LexemeIndex, Lexicon, RelationKind, SearchConfig};
 use std::sync::Once;
-use zstd::stream::Decoder as ZstdDecoder;
-
-static DATA_BYTES: &[u8] = include_bytes!(env!("OPENGLOSS_DATA"));
 
 fn ensure_loaded() {
     static ONCE: Once = Once::new();
@@ -18,17 +14,18 @@ fn ensure_loaded() {
 fn bench_cold_load(c: &mut Criterion) {
     c.bench_function("cold_load::decompress_blob", |b| {
         b.iter(|| {
-            let mut decoder = ZstdDecoder::new(Cursor::new(DATA_BYTES)).expect("cold-load decoder");
-            let mut buf = [0u8; 64 * 1024];
-            let mut total = 0usize;
-            loop {
-                let read = decoder.read(&mut buf).expect("stream read");
-                if read == 0 {
-                    break;
-                }
-                total += read;
-            }
-            black_box(total);
+            // Touch every section so the full store is inflated.
+            let lexicon = Lexicon::embedded();
+            let entry = lexicon.entry_by_word("dog").expect("entry present");
+            black_box(entry.senses().count());
+            black_box(entry.neighbor_ids(RelationKind::Synonym));
+            black_box(entry.text());
+        });
+    });
+    c.bench_function("cold_load::entries_section", |b| {
+        b.iter(|| {
+            let lexicon = Lexicon::embedded();
+            black_box(lexicon.entry_by_id(0).map(|entry| entry.lexeme_id()));
         });
     });
 }
@@ -105,12 +102,46 @@ fn bench_fuzzy_search(c: &mut Criterion) {
     }
 }
 
+/// Compares candidate pre-filtering against scoring every entry. Caches are cleared on each
+/// iteration so both paths do the full work.
+fn bench_fuzzy_candidates(c: &mut Criterion) {
+    ensure_loaded();
+    let lexicon = LexemeIndex::lexicon();
+    const QUERIES: &[&str] = &["algorithm", "photosynthesis", "gravitation"];
+    let paths = [
+        ("prefiltered", SearchConfig::default()),
+        (
+            "exhaustive",
+            SearchConfig {
+                candidate_budget: 0,
+                ..SearchConfig::default()
+            },
+        ),
+    ];
+    for (path, config) in &paths {
+        for &query in QUERIES {
+            c.bench_with_input(
+                BenchmarkId::new(format!("search_fuzzy_{path}"), query),
+                &query,
+                |b, &query| {
+                    b.iter(|| {
+                        lexicon.clear_caches();
+                        let hits = lexicon.search_fuzzy_with_stats(query, config, 10);
+                        black_box(hits.results.len());
+                    });
+                },
+            );
+        }
+    }
+}
+
 criterion_group!(
     benches,
     bench_cold_load,
     bench_entry_queries,
     bench_prefix_queries,
     bench_substring_search,
-    bench_fuzzy_search
+    bench_fuzzy_search,
+    bench_fuzzy_candidates
 );
 criterion_main!(benches);
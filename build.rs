@@ -9,13 +9,21 @@ use std::path::{Path, PathBuf};
 use fst::MapBuilder;
 use rkyv::{rancor::Error as RkyvError, to_bytes};
 use serde::Deserialize;
-use zstd::bulk::compress as zstd_compress;
+use zstd::bulk::{Compressor, compress as zstd_compress};
+use zstd::zstd_safe::CParameter;
 
 #[path = "src/data.rs"]
 mod data_model;
+#[path = "src/fulltext.rs"]
+mod fulltext;
+#[path = "src/normalize.rs"]
+mod normalize;
+#[path = "src/trigram.rs"]
+mod trigram;
 use data_model::{
-    CompressedTextStore, DataStore, EntryRecord, PackedStrings, Range, SenseRecord, StringId,
-    TextId,
+    CompressedTextStore, EntryRecord, EntrySection, FLAG_COMPRESSED, PackedStrings, Range,
+    RelationSection, SECTION_COUNT, SenseRecord, SenseSection, StoreHeader, StringId, TextId,
+    TextSection,
 };
 
 const STORE_ENTRY_TEXT: bool = true;
@@ -25,19 +33,70 @@ const STORE_ENCYCLOPEDIA_TEXT: bool = true;
 const ARCHIVE_COMPRESSION_LEVEL: i32 = 4;
 const LONG_TEXT_COMPRESSION_LEVEL: i32 = 5;
 const STRING_COMPRESSION_LEVEL: i32 = 5;
+// Shared zstd dictionaries for the per-item frames. Short strings benefit the most: on their own,
+// 10-50 byte frames are usually larger than the input.
+const STRING_DICTIONARY_SIZE: usize = 112 * 1024;
+const LONG_TEXT_DICTIONARY_SIZE: usize = 256 * 1024;
+/// Upper bound on the sample corpus handed to the dictionary trainer (samples are strided).
+const DICTIONARY_SAMPLE_BYTES: usize = 64 * 1024 * 1024;
+const COMPRESSION_REPORT_FILE: &str = "compression-report.txt";
+/// When set, the generated FSTs (`lexemes*.fst`), trigram and full-text indexes, and
+/// `opengloss_data.rkyv` are also copied into this directory.
+const ARTIFACT_DIR_ENV: &str = "OPENGLOSS_ARTIFACT_DIR";
 
 fn main() -> Result<(), Box<dyn Error>> {
+    println!("cargo:rerun-if-env-changed={ARTIFACT_DIR_ENV}");
+    let artifact_dir = env::var_os(ARTIFACT_DIR_ENV).map(PathBuf::from);
+    let embed = env::var_os("CARGO_FEATURE_EMBEDDED_DATA").is_some();
+    if !embed && artifact_dir.is_none() {
+        // Nothing to embed or export; the library loads its data at runtime.
+        return Ok(());
+    }
+
     let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR")?);
     let out_dir = PathBuf::from(env::var("OUT_DIR")?);
 
     let lexeme_rows = load_lexemes(&manifest_dir)?;
-    build_fst(&lexeme_rows, &out_dir)?;
+    let (fst_path, reversed_fst_path) = build_fst(&lexeme_rows, &out_dir)?;
+    let normalized_fst_path = build_normalized_fst(&lexeme_rows, &out_dir)?;
+    let trigram_path = build_trigram_index(&lexeme_rows, &out_dir)?;
     let lexeme_lookup: HashMap<String, u32> = lexeme_rows
         .iter()
         .map(|(word, id)| (word.clone(), *id))
         .collect();
-    build_data_store(&manifest_dir, &out_dir, lexeme_rows.len(), lexeme_lookup)?;
+    let (data_path, fulltext_path, inflections_path) =
+        build_data_store(&manifest_dir, &out_dir, lexeme_rows.len(), lexeme_lookup)?;
+
+    if let Some(dir) = artifact_dir {
+        let report_path = out_dir.join(COMPRESSION_REPORT_FILE);
+        export_artifacts(
+            &dir,
+            &[
+                &fst_path,
+                &normalized_fst_path,
+                &reversed_fst_path,
+                &inflections_path,
+                &trigram_path,
+                &data_path,
+                &fulltext_path,
+                &report_path,
+            ],
+        )?;
+    }
+
+    Ok(())
+}
 
+/// Copies the generated artifacts into `dir` so they can ship next to a binary built without
+/// the `embedded-data` feature.
+fn export_artifacts(dir: &Path, artifacts: &[&Path]) -> Result<(), Box<dyn Error>> {
+    fs::create_dir_all(dir)?;
+    for artifact in artifacts {
+        let name = artifact
+            .file_name()
+            .ok_or_else(|| format!("Artifact {} has no file name", artifact.display()))?;
+        fs::copy(artifact, dir.join(name))?;
+    }
     Ok(())
 }
 
@@ -77,7 +136,9 @@ fn load_lexemes(manifest_dir: &Path) -> Result<Vec<(String, u32)>, Box<dyn Error
     Ok(rows)
 }
 
-fn build_fst(rows: &[(String, u32)], out_dir: &Path) -> Result<(), Box<dyn Error>> {
+/// Builds `lexemes.fst` plus `lexemes_reversed.fst`, whose keys are the words spelled backwards so
+/// suffix queries become prefix scans.
+fn build_fst(rows: &[(String, u32)], out_dir: &Path) -> Result<(PathBuf, PathBuf), Box<dyn Error>> {
     let mut sorted = rows.to_vec();
     sorted.sort_by(|a, b| match a.0.as_str().cmp(b.0.as_str()) {
         Ordering::Equal => a.1.cmp(&b.1),
@@ -97,7 +158,38 @@ fn build_fst(rows: &[(String, u32)], out_dir: &Path) -> Result<(), Box<dyn Error
     }
     builder.finish()?;
     println!("cargo:rustc-env=LEXEME_FST={}", fst_path.display());
-    Ok(())
+
+    let reversed =
+        normalize::build_reversed_fst(sorted.iter().map(|(word, id)| (word.as_str(), *id)))?;
+    let reversed_path = out_dir.join("lexemes_reversed.fst");
+    fs::write(&reversed_path, reversed)?;
+    println!(
+        "cargo:rustc-env=LEXEME_REVERSED_FST={}",
+        reversed_path.display()
+    );
+    Ok((fst_path, reversed_path))
+}
+
+/// Builds the case-, accent-, and punctuation-insensitive lookup FST (see `src/normalize.rs`).
+fn build_normalized_fst(rows: &[(String, u32)], out_dir: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    let bytes =
+        normalize::build_normalized_fst(rows.iter().map(|(word, id)| (word.as_str(), *id)))?;
+    let fst_path = out_dir.join("lexemes_normalized.fst");
+    fs::write(&fst_path, bytes)?;
+    println!(
+        "cargo:rustc-env=LEXEME_NORMALIZED_FST={}",
+        fst_path.display()
+    );
+    Ok(fst_path)
+}
+
+/// Builds the trigram postings used to narrow substring searches (see `src/trigram.rs`).
+fn build_trigram_index(rows: &[(String, u32)], out_dir: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    let bytes = trigram::build_trigram_index(rows.iter().map(|(word, id)| (word.as_str(), *id)));
+    let index_path = out_dir.join("lexemes_trigrams.idx");
+    fs::write(&index_path, bytes)?;
+    println!("cargo:rustc-env=LEXEME_TRIGRAMS={}", index_path.display());
+    Ok(index_path)
 }
 
 fn build_data_store(
@@ -105,7 +197,7 @@ fn build_data_store(
     out_dir: &Path,
     expected_entries: usize,
     lexeme_lookup: HashMap<String, u32>,
-) -> Result<(), Box<dyn Error>> {
+) -> Result<(PathBuf, PathBuf, PathBuf), Box<dyn Error>> {
     let entries_path = manifest_dir.join("data/entries.jsonl");
     println!("cargo:rerun-if-changed={}", entries_path.display());
     if !entries_path.exists() {
@@ -128,15 +220,113 @@ fn build_data_store(
     }
 
     let store = builder.finish(expected_entries)?;
-    let bytes = to_bytes::<RkyvError>(&store)
-        .map_err(|err| format!("Failed to serialize data store: {err}"))?
-        .into_vec();
-    let compressed = zstd_compress(&bytes, ARCHIVE_COMPRESSION_LEVEL)
-        .expect("compress archived data store with zstd");
+    // Each section is serialized and compressed on its own so the runtime can inflate entries
+    // without paying for senses, relations, or long-form prose it never touches.
+    let sections = [
+        compress_section(&store.entries, "entries")?,
+        compress_section(&store.senses, "senses")?,
+        compress_section(&store.relations, "relations")?,
+        compress_section(&store.texts, "texts")?,
+    ];
+    let section_refs: [&[u8]; SECTION_COUNT] =
+        [&sections[0], &sections[1], &sections[2], &sections[3]];
 
     let data_path = out_dir.join("opengloss_data.rkyv");
-    fs::write(&data_path, compressed)?;
+    fs::write(
+        &data_path,
+        StoreHeader::encode(FLAG_COMPRESSED, section_refs),
+    )?;
+    write_compression_report(&out_dir.join(COMPRESSION_REPORT_FILE), &store.reports)?;
     println!("cargo:rustc-env=OPENGLOSS_DATA={}", data_path.display());
+
+    let fulltext_path = out_dir.join("opengloss_fulltext.idx");
+    fs::write(&fulltext_path, &store.fulltext)?;
+    println!(
+        "cargo:rustc-env=OPENGLOSS_FULLTEXT={}",
+        fulltext_path.display()
+    );
+
+    let inflections_path = out_dir.join("lexemes_inflections.fst");
+    fs::write(&inflections_path, &store.inflections)?;
+    println!(
+        "cargo:rustc-env=LEXEME_INFLECTIONS_FST={}",
+        inflections_path.display()
+    );
+    Ok((data_path, fulltext_path, inflections_path))
+}
+
+fn compress_section<T>(section: &T, name: &str) -> Result<Vec<u8>, Box<dyn Error>>
+where
+    T: for<'a> rkyv::Serialize<
+            rkyv::api::high::HighSerializer<
+                rkyv::util::AlignedVec,
+                rkyv::ser::allocator::ArenaHandle<'a>,
+                RkyvError,
+            >,
+        >,
+{
+    let bytes = to_bytes::<RkyvError>(section)
+        .map_err(|err| format!("Failed to serialize {name} section: {err}"))?;
+    let compressed = zstd_compress(&bytes, ARCHIVE_COMPRESSION_LEVEL)
+        .map_err(|err| format!("Failed to compress {name} section: {err}"))?;
+    Ok(compressed)
+}
+
+/// All sections of the data store before serialization.
+struct StoreSections {
+    entries: EntrySection,
+    senses: SenseSection,
+    relations: RelationSection,
+    texts: TextSection,
+    reports: [ArenaReport; 2],
+    /// Serialized BM25 inverted index (see `src/fulltext.rs`).
+    fulltext: Vec<u8>,
+    /// Inflected form -> lexeme FST (see `normalize::build_inflection_fst`).
+    inflections: Vec<u8>,
+}
+
+/// Before/after sizes of a frame arena, recorded for `compression-report.txt`.
+struct ArenaReport {
+    name: &'static str,
+    items: usize,
+    raw_bytes: usize,
+    plain_frame_bytes: usize,
+    dictionary_bytes: usize,
+    dictionary_frame_bytes: usize,
+    uses_dictionary: bool,
+}
+
+impl ArenaReport {
+    fn dictionary_total(&self) -> usize {
+        self.dictionary_bytes + self.dictionary_frame_bytes
+    }
+}
+
+/// Writes a tab-separated summary of each arena's size before (standalone frames) and after
+/// (dictionary + frames) dictionary compression.
+fn write_compression_report(path: &Path, reports: &[ArenaReport]) -> Result<(), Box<dyn Error>> {
+    let mut out = String::from(
+        "arena\titems\traw_bytes\tplain_frames\tdictionary\t\
+         dictionary_frames\tdictionary_total\tchosen\n",
+    );
+    for report in reports {
+        out.push_str(&format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+            report.name,
+            report.items,
+            report.raw_bytes,
+            report.plain_frame_bytes,
+            report.dictionary_bytes,
+            report.dictionary_frame_bytes,
+            report.dictionary_total(),
+            if report.uses_dictionary {
+                "dictionary"
+            } else {
+                "plain"
+            },
+        ));
+    }
+    fs::write(path, out)?;
     Ok(())
 }
 
@@ -223,7 +413,14 @@ struct DataBuilder {
     entry_antonym_neighbors: Vec<u32>,
     entry_hypernym_neighbors: Vec<u32>,
     entry_hyponym_neighbors: Vec<u32>,
+    sense_synonym_neighbors: Vec<u32>,
+    sense_antonym_neighbors: Vec<u32>,
+    sense_hypernym_neighbors: Vec<u32>,
+    sense_hyponym_neighbors: Vec<u32>,
     lexeme_lookup: HashMap<String, u32>,
+    fulltext: fulltext::FullTextBuilder,
+    /// `(inflected_form, lexeme_id, inflection_index)` rows for the inflection FST.
+    inflections: Vec<(String, u32, u32)>,
 }
 
 impl DataBuilder {
@@ -253,7 +450,13 @@ impl DataBuilder {
             entry_antonym_neighbors: Vec::new(),
             entry_hypernym_neighbors: Vec::new(),
             entry_hyponym_neighbors: Vec::new(),
+            sense_synonym_neighbors: Vec::new(),
+            sense_antonym_neighbors: Vec::new(),
+            sense_hypernym_neighbors: Vec::new(),
+            sense_hyponym_neighbors: Vec::new(),
             lexeme_lookup,
+            fulltext: fulltext::FullTextBuilder::default(),
+            inflections: Vec::new(),
         }
     }
 
@@ -267,6 +470,14 @@ impl DataBuilder {
             .into());
         }
 
+        self.index_text(&entry);
+        self.inflections.extend(
+            entry
+                .all_inflections
+                .iter()
+                .enumerate()
+                .map(|(idx, form)| (form.clone(), entry.lexeme_id, idx as u32)),
+        );
         let word_id = self.strings.intern_owned(entry.word);
         let entry_id = self.strings.intern_owned(entry.entry_id);
         let text_id = if STORE_ENTRY_TEXT {
@@ -394,6 +605,47 @@ impl DataBuilder {
         Ok(())
     }
 
+    /// Feeds the searchable fields of `entry` into the full-text index.
+    fn index_text(&mut self, entry: &EntryJson) {
+        let id = entry.lexeme_id;
+        let index = &mut self.fulltext;
+        index.add(id, fulltext::Field::Word, &entry.word);
+        for definition in &entry.all_definitions {
+            index.add(id, fulltext::Field::Definitions, definition);
+        }
+        for synonym in &entry.all_synonyms {
+            index.add(id, fulltext::Field::Synonyms, synonym);
+        }
+        for example in &entry.all_examples {
+            index.add(id, fulltext::Field::Examples, example);
+        }
+        if let Some(text) = entry.text.as_deref().filter(|_| STORE_ENTRY_TEXT) {
+            index.add(id, fulltext::Field::Text, text);
+        }
+        if let Some(article) = entry
+            .encyclopedia_entry
+            .as_deref()
+            .filter(|_| STORE_ENCYCLOPEDIA_TEXT)
+        {
+            index.add(id, fulltext::Field::Encyclopedia, article);
+        }
+        let lists = [
+            (fulltext::Field::Collocations, &entry.all_collocations),
+            (fulltext::Field::Inflections, &entry.all_inflections),
+            (fulltext::Field::Derivations, &entry.all_derivations),
+            (fulltext::Field::Antonyms, &entry.all_antonyms),
+            (fulltext::Field::Hypernyms, &entry.all_hypernyms),
+        ];
+        for (field, values) in lists {
+            for value in values {
+                index.add(id, field, value);
+            }
+        }
+        if let Some(summary) = entry.etymology_summary.as_deref() {
+            index.add(id, fulltext::Field::Etymology, summary);
+        }
+    }
+
     fn push_senses(&mut self, lexeme_id: u32, senses: Vec<SenseJson>) -> Range {
         let start = self.senses.len() as u32;
         for sense in senses {
@@ -401,6 +653,26 @@ impl DataBuilder {
                 .part_of_speech
                 .map(|pos| self.strings.intern_owned(pos));
             let definition = sense.definition.map(|def| self.strings.intern_owned(def));
+            let synonym_neighbors = push_neighbor_refs(
+                &self.lexeme_lookup,
+                &mut self.sense_synonym_neighbors,
+                sense.synonyms.iter(),
+            );
+            let antonym_neighbors = push_neighbor_refs(
+                &self.lexeme_lookup,
+                &mut self.sense_antonym_neighbors,
+                sense.antonyms.iter(),
+            );
+            let hypernym_neighbors = push_neighbor_refs(
+                &self.lexeme_lookup,
+                &mut self.sense_hypernym_neighbors,
+                sense.hypernyms.iter(),
+            );
+            let hyponym_neighbors = push_neighbor_refs(
+                &self.lexeme_lookup,
+                &mut self.sense_hyponym_neighbors,
+                sense.hyponyms.iter(),
+            );
             let synonyms = push_strings(
                 &mut self.strings,
                 &mut self.sense_synonyms,
@@ -437,12 +709,16 @@ impl DataBuilder {
                 hypernyms,
                 hyponyms,
                 examples,
+                synonym_neighbors,
+                antonym_neighbors,
+                hypernym_neighbors,
+                hyponym_neighbors,
             });
         }
         Range::new(start, self.senses.len() as u32 - start)
     }
 
-    fn finish(self, expected_entries: usize) -> Result<DataStore, Box<dyn Error>> {
+    fn finish(self, expected_entries: usize) -> Result<StoreSections, Box<dyn Error>> {
         if self.entries.len() != expected_entries {
             return Err(format!(
                 "Expected {expected_entries} entries, but found {}",
@@ -450,31 +726,52 @@ impl DataBuilder {
             )
             .into());
         }
-        Ok(DataStore {
-            strings: self.strings.into_store(),
-            long_texts: self.long_texts.into_store(),
-            entries: self.entries,
-            entry_parts_of_speech: self.entry_parts_of_speech,
-            senses: self.senses,
-            sense_synonyms: self.sense_synonyms,
-            sense_antonyms: self.sense_antonyms,
-            sense_hypernyms: self.sense_hypernyms,
-            sense_hyponyms: self.sense_hyponyms,
-            sense_examples: self.sense_examples,
-            entry_all_definitions: self.entry_all_definitions,
-            entry_all_synonyms: self.entry_all_synonyms,
-            entry_all_antonyms: self.entry_all_antonyms,
-            entry_all_hypernyms: self.entry_all_hypernyms,
-            entry_all_hyponyms: self.entry_all_hyponyms,
-            entry_all_collocations: self.entry_all_collocations,
-            entry_all_inflections: self.entry_all_inflections,
-            entry_all_derivations: self.entry_all_derivations,
-            entry_all_examples: self.entry_all_examples,
-            entry_etymology_cognates: self.entry_etymology_cognates,
-            entry_synonym_neighbors: self.entry_synonym_neighbors,
-            entry_antonym_neighbors: self.entry_antonym_neighbors,
-            entry_hypernym_neighbors: self.entry_hypernym_neighbors,
-            entry_hyponym_neighbors: self.entry_hyponym_neighbors,
+        let fulltext = self.fulltext.finish(expected_entries)?;
+        let inflections = normalize::build_inflection_fst(
+            self.inflections
+                .iter()
+                .map(|(form, id, idx)| (form.as_str(), *id, *idx)),
+        )?;
+        let (strings, strings_report) = self.strings.into_store();
+        let (long_texts, long_texts_report) = self.long_texts.into_store();
+        Ok(StoreSections {
+            entries: EntrySection {
+                strings,
+                entries: self.entries,
+                entry_parts_of_speech: self.entry_parts_of_speech,
+                entry_all_definitions: self.entry_all_definitions,
+                entry_all_synonyms: self.entry_all_synonyms,
+                entry_all_antonyms: self.entry_all_antonyms,
+                entry_all_hypernyms: self.entry_all_hypernyms,
+                entry_all_hyponyms: self.entry_all_hyponyms,
+                entry_all_collocations: self.entry_all_collocations,
+                entry_all_inflections: self.entry_all_inflections,
+                entry_all_derivations: self.entry_all_derivations,
+                entry_all_examples: self.entry_all_examples,
+                entry_etymology_cognates: self.entry_etymology_cognates,
+            },
+            senses: SenseSection {
+                senses: self.senses,
+                sense_synonyms: self.sense_synonyms,
+                sense_antonyms: self.sense_antonyms,
+                sense_hypernyms: self.sense_hypernyms,
+                sense_hyponyms: self.sense_hyponyms,
+                sense_examples: self.sense_examples,
+            },
+            relations: RelationSection {
+                entry_synonym_neighbors: self.entry_synonym_neighbors,
+                entry_antonym_neighbors: self.entry_antonym_neighbors,
+                entry_hypernym_neighbors: self.entry_hypernym_neighbors,
+                entry_hyponym_neighbors: self.entry_hyponym_neighbors,
+                sense_synonym_neighbors: self.sense_synonym_neighbors,
+                sense_antonym_neighbors: self.sense_antonym_neighbors,
+                sense_hypernym_neighbors: self.sense_hypernym_neighbors,
+                sense_hyponym_neighbors: self.sense_hyponym_neighbors,
+            },
+            texts: TextSection { long_texts },
+            reports: [strings_report, long_texts_report],
+            fulltext,
+            inflections,
         })
     }
 }
@@ -482,9 +779,7 @@ impl DataBuilder {
 #[derive(Default)]
 struct StringTable {
     map: HashMap<Box<str>, StringId>,
-    offsets: Vec<u32>,
-    lengths: Vec<u32>,
-    data: Vec<u8>,
+    values: Vec<String>,
 }
 
 impl StringTable {
@@ -492,13 +787,9 @@ impl StringTable {
         if let Some(&id) = self.map.get(value.as_str()) {
             return id;
         }
-        let id = self.offsets.len() as u32;
-        let compressed = zstd_compress(value.as_bytes(), STRING_COMPRESSION_LEVEL)
-            .expect("compress short string with zstd");
-        self.offsets.push(self.data.len() as u32);
-        self.lengths.push(compressed.len() as u32);
-        self.data.extend_from_slice(&compressed);
-        self.map.insert(value.into_boxed_str(), id);
+        let id = self.values.len() as u32;
+        self.map.insert(value.clone().into_boxed_str(), id);
+        self.values.push(value);
         id
     }
 
@@ -506,21 +797,27 @@ impl StringTable {
         value.map(|v| self.intern_owned(v))
     }
 
-    fn into_store(self) -> PackedStrings {
-        PackedStrings {
-            offsets: self.offsets,
-            lengths: self.lengths,
-            data: self.data,
-        }
+    fn into_store(self) -> (PackedStrings, ArenaReport) {
+        let (arena, report) = compress_arena(
+            "strings",
+            &self.values,
+            STRING_COMPRESSION_LEVEL,
+            STRING_DICTIONARY_SIZE,
+        );
+        let store = PackedStrings {
+            dictionary: arena.dictionary,
+            offsets: arena.offsets,
+            lengths: arena.lengths,
+            data: arena.data,
+        };
+        (store, report)
     }
 }
 
 #[derive(Default)]
 struct CompressedTextTable {
     map: HashMap<Box<str>, TextId>,
-    offsets: Vec<u32>,
-    lengths: Vec<u32>,
-    data: Vec<u8>,
+    values: Vec<String>,
 }
 
 impl CompressedTextTable {
@@ -528,25 +825,110 @@ impl CompressedTextTable {
         if let Some(&id) = self.map.get(value.as_str()) {
             return id;
         }
-        let compressed = zstd_compress(value.as_bytes(), LONG_TEXT_COMPRESSION_LEVEL)
-            .expect("compress long-form text with zstd");
-        let id = self.offsets.len() as u32;
-        self.offsets.push(self.data.len() as u32);
-        self.lengths.push(compressed.len() as u32);
-        self.data.extend_from_slice(&compressed);
-        self.map.insert(value.into_boxed_str(), id);
+        let id = self.values.len() as u32;
+        self.map.insert(value.clone().into_boxed_str(), id);
+        self.values.push(value);
         id
     }
 
-    fn into_store(self) -> CompressedTextStore {
-        CompressedTextStore {
-            offsets: self.offsets,
-            lengths: self.lengths,
-            data: self.data,
+    fn into_store(self) -> (CompressedTextStore, ArenaReport) {
+        let (arena, report) = compress_arena(
+            "long_texts",
+            &self.values,
+            LONG_TEXT_COMPRESSION_LEVEL,
+            LONG_TEXT_DICTIONARY_SIZE,
+        );
+        let store = CompressedTextStore {
+            dictionary: arena.dictionary,
+            offsets: arena.offsets,
+            lengths: arena.lengths,
+            data: arena.data,
+        };
+        (store, report)
+    }
+}
+
+/// One zstd frame per item, optionally sharing a dictionary.
+#[derive(Default)]
+struct FrameArena {
+    dictionary: Vec<u8>,
+    offsets: Vec<u32>,
+    lengths: Vec<u32>,
+    data: Vec<u8>,
+}
+
+impl FrameArena {
+    fn push(&mut self, frame: &[u8]) {
+        self.offsets.push(self.data.len() as u32);
+        self.lengths.push(frame.len() as u32);
+        self.data.extend_from_slice(frame);
+    }
+}
+
+/// Compresses every value as its own frame, both standalone and against a dictionary trained on
+/// the values, and keeps whichever arena is smaller overall (dictionary included).
+fn compress_arena(
+    name: &'static str,
+    values: &[String],
+    level: i32,
+    dictionary_size: usize,
+) -> (FrameArena, ArenaReport) {
+    let mut plain = FrameArena::default();
+    for value in values {
+        let frame = zstd_compress(value.as_bytes(), level).expect("compress frame with zstd");
+        plain.push(&frame);
+    }
+
+    let mut with_dictionary = FrameArena::default();
+    if let Some(dictionary) = train_dictionary(values, dictionary_size) {
+        let mut compressor =
+            Compressor::with_dictionary(level, &dictionary).expect("load zstd dictionary");
+        // Frames are always decoded with the arena's dictionary, so the ID is redundant.
+        compressor
+            .set_parameter(CParameter::DictIdFlag(false))
+            .expect("disable zstd dictionary id");
+        for value in values {
+            let frame = compressor
+                .compress(value.as_bytes())
+                .expect("compress frame with zstd dictionary");
+            with_dictionary.push(&frame);
         }
+        with_dictionary.dictionary = dictionary;
+    }
+
+    let mut report = ArenaReport {
+        name,
+        items: values.len(),
+        raw_bytes: values.iter().map(String::len).sum(),
+        plain_frame_bytes: plain.data.len(),
+        dictionary_bytes: with_dictionary.dictionary.len(),
+        dictionary_frame_bytes: with_dictionary.data.len(),
+        uses_dictionary: false,
+    };
+    if !with_dictionary.dictionary.is_empty()
+        && report.dictionary_total() < report.plain_frame_bytes
+    {
+        report.uses_dictionary = true;
+        (with_dictionary, report)
+    } else {
+        (plain, report)
     }
 }
 
+/// Trains a zstd dictionary on (a strided sample of) `values`; `None` when training fails, e.g.
+/// because the corpus is too small.
+fn train_dictionary(values: &[String], max_size: usize) -> Option<Vec<u8>> {
+    let total: usize = values.iter().map(String::len).sum();
+    let stride = total.div_ceil(DICTIONARY_SAMPLE_BYTES).max(1);
+    let samples: Vec<&[u8]> = values
+        .iter()
+        .step_by(stride)
+        .map(|value| value.as_bytes())
+        .filter(|sample| !sample.is_empty())
+        .collect();
+    zstd::dict::from_samples(&samples, max_size).ok()
+}
+
 fn push_strings<I>(table: &mut StringTable, target: &mut Vec<StringId>, iter: I) -> Range
 where
     I: IntoIterator<Item = String>,
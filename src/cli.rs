@@ -2,23 +2,24 @@ use std::cmp;
 use std::collections::HashMap;
 use std::error::Error;
 use std::io;
+use std::path::PathBuf;
 
 use atty::Stream;
-#[cfg(feature = "web")]
-use clap::Args;
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 #[cfg(feature = "web")]
 use opengloss_rs::web::{self, WebConfig, WebTheme};
 use opengloss_rs::{
-    FieldContribution, GraphOptions, GraphTraversal, LexemeIndex, RelationKind, SearchBreakdown,
-    SearchSummary,
+    DEFAULT_CANDIDATE_BUDGET, DEFAULT_SUGGEST_EDITS, FieldContribution, FieldKind, GraphEdge,
+    GraphOptions, GraphTraversal, InflectionMatch, LexemeIndex, PathOptions, PatternQuery,
+    PatternSyntax, Query, RelationKind, ReverseMatch, SearchBreakdown, SearchCursor, SearchFacets,
+    SearchFilters, SearchSummary, SenseId, SenseRef, Snippet, Suggestion,
 };
 use rayon::ThreadPoolBuilder;
 use serde_json::json;
 #[cfg(feature = "web")]
-use std::sync::OnceLock;
+use std::net::SocketAddr;
 #[cfg(feature = "web")]
-use std::{net::SocketAddr, path::PathBuf};
+use std::sync::OnceLock;
 use termimad::{FmtText, MadSkin, terminal_size};
 #[cfg(feature = "web")]
 use tokio::runtime::Builder as TokioRuntimeBuilder;
@@ -34,16 +35,25 @@ pub struct Cli {
     /// Limit the Rayon worker pool (defaults to logical CPU count).
     #[arg(long, global = true, value_name = "N")]
     rayon_threads: Option<usize>,
+    /// Load `lexemes.fst` + `opengloss_data.{mmap,rkyv}` from this directory instead of the
+    /// embedded data.
+    #[arg(long, global = true, value_name = "DIR")]
+    data_dir: Option<PathBuf>,
 
     #[command(subcommand)]
     command: Command,
 }
 
+// Parsed once per process, so the size gap to `Data` (without `Serve`) does not matter.
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand, Debug)]
 enum Command {
     /// Operations related to lexemes.
     #[command(subcommand)]
     Lexeme(LexemeCommand),
+    /// Manage the on-disk data artifacts.
+    #[command(subcommand)]
+    Data(DataCommand),
     /// Run the embedded web server (requires the `web` feature).
     #[cfg(feature = "web")]
     Serve(ServeArgs),
@@ -57,6 +67,11 @@ enum LexemeCommand {
         #[arg(required = true)]
         words: Vec<String>,
     },
+    /// Map an inflected form (`ran`, `geese`) back to the lexemes it belongs to.
+    Lemma {
+        /// Surface form to lemmatize.
+        form: String,
+    },
     /// List lexemes that start with the provided prefix.
     Prefix {
         /// Prefix to search for.
@@ -65,6 +80,37 @@ enum LexemeCommand {
         #[arg(short, long, default_value_t = 10)]
         limit: usize,
     },
+    /// List lexemes that end with the provided suffix (e.g. `phobia`, `ase`).
+    Suffix {
+        /// Suffix to search for.
+        suffix: String,
+        /// Maximum number of matches to return.
+        #[arg(short, long, default_value_t = 10)]
+        limit: usize,
+    },
+    /// List lexemes matching a whole-word glob (`c?t`, `*ology`, `s[aeiou]*e`) or regex.
+    Match {
+        /// Pattern to match; globs support `?`, `*`, and `[abc]` / `[!abc]` classes.
+        pattern: String,
+        /// Interpret the pattern as a regular expression instead of a glob.
+        #[arg(long)]
+        regex: bool,
+        /// Exact word length in characters (shorthand for `--min-len N --max-len N`).
+        #[arg(long, value_name = "N", conflicts_with_all = ["min_len", "max_len"])]
+        length: Option<usize>,
+        /// Minimum word length in characters.
+        #[arg(long, value_name = "N")]
+        min_len: Option<usize>,
+        /// Maximum word length in characters.
+        #[arg(long, value_name = "N")]
+        max_len: Option<usize>,
+        /// Only keep lexemes with this part of speech (e.g. noun, verb).
+        #[arg(long, value_name = "POS")]
+        pos: Option<String>,
+        /// Maximum number of matches to return.
+        #[arg(short, long, default_value_t = 10)]
+        limit: usize,
+    },
     /// Search for lexemes that contain the provided substring.
     Search {
         /// Query text to search for.
@@ -72,39 +118,47 @@ enum LexemeCommand {
         /// Maximum number of matches to return.
         #[arg(short, long, default_value_t = 10)]
         limit: usize,
-        /// Search mode (fuzzy uses RapidFuzz scoring; substring scans lexeme forms only).
+        /// Search mode (fuzzy uses RapidFuzz scoring; full-text ranks entry prose with BM25;
+        /// substring scans lexeme forms only; query parses the pattern as a structured query such
+        /// as `pos:noun def:"large animal" -syn:pet has:encyclopedia`).
         #[arg(long, value_enum, default_value_t = SearchMode::Substring)]
         mode: SearchMode,
-        /// Fields to search; omit to use defaults (word + definitions).
+        /// Fields to search; omit to use defaults (word + definitions for fuzzy; word,
+        /// definitions, examples, text, and encyclopedia for full-text).
         #[arg(long = "field", value_enum)]
         fields: Vec<SearchField>,
-        /// Weight for matching against the lexeme word itself.
-        #[arg(long, default_value_t = 3.0)]
-        weight_word: f32,
-        /// Weight for definitions and senses.
-        #[arg(long, default_value_t = 2.0)]
-        weight_definitions: f32,
-        /// Weight for synonyms list.
-        #[arg(long, default_value_t = 1.0)]
-        weight_synonyms: f32,
-        /// Weight for the entry text body.
-        #[arg(long, default_value_t = 1.5)]
-        weight_text: f32,
-        /// Weight for the encyclopedia article.
-        #[arg(long, default_value_t = 1.5)]
-        weight_encyclopedia: f32,
-        /// Minimum normalized score (0-1) before emitting a hit.
-        #[arg(long, default_value_t = 0.15)]
-        min_score: f32,
+        #[command(flatten)]
+        weights: SearchWeights,
+        #[command(flatten)]
+        filters: SearchFilterArgs,
+        /// Fuzzy scorer, either for every field (`partial-ratio`) or one field
+        /// (`word:jaro-winkler`); repeat or comma-separate, later entries win. Scorers: ratio,
+        /// partial-ratio, token-set-ratio, token-sort-ratio, jaro-winkler, levenshtein.
+        #[arg(long = "scorer", value_name = "[FIELD:]SCORER")]
+        scorers: Vec<String>,
+        /// Candidates each pre-filter may pass to fuzzy scoring; 0 scores every entry.
+        #[arg(long, default_value_t = DEFAULT_CANDIDATE_BUDGET)]
+        candidate_budget: usize,
         /// Print per-field scoring details and cache info.
         #[arg(long)]
         explain: bool,
+        /// Resume substring or fuzzy search after a previous page (its printed next cursor).
+        #[arg(long, value_name = "TOKEN")]
+        cursor: Option<SearchCursor>,
+    },
+    /// Find lexemes from a description of their meaning ("fear of enclosed spaces").
+    Reverse {
+        /// Description to match against entry definitions.
+        description: String,
+        /// Maximum number of matches to return.
+        #[arg(short, long, default_value_t = 10)]
+        limit: usize,
     },
-    /// Show the full entry for a lexeme.
+    /// Show the full entry for a lexeme, or a single sense (`dog#2`, `dog#n.2`).
     Show {
-        /// Word or lexeme ID to display.
+        /// Word, lexeme ID, or sense reference (`word#N`, `word#pos.N`) to display.
         query: String,
-        /// Interpret the query as a lexeme ID instead of a word.
+        /// Interpret the query as a lexeme ID (or `<id>#<N>` sense ID) instead of a word.
         #[arg(long)]
         by_id: bool,
     },
@@ -127,10 +181,42 @@ enum LexemeCommand {
         /// Maximum number of edges to record (0 = unlimited).
         #[arg(long, default_value_t = 256)]
         max_edges: usize,
+        /// Follow each sense's own relations instead of the entry-wide lists.
+        #[arg(long)]
+        sense_level: bool,
         /// Output format: tree (text), json, or dot (GraphViz).
         #[arg(long, value_enum, default_value_t = GraphFormat::Tree)]
         format: GraphFormat,
     },
+    /// Find a shortest chain of relation links from one lexeme to another.
+    Path {
+        /// Starting word or lexeme ID.
+        from: String,
+        /// Target word or lexeme ID.
+        to: String,
+        /// Interpret both queries as lexeme IDs instead of words.
+        #[arg(long)]
+        by_id: bool,
+        /// Longest path to look for, in edges.
+        #[arg(long, default_value_t = 6)]
+        max_hops: usize,
+        /// Relation types to follow; omit to include all.
+        #[arg(long = "relation", value_enum)]
+        relations: Vec<RelationArg>,
+        /// Give up after reaching this many lexemes from either end (0 = unlimited).
+        #[arg(long, default_value_t = 100_000)]
+        max_visited: usize,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum DataCommand {
+    /// Write `lexemes.fst`, the normalized, reversed, and inflection FSTs, the trigram and
+    /// full-text indexes, and an uncompressed, mmap-ready `opengloss_data.mmap` into DIR.
+    ExportMmap {
+        /// Output directory (created if missing); pass it to `--data-dir` afterwards.
+        dir: PathBuf,
+    },
 }
 
 #[cfg(feature = "web")]
@@ -151,6 +237,10 @@ struct ServeArgs {
     /// Optional path to append telemetry snapshots (JSONL). Defaults to data/telemetry/telemetry-log.jsonl.
     #[arg(long, value_name = "PATH")]
     telemetry_log: Option<PathBuf>,
+    /// JSON file with the default search settings, e.g. `{"weight_examples": 1.0,
+    /// "scorer_text": "partial-ratio"}`; omitted settings keep their defaults.
+    #[arg(long, value_name = "PATH")]
+    search_profile: Option<PathBuf>,
 }
 
 #[cfg(feature = "web")]
@@ -170,40 +260,189 @@ impl From<ServeTheme> for WebTheme {
     }
 }
 
+/// Entry facets `lexeme search` results must have (substring and fuzzy modes).
+#[derive(Args, Debug)]
+struct SearchFilterArgs {
+    /// Only keep lexemes with this part of speech (e.g. noun, verb).
+    #[arg(long, value_name = "POS")]
+    pos: Option<String>,
+    /// Drop stopwords ("the", "a", ...).
+    #[arg(long)]
+    exclude_stopwords: bool,
+    /// Only keep lexemes with an encyclopedia article.
+    #[arg(long)]
+    has_encyclopedia: bool,
+    /// Only keep lexemes with an etymology.
+    #[arg(long)]
+    has_etymology: bool,
+    /// Minimum number of senses.
+    #[arg(long, value_name = "N")]
+    min_senses: Option<usize>,
+    /// Maximum number of senses.
+    #[arg(long, value_name = "N")]
+    max_senses: Option<usize>,
+}
+
+impl SearchFilterArgs {
+    fn filters(&self) -> SearchFilters {
+        SearchFilters {
+            part_of_speech: self.pos.clone(),
+            stopword: self.exclude_stopwords.then_some(false),
+            has_encyclopedia: self.has_encyclopedia.then_some(true),
+            has_etymology: self.has_etymology.then_some(true),
+            min_senses: self.min_senses,
+            max_senses: self.max_senses,
+        }
+    }
+}
+
+/// Per-field weights for `lexeme search`; only fields selected with `--field` are searched.
+#[derive(Args, Debug)]
+struct SearchWeights {
+    /// Weight for matching against the lexeme word itself.
+    #[arg(long, default_value_t = 3.0)]
+    weight_word: f32,
+    /// Weight for definitions and senses.
+    #[arg(long, default_value_t = 2.0)]
+    weight_definitions: f32,
+    /// Weight for synonyms list.
+    #[arg(long, default_value_t = 1.0)]
+    weight_synonyms: f32,
+    /// Weight for the entry text body.
+    #[arg(long, default_value_t = 1.5)]
+    weight_text: f32,
+    /// Weight for the encyclopedia article.
+    #[arg(long, default_value_t = 1.5)]
+    weight_encyclopedia: f32,
+    /// Weight for collocations (e.g. "hot dog").
+    #[arg(long, default_value_t = 1.0)]
+    weight_collocations: f32,
+    /// Weight for example sentences.
+    #[arg(long, default_value_t = 1.0)]
+    weight_examples: f32,
+    /// Weight for inflected forms (e.g. "went" for "go").
+    #[arg(long, default_value_t = 1.0)]
+    weight_inflections: f32,
+    /// Weight for derived words.
+    #[arg(long, default_value_t = 1.0)]
+    weight_derivations: f32,
+    /// Weight for antonyms list.
+    #[arg(long, default_value_t = 1.0)]
+    weight_antonyms: f32,
+    /// Weight for hypernyms list.
+    #[arg(long, default_value_t = 1.0)]
+    weight_hypernyms: f32,
+    /// Weight for the etymology summary.
+    #[arg(long, default_value_t = 1.0)]
+    weight_etymology: f32,
+    /// Minimum normalized score (0-1) before emitting a hit.
+    #[arg(long, default_value_t = 0.15)]
+    min_score: f32,
+}
+
+impl SearchWeights {
+    fn config(&self) -> opengloss_rs::SearchConfig {
+        opengloss_rs::SearchConfig {
+            weight_word: self.weight_word,
+            weight_definitions: self.weight_definitions,
+            weight_synonyms: self.weight_synonyms,
+            weight_text: self.weight_text,
+            weight_encyclopedia: self.weight_encyclopedia,
+            weight_collocations: self.weight_collocations,
+            weight_examples: self.weight_examples,
+            weight_inflections: self.weight_inflections,
+            weight_derivations: self.weight_derivations,
+            weight_antonyms: self.weight_antonyms,
+            weight_hypernyms: self.weight_hypernyms,
+            weight_etymology: self.weight_etymology,
+            min_score: self.min_score,
+            ..opengloss_rs::SearchConfig::default()
+        }
+    }
+}
+
 pub fn run() -> Result<(), Box<dyn Error>> {
     let cli = Cli::parse();
     configure_rayon_pool(cli.rayon_threads)?;
+    if let Some(dir) = cli.data_dir.as_deref() {
+        // Long-running servers validate external data up front instead of aborting mid-request.
+        #[cfg(feature = "web")]
+        let validate = matches!(cli.command, Command::Serve(_));
+        #[cfg(not(feature = "web"))]
+        let validate = false;
+        let opened = if validate {
+            LexemeIndex::open_validated(dir)
+        } else {
+            LexemeIndex::open(dir)
+        };
+        opened.map_err(|err| {
+            user_error(format!(
+                "failed to load OpenGloss data from {}: {err}",
+                dir.display()
+            ))
+        })?;
+    } else {
+        LexemeIndex::try_lexicon()
+            .map_err(|err| user_error(format!("failed to load OpenGloss data: {err}")))?;
+    }
     match cli.command {
         Command::Lexeme(LexemeCommand::Get { words }) => handle_get(words, cli.json),
+        Command::Lexeme(LexemeCommand::Lemma { form }) => handle_lemma(form, cli.json),
         Command::Lexeme(LexemeCommand::Prefix { prefix, limit }) => {
             handle_prefix(prefix, limit, cli.json)
         }
+        Command::Lexeme(LexemeCommand::Suffix { suffix, limit }) => {
+            handle_suffix(suffix, limit, cli.json)
+        }
+        Command::Lexeme(LexemeCommand::Match {
+            pattern,
+            regex,
+            length,
+            min_len,
+            max_len,
+            pos,
+            limit,
+        }) => {
+            let query = PatternQuery {
+                pattern,
+                syntax: if regex {
+                    PatternSyntax::Regex
+                } else {
+                    PatternSyntax::Glob
+                },
+                min_len: length.or(min_len),
+                max_len: length.or(max_len),
+                part_of_speech: pos,
+            };
+            handle_match(query, limit, cli.json)
+        }
         Command::Lexeme(LexemeCommand::Search {
             pattern,
             limit,
             mode,
             fields,
-            weight_word,
-            weight_definitions,
-            weight_synonyms,
-            weight_text,
-            weight_encyclopedia,
-            min_score,
+            weights,
+            filters,
+            scorers,
+            candidate_budget,
             explain,
+            cursor,
         }) => handle_search(
             pattern,
             limit,
             cli.json,
             mode,
             fields,
-            weight_word,
-            weight_definitions,
-            weight_synonyms,
-            weight_text,
-            weight_encyclopedia,
-            min_score,
+            weights,
+            filters.filters(),
+            scorers,
+            candidate_budget,
             explain,
+            cursor,
         ),
+        Command::Lexeme(LexemeCommand::Reverse { description, limit }) => {
+            handle_reverse(description, limit, cli.json)
+        }
         Command::Lexeme(LexemeCommand::Show { query, by_id }) => {
             handle_show(query, by_id, cli.json)
         }
@@ -214,28 +453,117 @@ pub fn run() -> Result<(), Box<dyn Error>> {
             relations,
             max_nodes,
             max_edges,
+            sense_level,
             format,
         }) => handle_graph(
-            query, by_id, depth, relations, max_nodes, max_edges, format, cli.json,
+            query,
+            by_id,
+            depth,
+            relations,
+            max_nodes,
+            max_edges,
+            sense_level,
+            format,
+            cli.json,
+        ),
+        Command::Lexeme(LexemeCommand::Path {
+            from,
+            to,
+            by_id,
+            max_hops,
+            relations,
+            max_visited,
+        }) => handle_path(
+            &from,
+            &to,
+            by_id,
+            PathOptions {
+                max_hops,
+                relations: relations.into_iter().map(RelationArg::into).collect(),
+                max_visited,
+            },
+            cli.json,
         ),
+        Command::Data(DataCommand::ExportMmap { dir }) => handle_export_mmap(dir, cli.json),
         #[cfg(feature = "web")]
         Command::Serve(args) => handle_serve(args),
     }
 }
 
+/// Maximum "did you mean" candidates printed after a failed lookup.
+const SUGGESTION_LIMIT: usize = 5;
+
+/// Result of `lexeme get` for one word: the exact FST hit, the normalized fallback matches, or
+/// edit-distance suggestions when both miss.
+struct LookupRow {
+    word: String,
+    exact: Option<u32>,
+    normalized: Vec<u32>,
+    /// Lexemes listing the word among their inflections.
+    lemmas: Vec<u32>,
+    suggestions: Vec<Suggestion>,
+}
+
+impl LookupRow {
+    fn lexeme_id(&self) -> Option<u32> {
+        self.exact
+            .or_else(|| self.normalized.first().copied())
+            .or_else(|| self.lemmas.first().copied())
+    }
+}
+
 fn handle_get(words: Vec<String>, as_json: bool) -> Result<(), Box<dyn Error>> {
-    let results: Vec<(String, Option<u32>)> = words
+    let results: Vec<LookupRow> = words
         .into_iter()
         .map(|word| {
-            let id = LexemeIndex::get(&word);
-            (word, id)
+            let exact = LexemeIndex::get(&word);
+            let normalized = if exact.is_none() {
+                LexemeIndex::get_normalized(&word)
+            } else {
+                Vec::new()
+            };
+            // With no headword match, `lemmatize` only returns inflection matches.
+            let lemmas: Vec<u32> = if exact.is_none() && normalized.is_empty() {
+                LexemeIndex::lemmatize(&word)
+                    .into_iter()
+                    .map(|(id, _)| id)
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            let suggestions = if exact.is_none() && normalized.is_empty() && lemmas.is_empty() {
+                LexemeIndex::suggest(&word, DEFAULT_SUGGEST_EDITS, SUGGESTION_LIMIT)
+            } else {
+                Vec::new()
+            };
+            LookupRow {
+                word,
+                exact,
+                normalized,
+                lemmas,
+                suggestions,
+            }
         })
         .collect();
 
     if as_json {
         let payload: Vec<_> = results
             .iter()
-            .map(|(word, id)| json!({ "word": word, "lexeme_id": id }))
+            .map(|row| {
+                json!({
+                    "word": row.word,
+                    "lexeme_id": row.lexeme_id(),
+                    "normalized_matches": row.normalized,
+                    "lemma_matches": row.lemmas,
+                    "suggestions": row.suggestions.iter().map(|suggestion| {
+                        json!({
+                            "word": suggestion.word,
+                            "lexeme_id": suggestion.lexeme_id,
+                            "distance": suggestion.distance,
+                        })
+                    }).collect::<Vec<_>>(),
+                })
+            })
             .collect();
         println!("{}", serde_json::to_string_pretty(&payload)?);
     } else {
@@ -244,6 +572,56 @@ fn handle_get(words: Vec<String>, as_json: bool) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+fn handle_lemma(form: String, as_json: bool) -> Result<(), Box<dyn Error>> {
+    let matches: Vec<(String, u32, InflectionMatch)> = LexemeIndex::lemmatize(&form)
+        .into_iter()
+        .filter_map(|(id, matched)| {
+            LexemeIndex::entry_by_id(id).map(|entry| (entry.word().to_owned(), id, matched))
+        })
+        .collect();
+
+    if as_json {
+        let payload = json!({
+            "form": form,
+            "results": matches.iter().map(|(word, id, matched)| {
+                let inflection = match matched {
+                    InflectionMatch::Headword => None,
+                    InflectionMatch::Inflection(inflection) => Some(inflection),
+                };
+                json!({"word": word, "lexeme_id": id, "inflection": inflection})
+            }).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+    } else {
+        print_lemma_table(&form, &matches);
+    }
+    Ok(())
+}
+
+fn handle_reverse(description: String, limit: usize, as_json: bool) -> Result<(), Box<dyn Error>> {
+    let limit = cmp::max(1, limit);
+    let matches = LexemeIndex::reverse_lookup(&description, limit);
+
+    if as_json {
+        let payload = json!({
+            "description": description,
+            "limit": limit,
+            "results": matches.iter().map(|hit| {
+                json!({
+                    "word": hit.word,
+                    "lexeme_id": hit.lexeme_id,
+                    "score": hit.score,
+                    "definition": hit.definition,
+                })
+            }).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+    } else {
+        print_reverse_table(&description, &matches);
+    }
+    Ok(())
+}
+
 fn handle_prefix(prefix: String, limit: usize, as_json: bool) -> Result<(), Box<dyn Error>> {
     let limit = cmp::max(1, limit);
     let matches = LexemeIndex::prefix(&prefix, limit);
@@ -263,6 +641,52 @@ fn handle_prefix(prefix: String, limit: usize, as_json: bool) -> Result<(), Box<
     Ok(())
 }
 
+fn handle_suffix(suffix: String, limit: usize, as_json: bool) -> Result<(), Box<dyn Error>> {
+    let limit = cmp::max(1, limit);
+    let matches = LexemeIndex::suffix(&suffix, limit);
+
+    if as_json {
+        let payload = json!({
+            "suffix": suffix,
+            "limit": limit,
+            "results": matches.iter().map(|(word, id)| {
+                json!({"word": word, "lexeme_id": id})
+            }).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+    } else {
+        print_suffix_table(&suffix, &matches);
+    }
+    Ok(())
+}
+
+fn handle_match(query: PatternQuery, limit: usize, as_json: bool) -> Result<(), Box<dyn Error>> {
+    let limit = cmp::max(1, limit);
+    let matches =
+        LexemeIndex::search_pattern(&query, limit).map_err(|err| user_error(err.to_string()))?;
+
+    if as_json {
+        let payload = json!({
+            "pattern": query.pattern,
+            "syntax": match query.syntax {
+                PatternSyntax::Glob => "glob",
+                PatternSyntax::Regex => "regex",
+            },
+            "min_len": query.min_len,
+            "max_len": query.max_len,
+            "pos": query.part_of_speech,
+            "limit": limit,
+            "results": matches.iter().map(|(word, id)| {
+                json!({"word": word, "lexeme_id": id})
+            }).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+    } else {
+        print_pattern_table(&query.pattern, &matches);
+    }
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 fn handle_search(
     pattern: String,
@@ -270,36 +694,137 @@ fn handle_search(
     as_json: bool,
     mode: SearchMode,
     fields: Vec<SearchField>,
-    weight_word: f32,
-    weight_definitions: f32,
-    weight_synonyms: f32,
-    weight_text: f32,
-    weight_encyclopedia: f32,
-    min_score: f32,
+    weights: SearchWeights,
+    filters: SearchFilters,
+    scorers: Vec<String>,
+    candidate_budget: usize,
     explain: bool,
+    cursor: Option<SearchCursor>,
 ) -> Result<(), Box<dyn Error>> {
     if pattern.trim().is_empty() {
         return Err("Search pattern cannot be empty".into());
     }
+    if let Some(cursor) = &cursor
+        && !matches!(
+            (mode, cursor.is_ranked()),
+            (SearchMode::Substring, false) | (SearchMode::Fuzzy, true)
+        )
+    {
+        return Err("--cursor must come from a previous search in the same mode".into());
+    }
+    if !filters.is_empty() && matches!(mode, SearchMode::FullText | SearchMode::Query) {
+        return Err("Search filters are only available for substring and fuzzy search".into());
+    }
+    if !scorers.is_empty() && mode != SearchMode::Fuzzy {
+        return Err("--scorer is only available for fuzzy search".into());
+    }
     match mode {
         SearchMode::Substring => {
             if explain {
                 return Err("--explain is only available for fuzzy search".into());
             }
             let limit = cmp::max(1, limit);
-            let matches = LexemeIndex::search_contains(&pattern, limit);
+            // Facets only come with filters; the unfiltered path stops after one page.
+            let (page, facets) = if filters.is_empty() {
+                let page = LexemeIndex::search_contains_page(&pattern, cursor.as_ref(), limit);
+                (page, None)
+            } else {
+                let (page, facets) = LexemeIndex::search_contains_filtered(
+                    &pattern,
+                    &filters,
+                    cursor.as_ref(),
+                    limit,
+                );
+                (page, Some(facets))
+            };
             if as_json {
                 let payload = json!({
                     "mode": "substring",
                     "pattern": pattern,
                     "limit": limit,
-                    "results": matches.iter().map(|(word, id)| {
+                    "filters": filters,
+                    "facets": facets,
+                    "next_cursor": page.next_cursor.as_ref().map(ToString::to_string),
+                    "results": page.items.iter().map(|(word, id)| {
                         json!({"word": word, "lexeme_id": id})
                     }).collect::<Vec<_>>(),
                 });
                 println!("{}", serde_json::to_string_pretty(&payload)?);
             } else {
-                print_search_table(&pattern, &matches);
+                print_search_table(&pattern, &page.items);
+                if let Some(facets) = &facets {
+                    print_facets(facets);
+                }
+                print_next_cursor(page.next_cursor.as_ref());
+            }
+            Ok(())
+        }
+        SearchMode::FullText => {
+            if explain {
+                return Err("--explain is only available for fuzzy search".into());
+            }
+            let selected = if fields.is_empty() {
+                vec![
+                    SearchField::Word,
+                    SearchField::Definitions,
+                    SearchField::Examples,
+                    SearchField::Text,
+                    SearchField::Encyclopedia,
+                ]
+            } else {
+                fields
+            };
+            let mut config = weights.config();
+            apply_field_filter(&mut config, &selected);
+            let limit = cmp::max(1, limit);
+            let results = LexemeIndex::search_text(&pattern, &config, limit);
+            if as_json {
+                let payload = json!({
+                    "mode": "full-text",
+                    "pattern": pattern,
+                    "limit": limit,
+                    "fields": selected.iter().map(|f| f.to_string()).collect::<Vec<_>>(),
+                    "results": results.iter().map(|row| {
+                        json!({
+                            "lexeme_id": row.lexeme_id,
+                            "word": row.word,
+                            "score": row.score,
+                            "snippet": row.snippet,
+                        })
+                    }).collect::<Vec<_>>(),
+                });
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+            } else {
+                print_scored_table("Full-text", &pattern, &results);
+            }
+            Ok(())
+        }
+        SearchMode::Query => {
+            if explain {
+                return Err("--explain is only available for fuzzy search".into());
+            }
+            let parsed =
+                Query::parse(&pattern).map_err(|message| format!("Invalid query: {message}"))?;
+            let limit = cmp::max(1, limit);
+            let results = LexemeIndex::lexicon().run_query(&parsed, limit)?;
+            if as_json {
+                let payload = json!({
+                    "mode": "query",
+                    "pattern": pattern,
+                    "parsed": parsed.to_string(),
+                    "limit": limit,
+                    "results": results.iter().map(|row| {
+                        json!({
+                            "lexeme_id": row.lexeme_id,
+                            "word": row.word,
+                            "score": row.score,
+                            "snippet": row.snippet,
+                        })
+                    }).collect::<Vec<_>>(),
+                });
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+            } else {
+                print_scored_table("Query", &pattern, &results);
             }
             Ok(())
         }
@@ -310,19 +835,19 @@ fn handle_search(
                 fields
             };
             let mut config = opengloss_rs::SearchConfig {
-                weight_word,
-                weight_definitions,
-                weight_synonyms,
-                weight_text,
-                weight_encyclopedia,
-                min_score,
+                candidate_budget,
+                filters,
+                ..weights.config()
             };
             apply_field_filter(&mut config, &selected);
+            for spec in &scorers {
+                config.apply_scorer_spec(spec)?;
+            }
             if config.total_weight() <= 0.0 {
                 return Err("All search weights are zero; nothing to search".into());
             }
             let limit = cmp::max(1, limit);
-            let summary = LexemeIndex::search_fuzzy_with_stats(&pattern, &config, limit);
+            let summary = LexemeIndex::search_fuzzy_page(&pattern, &config, cursor.as_ref(), limit);
             let diagnostics = if explain {
                 LexemeIndex::explain_search(&pattern, &config, &summary.results)
             } else {
@@ -334,20 +859,36 @@ fn handle_search(
                     "pattern": pattern,
                     "limit": limit,
                     "cache_hit": summary.cache_hit,
+                    "candidates": summary.candidates,
+                    "facets": summary.facets,
+                    "next_cursor": summary.next_cursor.as_ref().map(ToString::to_string),
                     "config": {
                         "weight_word": config.weight_word,
                         "weight_definitions": config.weight_definitions,
                         "weight_synonyms": config.weight_synonyms,
                         "weight_text": config.weight_text,
                         "weight_encyclopedia": config.weight_encyclopedia,
+                        "weight_collocations": config.weight_collocations,
+                        "weight_examples": config.weight_examples,
+                        "weight_inflections": config.weight_inflections,
+                        "weight_derivations": config.weight_derivations,
+                        "weight_antonyms": config.weight_antonyms,
+                        "weight_hypernyms": config.weight_hypernyms,
+                        "weight_etymology": config.weight_etymology,
                         "min_score": config.min_score,
+                        "candidate_budget": config.candidate_budget,
+                        "filters": config.filters,
                         "fields": selected.iter().map(|f| f.to_string()).collect::<Vec<_>>(),
+                        "scorers": FieldKind::ALL.iter().map(|field| {
+                            (field.to_string(), json!(config.scorer(*field).to_string()))
+                        }).collect::<serde_json::Map<_, _>>(),
                     },
                     "results": summary.results.iter().map(|row| {
                         json!({
                             "lexeme_id": row.lexeme_id,
                             "word": row.word,
                             "score": row.score,
+                            "snippet": row.snippet,
                         })
                     }).collect::<Vec<_>>(),
                     "diagnostics": if explain {
@@ -361,7 +902,9 @@ fn handle_search(
                 });
                 println!("{}", serde_json::to_string_pretty(&payload)?);
             } else {
-                print_fuzzy_table(&pattern, &summary.results);
+                print_scored_table("Fuzzy", &pattern, &summary.results);
+                print_facets(&summary.facets);
+                print_next_cursor(summary.next_cursor.as_ref());
                 if explain {
                     print_search_diagnostics(&summary, &diagnostics);
                 } else {
@@ -377,6 +920,16 @@ fn handle_search(
 }
 
 fn handle_show(query: String, by_id: bool, as_json: bool) -> Result<(), Box<dyn Error>> {
+    // `dog#2` addresses a sense, unless the whole query is itself a headword (`c#`).
+    if query.contains('#') && (by_id || LexemeIndex::get(&query).is_none()) {
+        let sense = resolve_sense(&query, by_id)?;
+        if as_json {
+            println!("{}", serde_json::to_string_pretty(&sense_to_json(&sense))?);
+        } else {
+            print_sense(&sense);
+        }
+        return Ok(());
+    }
     let entry = resolve_entry(&query, by_id)?;
 
     if as_json {
@@ -396,6 +949,7 @@ fn handle_graph(
     relations: Vec<RelationArg>,
     max_nodes: usize,
     max_edges: usize,
+    sense_level: bool,
     mut format: GraphFormat,
     force_json: bool,
 ) -> Result<(), Box<dyn Error>> {
@@ -404,6 +958,7 @@ fn handle_graph(
         max_depth: depth,
         max_nodes,
         max_edges,
+        sense_level,
         ..GraphOptions::default()
     };
     if !relations.is_empty() {
@@ -427,6 +982,84 @@ fn handle_graph(
     Ok(())
 }
 
+fn handle_path(
+    from: &str,
+    to: &str,
+    by_id: bool,
+    options: PathOptions,
+    as_json: bool,
+) -> Result<(), Box<dyn Error>> {
+    let from_id = resolve_lexeme_id(from, by_id)?;
+    let to_id = resolve_lexeme_id(to, by_id)?;
+    let edges = LexemeIndex::shortest_path(from_id, to_id, &options).ok_or_else(|| {
+        user_error(format!(
+            "No relation path from {from:?} to {to:?} within {} hops",
+            options.max_hops
+        ))
+    })?;
+    if as_json {
+        let payload = json!({
+            "from": from_id,
+            "to": to_id,
+            "hops": edges.len(),
+            "edges": edges.iter().map(|edge| {
+                json!({
+                    "from": edge.from,
+                    "from_word": lexeme_word(edge.from),
+                    "to": edge.to,
+                    "to_word": lexeme_word(edge.to),
+                    "relation": edge.relation.to_string(),
+                })
+            }).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+        return Ok(());
+    }
+    println!(
+        "Path from {} (#{from_id}) to {} (#{to_id}): {} hop(s)",
+        lexeme_word(from_id),
+        lexeme_word(to_id),
+        edges.len()
+    );
+    for (step, edge) in edges.iter().enumerate() {
+        println!(
+            "  {}. {} (#{}) -[{}]-> {} (#{})",
+            step + 1,
+            lexeme_word(edge.from),
+            edge.from,
+            edge.relation,
+            lexeme_word(edge.to),
+            edge.to
+        );
+    }
+    Ok(())
+}
+
+fn lexeme_word(id: u32) -> String {
+    LexemeIndex::entry_by_id(id)
+        .map(|entry| entry.word().to_owned())
+        .unwrap_or_default()
+}
+
+fn handle_export_mmap(dir: PathBuf, as_json: bool) -> Result<(), Box<dyn Error>> {
+    let path = LexemeIndex::lexicon()
+        .write_mmap_store(&dir)
+        .map_err(|err| user_error(format!("failed to write {}: {err}", dir.display())))?;
+    let bytes = std::fs::metadata(&path)?.len();
+    if as_json {
+        let payload = json!({ "path": path.display().to_string(), "bytes": bytes });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+    } else {
+        println!(
+            "Wrote {} ({:.1} MiB); load it with `--data-dir {}`",
+            path.display(),
+            bytes as f64 / (1024.0 * 1024.0),
+            dir.display()
+        );
+    }
+    Ok(())
+}
+
 #[cfg(feature = "web")]
 fn handle_serve(args: ServeArgs) -> Result<(), Box<dyn Error>> {
     init_web_logging();
@@ -443,6 +1076,17 @@ fn handle_serve(args: ServeArgs) -> Result<(), Box<dyn Error>> {
     if let Some(path) = args.telemetry_log {
         config.telemetry_path = Some(path);
     }
+    if let Some(path) = args.search_profile {
+        let text = std::fs::read_to_string(&path).map_err(|err| {
+            user_error(format!(
+                "Failed to read search profile {}: {err}",
+                path.display()
+            ))
+        })?;
+        config.default_search = serde_json::from_str(&text).map_err(|err| {
+            user_error(format!("Invalid search profile {}: {err}", path.display()))
+        })?;
+    }
     tracing::info!(
         %config.addr,
         openapi = config.enable_openapi,
@@ -497,36 +1141,142 @@ fn resolve_entry(
         .ok_or_else(|| user_error(format!("No entry found for lexeme ID {lexeme_id}")))
 }
 
+fn resolve_sense(query: &str, by_id: bool) -> Result<SenseRef<'static>, Box<dyn Error>> {
+    let id = if by_id {
+        query
+            .parse::<SenseId>()
+            .map_err(|err| user_error(err.to_string()))?
+    } else {
+        LexemeIndex::resolve_sense(query)
+            .map_err(|err| user_error(err.to_string()))?
+            .ok_or_else(|| user_error(format!("No sense found for {query:?}")))?
+    };
+    LexemeIndex::sense(id).ok_or_else(|| user_error(format!("No sense found for sense ID {id}")))
+}
+
 fn resolve_lexeme_id(query: &str, by_id: bool) -> Result<u32, Box<dyn Error>> {
     if by_id {
         query
             .parse::<u32>()
             .map_err(|_| user_error(format!("Failed to parse lexeme ID from {query:?}")))
     } else {
+        // Inflected forms ("ran", "Running") resolve to their base lexeme.
         LexemeIndex::get(query)
-            .ok_or_else(|| user_error(format!("No entry found for word {query:?}")))
+            .or_else(|| LexemeIndex::lemmatize(query).first().map(|(id, _)| *id))
+            .ok_or_else(|| {
+                let suggestions =
+                    LexemeIndex::suggest(query, DEFAULT_SUGGEST_EDITS, SUGGESTION_LIMIT);
+                if suggestions.is_empty() {
+                    user_error(format!("No entry found for word {query:?}"))
+                } else {
+                    user_error(format!(
+                        "No entry found for word {query:?}. Did you mean {}?",
+                        format_suggestions(&suggestions)
+                    ))
+                }
+            })
     }
 }
 
+fn format_suggestions(suggestions: &[Suggestion]) -> String {
+    suggestions
+        .iter()
+        .map(|suggestion| suggestion.word.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 #[allow(clippy::uninlined_format_args)]
-fn print_lookup_table(rows: &[(String, Option<u32>)]) {
+fn print_lookup_table(rows: &[LookupRow]) {
     if rows.is_empty() {
         println!("No words provided.");
         return;
     }
     let width = rows
         .iter()
-        .map(|(word, _)| word.len())
+        .map(|row| row.word.len())
         .max()
         .unwrap_or(4)
         .max("WORD".len());
     println!("{:<width$}  LEXEME_ID", "WORD", width = width);
     println!("{:-<width$}  ----------", "", width = width);
-    for (word, id) in rows {
-        let value = id
-            .map(|v| v.to_string())
-            .unwrap_or_else(|| "<missing>".to_string());
-        println!("{word:<width$}  {value}", width = width);
+    for row in rows {
+        let join_ids = |ids: &[u32]| {
+            ids.iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let value = match (row.exact, row.normalized.as_slice(), row.lemmas.as_slice()) {
+            (Some(id), _, _) => id.to_string(),
+            (None, [], []) if row.suggestions.is_empty() => "<missing>".to_string(),
+            (None, [], []) => format!(
+                "<missing> (did you mean {}?)",
+                format_suggestions(&row.suggestions)
+            ),
+            (None, [], ids) => format!("{} (inflection)", join_ids(ids)),
+            (None, ids, _) => format!("{} (normalized)", join_ids(ids)),
+        };
+        println!("{:<width$}  {value}", row.word, width = width);
+    }
+}
+
+#[allow(clippy::uninlined_format_args)]
+fn print_lemma_table(form: &str, rows: &[(String, u32, InflectionMatch)]) {
+    if rows.is_empty() {
+        println!("No lexemes matched form \"{form}\".");
+        return;
+    }
+    let width = rows
+        .iter()
+        .map(|(word, _, _)| word.len())
+        .max()
+        .unwrap_or(4)
+        .max("WORD".len());
+    println!("Lexemes for form \"{form}\":");
+    println!("{:<width$}  LEXEME_ID   MATCH", "WORD", width = width);
+    println!("{:-<width$}  ----------  ----------", "", width = width);
+    for (word, id, matched) in rows {
+        let matched = match matched {
+            InflectionMatch::Headword => "headword".to_string(),
+            InflectionMatch::Inflection(inflection) => format!("inflection \"{inflection}\""),
+        };
+        println!("{word:<width$}  {id:<10}  {matched}", width = width);
+    }
+}
+
+#[allow(clippy::uninlined_format_args)]
+fn print_reverse_table(description: &str, rows: &[ReverseMatch]) {
+    if rows.is_empty() {
+        println!("No definitions matched \"{description}\".");
+        return;
+    }
+    let width = rows
+        .iter()
+        .map(|hit| hit.word.len())
+        .max()
+        .unwrap_or(4)
+        .max("WORD".len());
+    println!("Words for \"{description}\":");
+    println!(
+        "{:<width$}  LEXEME_ID   SCORE  DEFINITION",
+        "WORD",
+        width = width
+    );
+    println!(
+        "{:-<width$}  ----------  -----  ----------",
+        "",
+        width = width
+    );
+    for hit in rows {
+        println!(
+            "{:<width$}  {:<10}  {:.3}  {}",
+            hit.word,
+            hit.lexeme_id,
+            hit.score,
+            hit.definition,
+            width = width
+        );
     }
 }
 
@@ -550,6 +1300,46 @@ fn print_prefix_table(prefix: &str, rows: &[(String, u32)]) {
     }
 }
 
+#[allow(clippy::uninlined_format_args)]
+fn print_suffix_table(suffix: &str, rows: &[(String, u32)]) {
+    if rows.is_empty() {
+        println!("No lexemes matched suffix \"{suffix}\".");
+        return;
+    }
+    let width = rows
+        .iter()
+        .map(|(word, _)| word.len())
+        .max()
+        .unwrap_or(suffix.len())
+        .max("WORD".len());
+    println!("Matches for suffix \"{suffix}\":");
+    println!("{:<width$}  LEXEME_ID", "WORD", width = width);
+    println!("{:-<width$}  ----------", "", width = width);
+    for (word, id) in rows {
+        println!("{word:<width$}  {id}", width = width);
+    }
+}
+
+#[allow(clippy::uninlined_format_args)]
+fn print_pattern_table(pattern: &str, rows: &[(String, u32)]) {
+    if rows.is_empty() {
+        println!("No lexemes matched pattern \"{pattern}\".");
+        return;
+    }
+    let width = rows
+        .iter()
+        .map(|(word, _)| word.len())
+        .max()
+        .unwrap_or(pattern.len())
+        .max("WORD".len());
+    println!("Matches for pattern \"{pattern}\":");
+    println!("{:<width$}  LEXEME_ID", "WORD", width = width);
+    println!("{:-<width$}  ----------", "", width = width);
+    for (word, id) in rows {
+        println!("{word:<width$}  {id}", width = width);
+    }
+}
+
 #[allow(clippy::uninlined_format_args)]
 fn print_search_table(pattern: &str, rows: &[(String, u32)]) {
     if rows.is_empty() {
@@ -570,10 +1360,47 @@ fn print_search_table(pattern: &str, rows: &[(String, u32)]) {
     }
 }
 
+fn print_next_cursor(cursor: Option<&SearchCursor>) {
+    if let Some(cursor) = cursor {
+        println!("\nMore results: rerun with --cursor {cursor}");
+    }
+}
+
+/// Prints one line of facet counts, e.g. `Facets: 12 matches · noun 8, verb 3 · 4 with
+/// encyclopedia`.
+fn print_facets(facets: &SearchFacets) {
+    if facets.total == 0 {
+        return;
+    }
+    let mut parts = vec![format!("{} matches", facets.total)];
+    let mut pos: Vec<(&String, &usize)> = facets.parts_of_speech.iter().collect();
+    pos.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    if !pos.is_empty() {
+        let rendered: Vec<String> = pos
+            .iter()
+            .map(|(label, count)| format!("{label} {count}"))
+            .collect();
+        parts.push(rendered.join(", "));
+    }
+    for (count, label) in [
+        (facets.stopwords, "stopwords"),
+        (facets.with_encyclopedia, "with encyclopedia"),
+        (facets.with_etymology, "with etymology"),
+    ] {
+        if count > 0 {
+            parts.push(format!("{count} {label}"));
+        }
+    }
+    println!("\nFacets: {}", parts.join(" · "));
+}
+
 #[allow(clippy::uninlined_format_args)]
-fn print_fuzzy_table(pattern: &str, rows: &[opengloss_rs::SearchResult]) {
+fn print_scored_table(kind: &str, pattern: &str, rows: &[opengloss_rs::SearchResult]) {
     if rows.is_empty() {
-        println!("No fuzzy matches found for \"{pattern}\".");
+        println!(
+            "No {} matches found for \"{pattern}\".",
+            kind.to_lowercase()
+        );
         return;
     }
     let width = rows
@@ -582,7 +1409,7 @@ fn print_fuzzy_table(pattern: &str, rows: &[opengloss_rs::SearchResult]) {
         .max()
         .unwrap_or(pattern.len())
         .max("WORD".len());
-    println!("Fuzzy matches for \"{pattern}\":");
+    println!("{kind} matches for \"{pattern}\":");
     println!(
         "{:<width$}  {:<8}  LEXEME_ID",
         "WORD",
@@ -603,15 +1430,38 @@ fn print_fuzzy_table(pattern: &str, rows: &[opengloss_rs::SearchResult]) {
             id = row.lexeme_id,
             width = width
         );
+        if let Some(snippet) = &row.snippet {
+            println!("    {}: {}", snippet.field, render_snippet(snippet));
+        }
     }
 }
 
+/// Renders a result snippet with its matches in bold (plain text when stdout is not a TTY).
+fn render_snippet(snippet: &Snippet) -> String {
+    let bold = stdout_is_tty();
+    snippet
+        .segments()
+        .into_iter()
+        .map(|(text, highlighted)| {
+            if highlighted && bold {
+                format!("\x1b[1m{text}\x1b[0m")
+            } else {
+                text.to_string()
+            }
+        })
+        .collect()
+}
+
 fn print_search_diagnostics(summary: &SearchSummary, breakdowns: &[SearchBreakdown]) {
     println!("\nSearch diagnostics:");
     println!(
         "  Cache: {}",
         if summary.cache_hit { "hit" } else { "miss" }
     );
+    match summary.candidates {
+        Some(count) => println!("  Candidates scored: {count}"),
+        None => println!("  Candidates scored: all entries"),
+    }
     if breakdowns.is_empty() {
         println!("  No breakdowns available.");
         return;
@@ -625,7 +1475,10 @@ fn print_search_diagnostics(summary: &SearchSummary, breakdowns: &[SearchBreakdo
             println!("    (no weighted fields)");
             continue;
         }
-        println!("    {:<14} {:>7} {:>7}  SAMPLE", "FIELD", "SCORE", "WEIGHT");
+        println!(
+            "    {:<14} {:<16} {:>7} {:>7}  SAMPLE",
+            "FIELD", "SCORER", "SCORE", "WEIGHT"
+        );
         for field in &row.fields {
             print_field_line(field);
         }
@@ -635,8 +1488,8 @@ fn print_search_diagnostics(summary: &SearchSummary, breakdowns: &[SearchBreakdo
 fn print_field_line(field: &FieldContribution) {
     let sample = field.sample.as_deref().unwrap_or("-");
     println!(
-        "    {:<14} {:>7.3} {:>7.3}  {}",
-        field.field, field.score, field.weight, sample
+        "    {:<14} {:<16} {:>7.3} {:>7.3}  {}",
+        field.field, field.scorer, field.score, field.weight, sample
     );
 }
 
@@ -648,6 +1501,7 @@ fn breakdown_to_json(row: &SearchBreakdown) -> serde_json::Value {
         "fields": row.fields.iter().map(|field| {
             json!({
                 "field": field.field.to_string(),
+                "scorer": field.scorer.to_string(),
                 "score": field.score,
                 "weight": field.weight,
                 "sample": field.sample,
@@ -676,26 +1530,23 @@ fn print_graph_tree(graph: &GraphTraversal) {
             return;
         }
     };
-    let mut children: HashMap<u32, Vec<(u32, RelationKind)>> = HashMap::new();
+    let mut children: HashMap<u32, Vec<&GraphEdge>> = HashMap::new();
     for edge in &graph.edges {
-        children
-            .entry(edge.from)
-            .or_default()
-            .push((edge.to, edge.relation));
+        children.entry(edge.from).or_default().push(edge);
     }
     for edges in children.values_mut() {
-        edges.sort_by(|(left_id, left_rel), (right_id, right_rel)| {
+        edges.sort_by(|left, right| {
             let left_word = node_map
-                .get(left_id)
+                .get(&left.to)
                 .map(|node| node.word.as_str())
                 .unwrap_or("");
             let right_word = node_map
-                .get(right_id)
+                .get(&right.to)
                 .map(|node| node.word.as_str())
                 .unwrap_or("");
             left_word
                 .cmp(right_word)
-                .then_with(|| left_rel.label().cmp(right_rel.label()))
+                .then_with(|| left.relation.label().cmp(right.relation.label()))
         });
     }
 
@@ -708,8 +1559,8 @@ fn print_graph_tree(graph: &GraphTraversal) {
         graph.max_depth_reached
     );
     if let Some(kids) = children.get(&graph.root) {
-        for (child_id, relation) in kids {
-            print_graph_branch(*child_id, *relation, 0, &node_map, &children);
+        for edge in kids {
+            print_graph_branch(edge, 0, &node_map, &children);
         }
     } else {
         println!("  (no neighbors within the current limits)");
@@ -717,26 +1568,36 @@ fn print_graph_tree(graph: &GraphTraversal) {
 }
 
 fn print_graph_branch(
-    node_id: u32,
-    relation: RelationKind,
+    edge: &GraphEdge,
     depth: usize,
     nodes: &HashMap<u32, &opengloss_rs::GraphNode>,
-    children: &HashMap<u32, Vec<(u32, RelationKind)>>,
+    children: &HashMap<u32, Vec<&GraphEdge>>,
 ) {
-    if let Some(node) = nodes.get(&node_id) {
+    if let Some(node) = nodes.get(&edge.to) {
         let padding = "  ".repeat(depth + 1);
         println!(
             "{padding}- [{}] {} (#{} depth {})",
-            relation, node.word, node.lexeme_id, node.depth
+            edge_label(edge),
+            node.word,
+            node.lexeme_id,
+            node.depth
         );
-        if let Some(kids) = children.get(&node_id) {
-            for (child_id, rel) in kids {
-                print_graph_branch(*child_id, *rel, depth + 1, nodes, children);
+        if let Some(kids) = children.get(&edge.to) {
+            for child in kids {
+                print_graph_branch(child, depth + 1, nodes, children);
             }
         }
     }
 }
 
+/// The relation name, plus the source sense (1-based) for sense-level edges.
+fn edge_label(edge: &GraphEdge) -> String {
+    match edge.sense {
+        Some(position) => format!("{}, sense {}", edge.relation, position + 1),
+        None => edge.relation.to_string(),
+    }
+}
+
 fn graph_to_json(graph: &GraphTraversal) -> serde_json::Value {
     json!({
         "root": graph.root,
@@ -755,6 +1616,7 @@ fn graph_to_json(graph: &GraphTraversal) -> serde_json::Value {
                 "from": edge.from,
                 "to": edge.to,
                 "relation": edge.relation.to_string(),
+                "sense": edge.sense,
             })
         }).collect::<Vec<_>>(),
     })
@@ -775,7 +1637,7 @@ fn graph_to_dot(graph: &GraphTraversal) -> String {
             "  n{} -> n{} [label=\"{}\"];",
             edge.from,
             edge.to,
-            escape_label(edge.relation.label())
+            escape_label(&edge_label(edge))
         ));
         out.push('\n');
     }
@@ -790,19 +1652,7 @@ fn escape_label(label: &str) -> String {
 fn entry_to_json(entry: &opengloss_rs::LexemeEntry<'_>) -> serde_json::Value {
     let senses = entry
         .senses()
-        .map(|sense| {
-            json!({
-                "lexeme_id": sense.lexeme_id(),
-                "sense_index": sense.sense_index(),
-                "part_of_speech": sense.part_of_speech(),
-                "definition": sense.definition(),
-                "synonyms": sense.synonyms().collect::<Vec<_>>(),
-                "antonyms": sense.antonyms().collect::<Vec<_>>(),
-                "hypernyms": sense.hypernyms().collect::<Vec<_>>(),
-                "hyponyms": sense.hyponyms().collect::<Vec<_>>(),
-                "examples": sense.examples().collect::<Vec<_>>(),
-            })
-        })
+        .map(|sense| sense_to_json(&sense))
         .collect::<Vec<_>>();
 
     json!({
@@ -831,6 +1681,46 @@ fn entry_to_json(entry: &opengloss_rs::LexemeEntry<'_>) -> serde_json::Value {
     })
 }
 
+fn sense_to_json(sense: &SenseRef<'_>) -> serde_json::Value {
+    json!({
+        "sense_id": sense.id().to_string(),
+        "key": sense.key(),
+        "lexeme_id": sense.lexeme_id(),
+        "ordinal": sense.ordinal(),
+        "sense_index": sense.sense_index(),
+        "part_of_speech": sense.part_of_speech(),
+        "definition": sense.definition(),
+        "synonyms": sense.synonyms().collect::<Vec<_>>(),
+        "antonyms": sense.antonyms().collect::<Vec<_>>(),
+        "hypernyms": sense.hypernyms().collect::<Vec<_>>(),
+        "hyponyms": sense.hyponyms().collect::<Vec<_>>(),
+        "examples": sense.examples().collect::<Vec<_>>(),
+    })
+}
+
+fn print_sense(sense: &SenseRef<'_>) {
+    println!("Sense: {} (ID {})", sense.key(), sense.id());
+    if let Some(pos) = sense.part_of_speech() {
+        println!("Part of Speech: {pos}");
+    }
+    println!(
+        "Definition: {}",
+        sense.definition().unwrap_or("<definition unavailable>")
+    );
+    let lists = [
+        ("Synonyms", sense.synonyms().collect::<Vec<_>>()),
+        ("Antonyms", sense.antonyms().collect()),
+        ("Hypernyms", sense.hypernyms().collect()),
+        ("Hyponyms", sense.hyponyms().collect()),
+        ("Examples", sense.examples().collect()),
+    ];
+    for (label, items) in lists {
+        if let Some(text) = format_list(items, 8) {
+            println!("{label}: {text}");
+        }
+    }
+}
+
 fn print_entry(entry: &opengloss_rs::LexemeEntry<'_>) {
     println!("Lexeme: {} (ID {})", entry.word(), entry.lexeme_id());
     println!("Entry ID: {}", entry.entry_id());
@@ -934,20 +1824,13 @@ where
 }
 
 fn apply_field_filter(config: &mut opengloss_rs::SearchConfig, fields: &[SearchField]) {
-    if !fields.contains(&SearchField::Word) {
-        config.weight_word = 0.0;
-    }
-    if !fields.contains(&SearchField::Definitions) {
-        config.weight_definitions = 0.0;
-    }
-    if !fields.contains(&SearchField::Synonyms) {
-        config.weight_synonyms = 0.0;
-    }
-    if !fields.contains(&SearchField::Text) {
-        config.weight_text = 0.0;
-    }
-    if !fields.contains(&SearchField::Encyclopedia) {
-        config.weight_encyclopedia = 0.0;
+    for field in FieldKind::ALL {
+        if !fields
+            .iter()
+            .any(|selected| FieldKind::from(*selected) == field)
+        {
+            config.set_weight(field, 0.0);
+        }
     }
 }
 
@@ -998,7 +1881,9 @@ fn configure_rayon_pool(rayon_threads: Option<usize>) -> Result<(), Box<dyn Erro
 #[derive(Copy, Clone, Debug, ValueEnum, Eq, PartialEq)]
 enum SearchMode {
     Fuzzy,
+    FullText,
     Substring,
+    Query,
 }
 
 #[derive(Copy, Clone, Debug, ValueEnum, Eq, PartialEq)]
@@ -1034,17 +1919,36 @@ enum SearchField {
     Synonyms,
     Text,
     Encyclopedia,
+    Collocations,
+    Examples,
+    Inflections,
+    Derivations,
+    Antonyms,
+    Hypernyms,
+    Etymology,
 }
 
 impl std::fmt::Display for SearchField {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let label = match self {
-            SearchField::Word => "word",
-            SearchField::Definitions => "definitions",
-            SearchField::Synonyms => "synonyms",
-            SearchField::Text => "text",
-            SearchField::Encyclopedia => "encyclopedia",
-        };
-        write!(f, "{label}")
+        write!(f, "{}", FieldKind::from(*self))
+    }
+}
+
+impl From<SearchField> for FieldKind {
+    fn from(value: SearchField) -> Self {
+        match value {
+            SearchField::Word => FieldKind::Word,
+            SearchField::Definitions => FieldKind::Definitions,
+            SearchField::Synonyms => FieldKind::Synonyms,
+            SearchField::Text => FieldKind::Text,
+            SearchField::Encyclopedia => FieldKind::Encyclopedia,
+            SearchField::Collocations => FieldKind::Collocations,
+            SearchField::Examples => FieldKind::Examples,
+            SearchField::Inflections => FieldKind::Inflections,
+            SearchField::Derivations => FieldKind::Derivations,
+            SearchField::Antonyms => FieldKind::Antonyms,
+            SearchField::Hypernyms => FieldKind::Hypernyms,
+            SearchField::Etymology => FieldKind::Etymology,
+        }
     }
 }
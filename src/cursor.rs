@@ -0,0 +1,180 @@
+use crate::LexemeError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Opaque position after the last result of a page; pass it back to fetch the next page.
+///
+/// Prefix and substring pages resume after the last FST key, ranked (fuzzy) pages after the last
+/// score and lexeme ID. A cursor only resumes the kind of search that produced it; any other
+/// search starts from its first result. Cursors round-trip through [`fmt::Display`] and
+/// [`FromStr`] as URL-safe tokens.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SearchCursor(Position);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Position {
+    Key(Vec<u8>),
+    Ranked { score_bits: u32, lexeme_id: u32 },
+}
+
+impl SearchCursor {
+    pub(crate) fn after_key(key: &[u8]) -> Self {
+        SearchCursor(Position::Key(key.to_vec()))
+    }
+
+    pub(crate) fn after_rank(score: f32, lexeme_id: u32) -> Self {
+        SearchCursor(Position::Ranked {
+            score_bits: score.to_bits(),
+            lexeme_id,
+        })
+    }
+
+    /// The FST key the page ends on, for prefix and substring cursors.
+    pub(crate) fn key(&self) -> Option<&[u8]> {
+        match &self.0 {
+            Position::Key(key) => Some(key),
+            Position::Ranked { .. } => None,
+        }
+    }
+
+    /// The `(score, lexeme_id)` the page ends on, for ranked cursors.
+    pub(crate) fn rank(&self) -> Option<(f32, u32)> {
+        match self.0 {
+            Position::Key(_) => None,
+            Position::Ranked {
+                score_bits,
+                lexeme_id,
+            } => Some((f32::from_bits(score_bits), lexeme_id)),
+        }
+    }
+
+    /// Whether the cursor came from a ranked (fuzzy) search rather than a prefix or substring one.
+    pub fn is_ranked(&self) -> bool {
+        matches!(self.0, Position::Ranked { .. })
+    }
+}
+
+impl fmt::Display for SearchCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Position::Key(key) => {
+                f.write_str("k")?;
+                for byte in key {
+                    write!(f, "{byte:02x}")?;
+                }
+                Ok(())
+            }
+            Position::Ranked {
+                score_bits,
+                lexeme_id,
+            } => write!(f, "r{score_bits:08x}{lexeme_id:08x}"),
+        }
+    }
+}
+
+impl FromStr for SearchCursor {
+    type Err = LexemeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || LexemeError::InvalidCursor {
+            cursor: value.to_owned(),
+        };
+        let hex = |digits: &str| u32::from_str_radix(digits, 16).map_err(|_| invalid());
+        let position = match value.split_at_checked(1) {
+            Some(("k", digits)) if digits.len() % 2 == 0 && digits.is_ascii() => Position::Key(
+                (0..digits.len())
+                    .step_by(2)
+                    .map(|idx| u8::from_str_radix(&digits[idx..idx + 2], 16))
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| invalid())?,
+            ),
+            Some(("r", digits)) if digits.len() == 16 && digits.is_ascii() => Position::Ranked {
+                score_bits: hex(&digits[..8])?,
+                lexeme_id: hex(&digits[8..])?,
+            },
+            _ => return Err(invalid()),
+        };
+        Ok(SearchCursor(position))
+    }
+}
+
+/// One page of results and the cursor for the page after it (`None` on the last page).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<SearchCursor>,
+}
+
+impl<T> Page<T> {
+    /// Trims `items` (fetched with one extra element) to `limit`, pointing the cursor at the last
+    /// kept item when more remain.
+    pub(crate) fn from_overfetch(
+        mut items: Vec<T>,
+        limit: usize,
+        cursor: impl FnOnce(&T) -> SearchCursor,
+    ) -> Self {
+        let next_cursor = if items.len() > limit {
+            items.truncate(limit);
+            items.last().map(cursor)
+        } else {
+            None
+        };
+        Page { items, next_cursor }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursors_round_trip_as_tokens() {
+        let key = SearchCursor::after_key("naïve".as_bytes());
+        assert_eq!(key.to_string(), "k6e61c3af7665");
+        assert_eq!(key.to_string().parse::<SearchCursor>().unwrap(), key);
+        assert_eq!(key.key(), Some("naïve".as_bytes()));
+        assert!(!key.is_ranked() && key.rank().is_none());
+
+        let ranked = SearchCursor::after_rank(0.75, 42);
+        assert_eq!(ranked.to_string(), "r3f4000000000002a");
+        assert_eq!(ranked.to_string().parse::<SearchCursor>().unwrap(), ranked);
+        assert_eq!(ranked.rank(), Some((0.75, 42)));
+        assert!(ranked.is_ranked() && ranked.key().is_none());
+
+        let empty = SearchCursor::after_key(b"");
+        assert_eq!(empty.to_string().parse::<SearchCursor>().unwrap(), empty);
+    }
+
+    #[test]
+    fn malformed_tokens_are_rejected() {
+        for token in [
+            "",
+            "q123",
+            "kzz",
+            "k6",
+            "kéé",
+            "r3f40",
+            "r3f4000000000002a00",
+            "rxxxxxxxx0000002a",
+            "r3f400000éééé",
+        ] {
+            assert!(
+                matches!(
+                    token.parse::<SearchCursor>(),
+                    Err(LexemeError::InvalidCursor { cursor }) if cursor == token
+                ),
+                "{token:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn overfetched_pages_point_at_their_last_item() {
+        let page = Page::from_overfetch(vec![1, 2, 3], 2, |n| SearchCursor::after_rank(0.0, *n));
+        assert_eq!(page.items, [1, 2]);
+        assert_eq!(page.next_cursor.and_then(|c| c.rank()), Some((0.0, 2)));
+
+        let last = Page::from_overfetch(vec![1, 2], 2, |_| unreachable!());
+        assert_eq!((last.items, last.next_cursor), (vec![1, 2], None));
+    }
+}
@@ -63,10 +63,16 @@ pub struct SenseRecord {
     pub hypernyms: Range,
     pub hyponyms: Range,
     pub examples: Range,
+    pub synonym_neighbors: Range,
+    pub antonym_neighbors: Range,
+    pub hypernym_neighbors: Range,
+    pub hyponym_neighbors: Range,
 }
 
 #[derive(Archive, Serialize, Debug)]
 pub struct PackedStrings {
+    /// Zstd dictionary shared by every frame; empty when frames are standalone.
+    pub dictionary: Vec<u8>,
     pub offsets: Vec<u32>,
     pub lengths: Vec<u32>,
     pub data: Vec<u8>,
@@ -74,23 +80,20 @@ pub struct PackedStrings {
 
 #[derive(Archive, Serialize, Debug)]
 pub struct CompressedTextStore {
+    /// Zstd dictionary shared by every frame; empty when frames are standalone.
+    pub dictionary: Vec<u8>,
     pub offsets: Vec<u32>,
     pub lengths: Vec<u32>,
     pub data: Vec<u8>,
 }
 
+/// Entry records plus the short-string arena every other section points into.
+#[allow(dead_code)]
 #[derive(Archive, Serialize, Debug)]
-pub struct DataStore {
+pub struct EntrySection {
     pub strings: PackedStrings,
-    pub long_texts: CompressedTextStore,
     pub entries: Vec<EntryRecord>,
     pub entry_parts_of_speech: Vec<StringId>,
-    pub senses: Vec<SenseRecord>,
-    pub sense_synonyms: Vec<StringId>,
-    pub sense_antonyms: Vec<StringId>,
-    pub sense_hypernyms: Vec<StringId>,
-    pub sense_hyponyms: Vec<StringId>,
-    pub sense_examples: Vec<StringId>,
     pub entry_all_definitions: Vec<StringId>,
     pub entry_all_synonyms: Vec<StringId>,
     pub entry_all_antonyms: Vec<StringId>,
@@ -101,8 +104,146 @@ pub struct DataStore {
     pub entry_all_derivations: Vec<StringId>,
     pub entry_all_examples: Vec<StringId>,
     pub entry_etymology_cognates: Vec<StringId>,
+}
+
+#[allow(dead_code)]
+#[derive(Archive, Serialize, Debug)]
+pub struct SenseSection {
+    pub senses: Vec<SenseRecord>,
+    pub sense_synonyms: Vec<StringId>,
+    pub sense_antonyms: Vec<StringId>,
+    pub sense_hypernyms: Vec<StringId>,
+    pub sense_hyponyms: Vec<StringId>,
+    pub sense_examples: Vec<StringId>,
+}
+
+/// Neighbor lexeme IDs resolved at build time, for whole entries and for individual senses.
+#[allow(dead_code)]
+#[derive(Archive, Serialize, Debug)]
+pub struct RelationSection {
     pub entry_synonym_neighbors: Vec<u32>,
     pub entry_antonym_neighbors: Vec<u32>,
     pub entry_hypernym_neighbors: Vec<u32>,
     pub entry_hyponym_neighbors: Vec<u32>,
+    pub sense_synonym_neighbors: Vec<u32>,
+    pub sense_antonym_neighbors: Vec<u32>,
+    pub sense_hypernym_neighbors: Vec<u32>,
+    pub sense_hyponym_neighbors: Vec<u32>,
+}
+
+/// Entry text and encyclopedia prose.
+#[allow(dead_code)]
+#[derive(Archive, Serialize, Debug)]
+pub struct TextSection {
+    pub long_texts: CompressedTextStore,
+}
+
+/// Independently stored sections of the data store file, in on-disk order.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    Entries = 0,
+    Senses = 1,
+    Relations = 2,
+    Texts = 3,
+}
+
+#[allow(dead_code)]
+impl Section {
+    pub const ALL: [Section; SECTION_COUNT] = [
+        Section::Entries,
+        Section::Senses,
+        Section::Relations,
+        Section::Texts,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Section::Entries => "entries",
+            Section::Senses => "senses",
+            Section::Relations => "relations",
+            Section::Texts => "texts",
+        }
+    }
+}
+
+pub const SECTION_COUNT: usize = 4;
+
+/// Layout of `opengloss_data.rkyv` / `opengloss_data.mmap`:
+///
+/// ```text
+/// magic "OGLSTORE" | version u32 | flags u32 | SECTION_COUNT x (offset u64, len u64) | sections...
+/// ```
+///
+/// All integers are little-endian and every section starts on a `SECTION_ALIGN` boundary so an
+/// uncompressed file can be mapped and accessed in place. With `FLAG_COMPRESSED` set, each section
+/// is an independent zstd frame wrapping an rkyv archive.
+pub const STORE_MAGIC: [u8; 8] = *b"OGLSTORE";
+pub const STORE_VERSION: u32 = 2;
+pub const FLAG_COMPRESSED: u32 = 1;
+pub const SECTION_ALIGN: usize = 16;
+pub const STORE_HEADER_LEN: usize = 16 + SECTION_COUNT * 16;
+
+#[derive(Debug, Clone, Copy)]
+pub struct StoreHeader {
+    pub flags: u32,
+    pub sections: [(usize, usize); SECTION_COUNT],
+}
+
+#[allow(dead_code)]
+impl StoreHeader {
+    pub fn is_compressed(&self) -> bool {
+        self.flags & FLAG_COMPRESSED != 0
+    }
+
+    /// Concatenates `sections` behind a header, padding each one to `SECTION_ALIGN`.
+    pub fn encode(flags: u32, sections: [&[u8]; SECTION_COUNT]) -> Vec<u8> {
+        let mut header = Vec::with_capacity(STORE_HEADER_LEN);
+        header.extend_from_slice(&STORE_MAGIC);
+        header.extend_from_slice(&STORE_VERSION.to_le_bytes());
+        header.extend_from_slice(&flags.to_le_bytes());
+        let mut body = Vec::new();
+        for section in sections {
+            let offset = STORE_HEADER_LEN + body.len();
+            header.extend_from_slice(&(offset as u64).to_le_bytes());
+            header.extend_from_slice(&(section.len() as u64).to_le_bytes());
+            body.extend_from_slice(section);
+            body.resize(body.len().next_multiple_of(SECTION_ALIGN), 0);
+        }
+        header.extend_from_slice(&body);
+        header
+    }
+
+    /// Parses and bounds-checks the header at the start of `bytes`.
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < STORE_HEADER_LEN || bytes[..8] != STORE_MAGIC {
+            return Err("not an OpenGloss data store (bad magic)".into());
+        }
+        let word = |at: usize| u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap());
+        let wide = |at: usize| u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap()) as usize;
+        let version = word(8);
+        if version != STORE_VERSION {
+            return Err(format!(
+                "unsupported data store version {version} (expected {STORE_VERSION})"
+            ));
+        }
+        let mut sections = [(0, 0); SECTION_COUNT];
+        for (idx, slot) in sections.iter_mut().enumerate() {
+            let at = 16 + idx * 16;
+            let (offset, len) = (wide(at), wide(at + 8));
+            if offset.checked_add(len).is_none_or(|end| end > bytes.len()) {
+                return Err(format!("data store section {idx} is out of bounds"));
+            }
+            *slot = (offset, len);
+        }
+        Ok(Self {
+            flags: word(12),
+            sections,
+        })
+    }
+
+    pub fn section<'a>(&self, bytes: &'a [u8], section: Section) -> &'a [u8] {
+        let (offset, len) = self.sections[section as usize];
+        &bytes[offset..offset + len]
+    }
 }
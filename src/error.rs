@@ -0,0 +1,97 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Failures surfaced while loading or reading an OpenGloss dataset.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LexemeError {
+    /// Reading an artifact from disk failed.
+    Io(io::Error),
+    /// The lexeme FST could not be parsed.
+    InvalidFst(fst::Error),
+    /// The data store header or one of its sections failed to load or validate.
+    CorruptStore(String),
+    /// A compressed string or text frame could not be decoded.
+    Decompress {
+        arena: &'static str,
+        index: usize,
+        source: io::Error,
+    },
+    /// A decoded string, text, or FST key was not valid UTF-8.
+    InvalidUtf8 { arena: &'static str, index: usize },
+    /// `LexemeIndex::open` was called after the default lexicon was initialized.
+    AlreadyInitialized,
+    /// No lexicon was installed and there is neither `$OPENGLOSS_DATA_DIR` nor embedded data.
+    NoData,
+    /// A glob or regex passed to `search_pattern` failed to compile.
+    InvalidPattern { pattern: String, message: String },
+    /// A structured query passed to `query` failed to parse.
+    InvalidQuery { query: String, message: String },
+    /// A pagination cursor token could not be decoded.
+    InvalidCursor { cursor: String },
+    /// A sense reference (`dog#n.2`, `8#2`) could not be parsed.
+    InvalidSense { reference: String, message: String },
+}
+
+impl fmt::Display for LexemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LexemeError::Io(err) => write!(f, "I/O error: {err}"),
+            LexemeError::InvalidFst(err) => write!(f, "invalid lexeme FST: {err}"),
+            LexemeError::CorruptStore(msg) => write!(f, "corrupt data store: {msg}"),
+            LexemeError::Decompress {
+                arena,
+                index,
+                source,
+            } => write!(f, "failed to decompress {arena} #{index}: {source}"),
+            LexemeError::InvalidUtf8 { arena, index } => {
+                write!(f, "{arena} #{index} is not valid UTF-8")
+            }
+            LexemeError::AlreadyInitialized => {
+                f.write_str("OpenGloss data store is already initialized")
+            }
+            LexemeError::NoData => write!(
+                f,
+                "no OpenGloss data loaded: call `LexemeIndex::open` or set {} \
+                 (or rebuild with the `embedded-data` feature)",
+                crate::DATA_DIR_ENV
+            ),
+            LexemeError::InvalidPattern { pattern, message } => {
+                write!(f, "invalid pattern {pattern:?}: {message}")
+            }
+            LexemeError::InvalidQuery { query, message } => {
+                write!(f, "invalid query {query:?}: {message}")
+            }
+            LexemeError::InvalidCursor { cursor } => {
+                write!(f, "invalid search cursor {cursor:?}")
+            }
+            LexemeError::InvalidSense { reference, message } => {
+                write!(f, "invalid sense reference {reference:?}: {message}")
+            }
+        }
+    }
+}
+
+impl Error for LexemeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LexemeError::Io(err) => Some(err),
+            LexemeError::InvalidFst(err) => Some(err),
+            LexemeError::Decompress { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for LexemeError {
+    fn from(err: io::Error) -> Self {
+        LexemeError::Io(err)
+    }
+}
+
+impl From<fst::Error> for LexemeError {
+    fn from(err: fst::Error) -> Self {
+        LexemeError::InvalidFst(err)
+    }
+}
@@ -0,0 +1,461 @@
+use crate::normalize::push_folded;
+use fst::{Map, MapBuilder};
+use std::collections::HashMap;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+const FULLTEXT_MAGIC: [u8; 8] = *b"OGLFTS02";
+const HEADER_LEN: usize = 24 + 8 * FIELD_COUNT;
+/// BM25 term-frequency saturation.
+const BM25_K1: f32 = 1.2;
+/// BM25 length normalization (0 = none, 1 = full).
+const BM25_B: f32 = 0.75;
+
+/// Very common English words that carry no ranking signal; kept sorted for binary search.
+const STOPWORDS: &[&str] = &[
+    "an", "and", "are", "as", "at", "be", "by", "for", "from", "has", "in", "is", "it", "its",
+    "of", "on", "or", "that", "the", "this", "to", "was", "were", "which", "with",
+];
+
+/// Number of indexed fields; each has its own length statistics and query-time boost. At most
+/// 16, the width of the per-posting field mask.
+pub const FIELD_COUNT: usize = 12;
+
+/// An indexed field of an entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Word,
+    Definitions,
+    Synonyms,
+    Examples,
+    Text,
+    Encyclopedia,
+    Collocations,
+    Inflections,
+    Derivations,
+    Antonyms,
+    Hypernyms,
+    Etymology,
+}
+
+impl Field {
+    #[allow(dead_code)]
+    pub const ALL: [Field; FIELD_COUNT] = [
+        Field::Word,
+        Field::Definitions,
+        Field::Synonyms,
+        Field::Examples,
+        Field::Text,
+        Field::Encyclopedia,
+        Field::Collocations,
+        Field::Inflections,
+        Field::Derivations,
+        Field::Antonyms,
+        Field::Hypernyms,
+        Field::Etymology,
+    ];
+}
+
+/// Splits `text` into case-folded, accent-stripped alphanumeric tokens, skipping single characters
+/// and stopwords. Queries and documents go through the same function.
+pub fn tokenize(text: &str, mut emit: impl FnMut(&str)) {
+    let mut token = String::new();
+    for ch in text.nfkd() {
+        if is_combining_mark(ch) {
+            continue;
+        }
+        if ch.is_alphanumeric() {
+            push_folded(&mut token, ch);
+        } else if !token.is_empty() {
+            emit_token(&mut token, &mut emit);
+        }
+    }
+    if !token.is_empty() {
+        emit_token(&mut token, &mut emit);
+    }
+}
+
+fn emit_token(token: &mut String, emit: &mut impl FnMut(&str)) {
+    if token.chars().nth(1).is_some() && STOPWORDS.binary_search(&token.as_str()).is_err() {
+        emit(token);
+    }
+    token.clear();
+}
+
+/// Accumulates per-document term frequencies and serializes the inverted index.
+///
+/// Documents (lexeme IDs) must be added in ascending order; a document's fields may be added in
+/// any order and any number of times.
+#[derive(Default)]
+pub struct FullTextBuilder {
+    current: Option<u32>,
+    pending: HashMap<String, [u32; FIELD_COUNT]>,
+    pending_lengths: [u32; FIELD_COUNT],
+    norms: Vec<[u16; FIELD_COUNT]>,
+    field_tokens: [u64; FIELD_COUNT],
+    terms: HashMap<String, TermPostings>,
+}
+
+#[derive(Default)]
+struct TermPostings {
+    docs: u32,
+    last_doc: u32,
+    bytes: Vec<u8>,
+}
+
+impl FullTextBuilder {
+    pub fn add(&mut self, doc: u32, field: Field, text: &str) {
+        if self.current != Some(doc) {
+            debug_assert!(self.current.is_none_or(|current| current < doc));
+            self.flush();
+            self.current = Some(doc);
+        }
+        let slot = field as usize;
+        let (pending, lengths) = (&mut self.pending, &mut self.pending_lengths);
+        tokenize(text, |token| {
+            lengths[slot] += 1;
+            match pending.get_mut(token) {
+                Some(counts) => counts[slot] += 1,
+                None => {
+                    let mut counts = [0; FIELD_COUNT];
+                    counts[slot] = 1;
+                    pending.insert(token.to_owned(), counts);
+                }
+            }
+        });
+    }
+
+    fn flush(&mut self) {
+        let Some(doc) = self.current.take() else {
+            return;
+        };
+        let doc_idx = doc as usize;
+        if self.norms.len() <= doc_idx {
+            self.norms.resize(doc_idx + 1, [0; FIELD_COUNT]);
+        }
+        for (slot, len) in std::mem::take(&mut self.pending_lengths)
+            .into_iter()
+            .enumerate()
+        {
+            self.norms[doc_idx][slot] = len.min(u32::from(u16::MAX)) as u16;
+            self.field_tokens[slot] += u64::from(len);
+        }
+        for (term, counts) in self.pending.drain() {
+            let postings = self.terms.entry(term).or_default();
+            write_varint(&mut postings.bytes, doc - postings.last_doc);
+            postings.last_doc = doc;
+            postings.docs += 1;
+            let mask = counts
+                .iter()
+                .enumerate()
+                .filter(|(_, tf)| **tf > 0)
+                .fold(0u16, |mask, (slot, _)| mask | (1 << slot));
+            postings.bytes.extend_from_slice(&mask.to_le_bytes());
+            for tf in counts.into_iter().filter(|tf| *tf > 0) {
+                write_varint(&mut postings.bytes, tf);
+            }
+        }
+    }
+
+    /// Serializes the index; `doc_count` pads the length table for trailing documents that had
+    /// no indexed text.
+    pub fn finish(mut self, doc_count: usize) -> Result<Vec<u8>, fst::Error> {
+        self.flush();
+        self.norms
+            .resize(doc_count.max(self.norms.len()), [0; FIELD_COUNT]);
+        let mut terms: Vec<(String, TermPostings)> = self.terms.into_iter().collect();
+        terms.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+
+        let mut postings = Vec::new();
+        let mut dictionary = MapBuilder::memory();
+        for (term, list) in &terms {
+            dictionary.insert(term, postings.len() as u64)?;
+            write_varint(&mut postings, list.docs);
+            postings.extend_from_slice(&list.bytes);
+        }
+        let dictionary = dictionary.into_inner()?;
+
+        let norms_len = self.norms.len() * FIELD_COUNT * 2;
+        let mut out =
+            Vec::with_capacity(HEADER_LEN + norms_len + postings.len() + dictionary.len());
+        out.extend_from_slice(&FULLTEXT_MAGIC);
+        out.extend_from_slice(&(self.norms.len() as u32).to_le_bytes());
+        out.extend_from_slice(&(FIELD_COUNT as u32).to_le_bytes());
+        for total in self.field_tokens {
+            out.extend_from_slice(&total.to_le_bytes());
+        }
+        out.extend_from_slice(&(postings.len() as u64).to_le_bytes());
+        for lengths in &self.norms {
+            for len in lengths {
+                out.extend_from_slice(&len.to_le_bytes());
+            }
+        }
+        out.extend_from_slice(&postings);
+        out.extend_from_slice(&dictionary);
+        Ok(out)
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Read-only view over a serialized full-text index, ranked with BM25.
+///
+/// Layout (little-endian throughout, so it can be read in place at any alignment):
+///
+/// ```text
+/// magic "OGLFTS02" | u32 document count | u32 field count
+/// field count × u64 total tokens | u64 postings length
+/// document count × field count × u16 field length (tokens, saturating)
+/// postings: per term, LEB128 document frequency, then per document a LEB128 ID delta,
+///           a u16 field mask, and a LEB128 term frequency for each field in the mask
+/// term dictionary: FST mapping each term to its postings offset
+/// ```
+#[allow(dead_code)]
+pub struct FullTextIndex<B> {
+    bytes: B,
+    doc_count: usize,
+    avg_lengths: [f32; FIELD_COUNT],
+    postings_start: usize,
+    terms_start: usize,
+}
+
+#[allow(dead_code)]
+impl<B: AsRef<[u8]>> FullTextIndex<B> {
+    pub fn new(bytes: B) -> Result<Self, String> {
+        let raw = bytes.as_ref();
+        if raw.len() >= 8 && raw[..6] == FULLTEXT_MAGIC[..6] && raw[..8] != FULLTEXT_MAGIC {
+            return Err(
+                "unsupported full-text index version (re-export the data directory)".to_string(),
+            );
+        }
+        if raw.len() < HEADER_LEN || raw[..8] != FULLTEXT_MAGIC {
+            return Err("missing full-text index header".to_string());
+        }
+        let doc_count = read_u32(raw, 8) as usize;
+        if read_u32(raw, 12) as usize != FIELD_COUNT {
+            return Err("unexpected full-text field count".to_string());
+        }
+        let mut avg_lengths = [0.0; FIELD_COUNT];
+        for (slot, avg) in avg_lengths.iter_mut().enumerate() {
+            let total = read_u64(raw, 16 + slot * 8);
+            *avg = total as f32 / doc_count.max(1) as f32;
+        }
+        let postings_len = read_u64(raw, 16 + FIELD_COUNT * 8) as usize;
+        let postings_start = HEADER_LEN + doc_count * FIELD_COUNT * 2;
+        let terms_start = postings_start.saturating_add(postings_len);
+        if raw.len() < terms_start {
+            return Err("truncated full-text postings".to_string());
+        }
+        Map::new(&raw[terms_start..]).map_err(|err| format!("term dictionary: {err}"))?;
+        Ok(Self {
+            bytes,
+            doc_count,
+            avg_lengths,
+            postings_start,
+            terms_start,
+        })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_ref()
+    }
+
+    /// Ranks documents against the tokens of `query` with BM25, summing each field's
+    /// contribution scaled by `boosts` (indexed like [`Field::ALL`]).
+    ///
+    /// Returns at most `limit` `(lexeme_id, score)` pairs, best first; ties go to the lower ID.
+    pub fn search(
+        &self,
+        query: &str,
+        boosts: &[f32; FIELD_COUNT],
+        limit: usize,
+    ) -> Vec<(u32, f32)> {
+        let raw = self.bytes.as_ref();
+        let Ok(terms) = Map::new(&raw[self.terms_start..]) else {
+            return Vec::new();
+        };
+        let mut tokens = Vec::new();
+        tokenize(query, |token| tokens.push(token.to_owned()));
+        tokens.sort_unstable();
+        tokens.dedup();
+
+        let mut scores: HashMap<u32, f32> = HashMap::new();
+        for token in &tokens {
+            let Some(offset) = terms.get(token) else {
+                continue;
+            };
+            let mut cursor = &raw[self.postings_start + offset as usize..self.terms_start];
+            let docs = read_varint(&mut cursor);
+            let idf = self.idf_for(docs);
+            let mut doc = 0u32;
+            for _ in 0..docs {
+                doc = doc.wrapping_add(read_varint(&mut cursor));
+                let Some((mask, rest)) = cursor.split_first_chunk::<2>() else {
+                    break;
+                };
+                let mask = u16::from_le_bytes(*mask);
+                cursor = rest;
+                let mut score = 0.0;
+                for (slot, &boost) in boosts.iter().enumerate() {
+                    if mask & (1 << slot) == 0 {
+                        continue;
+                    }
+                    let tf = read_varint(&mut cursor) as f32;
+                    if boost <= 0.0 {
+                        continue;
+                    }
+                    let len = f32::from(self.field_len(doc, slot));
+                    let norm = 1.0 - BM25_B + BM25_B * len / self.avg_lengths[slot].max(1.0);
+                    score += boost * tf * (BM25_K1 + 1.0) / (tf + BM25_K1 * norm);
+                }
+                if score > 0.0 {
+                    *scores.entry(doc).or_default() += idf * score;
+                }
+            }
+        }
+
+        let mut ranked: Vec<(u32, f32)> = scores.into_iter().collect();
+        ranked.sort_unstable_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked.truncate(limit);
+        ranked
+    }
+
+    /// BM25 inverse document frequency of an already tokenized `term`, or `None` when no
+    /// document contains it.
+    pub fn idf(&self, term: &str) -> Option<f32> {
+        let raw = self.bytes.as_ref();
+        let offset = Map::new(&raw[self.terms_start..]).ok()?.get(term)?;
+        let mut cursor = &raw[self.postings_start + offset as usize..self.terms_start];
+        Some(self.idf_for(read_varint(&mut cursor)))
+    }
+
+    fn idf_for(&self, docs: u32) -> f32 {
+        let df = docs as f32;
+        (1.0 + (self.doc_count as f32 - df + 0.5) / (df + 0.5)).ln()
+    }
+
+    fn field_len(&self, doc: u32, slot: usize) -> u16 {
+        let start = HEADER_LEN + (doc as usize * FIELD_COUNT + slot) * 2;
+        self.bytes
+            .as_ref()
+            .get(start..start + 2)
+            .map_or(0, |bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+}
+
+#[allow(dead_code)]
+fn read_u32(raw: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes(raw[at..at + 4].try_into().unwrap())
+}
+
+#[allow(dead_code)]
+fn read_u64(raw: &[u8], at: usize) -> u64 {
+    u64::from_le_bytes(raw[at..at + 8].try_into().unwrap())
+}
+
+#[allow(dead_code)]
+fn read_varint(cursor: &mut &[u8]) -> u32 {
+    let mut value = 0u32;
+    let mut shift = 0;
+    while let Some((&byte, rest)) = cursor.split_first() {
+        *cursor = rest;
+        value |= u32::from(byte & 0x7f).checked_shl(shift).unwrap_or(0);
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(text: &str) -> Vec<String> {
+        let mut out = Vec::new();
+        tokenize(text, |token| out.push(token.to_owned()));
+        out
+    }
+
+    fn index() -> FullTextIndex<Vec<u8>> {
+        let mut builder = FullTextBuilder::default();
+        builder.add(0, Field::Word, "dog");
+        builder.add(0, Field::Definitions, "a domesticated canine kept as a pet");
+        builder.add(1, Field::Word, "cat");
+        builder.add(1, Field::Definitions, "a small domesticated feline");
+        builder.add(1, Field::Definitions, "a pet that hunts mice");
+        builder.add(3, Field::Word, "hot dog");
+        builder.add(3, Field::Examples, "a dog at the fair");
+        FullTextIndex::new(builder.finish(5).unwrap()).unwrap()
+    }
+
+    fn boosts(fields: &[(Field, f32)]) -> [f32; FIELD_COUNT] {
+        let mut boosts = [0.0; FIELD_COUNT];
+        for &(field, boost) in fields {
+            boosts[field as usize] = boost;
+        }
+        boosts
+    }
+
+    #[test]
+    fn tokenize_folds_case_accents_and_stopwords() {
+        assert_eq!(
+            tokens("The Naïve DOG-house, a x"),
+            ["naive", "dog", "house"]
+        );
+        assert_eq!(tokens("Straße STRASSE"), ["strasse", "strasse"]);
+        assert_eq!(tokens("of the and"), Vec::<String>::new());
+        assert!(STOPWORDS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn search_ranks_with_field_boosts() {
+        let index = index();
+        let word = boosts(&[(Field::Word, 1.0)]);
+        let hits = index.search("Dog", &word, 10);
+        assert_eq!(hits.iter().map(|hit| hit.0).collect::<Vec<_>>(), [0, 3]);
+        assert!(hits[0].1 > hits[1].1);
+
+        let everything = [1.0; FIELD_COUNT];
+        let hits = index.search("domesticated pet", &everything, 10);
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|hit| hit.1 > 0.0));
+        assert_eq!(index.search("domesticated pet", &everything, 1).len(), 1);
+
+        assert!(
+            index
+                .search("dog", &boosts(&[(Field::Synonyms, 1.0)]), 10)
+                .is_empty()
+        );
+        assert!(index.search("wolf", &everything, 10).is_empty());
+        assert!(index.search("the", &everything, 10).is_empty());
+    }
+
+    #[test]
+    fn idf_favours_rare_terms() {
+        let index = index();
+        assert!(index.idf("feline").unwrap() > index.idf("domesticated").unwrap());
+        assert_eq!(index.idf("wolf"), None);
+        assert_eq!(index.field_len(4, Field::Word as usize), 0);
+        assert_eq!(index.field_len(1, Field::Definitions as usize), 6);
+    }
+
+    #[test]
+    fn rejects_bad_headers() {
+        assert!(FullTextIndex::new(Vec::new()).is_err());
+        let mut stale = FullTextBuilder::default().finish(0).unwrap();
+        stale[7] = b'1';
+        let err = FullTextIndex::new(stale).err().unwrap();
+        assert!(err.contains("unsupported"), "{err}");
+        let mut truncated = FullTextBuilder::default().finish(3).unwrap();
+        truncated.truncate(HEADER_LEN + 2);
+        assert!(FullTextIndex::new(truncated).is_err());
+    }
+}
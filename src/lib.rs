@@ -1,4 +1,16 @@
+mod cursor;
 mod data;
+mod error;
+mod fulltext;
+mod normalize;
+mod path;
+mod pattern;
+mod query;
+mod reverse;
+mod scorer;
+mod sense;
+mod snippet;
+mod trigram;
 
 #[cfg(feature = "web")]
 pub mod web;
@@ -6,54 +18,166 @@ pub mod web;
 #[cfg(feature = "web")]
 pub mod telemetry;
 
+pub use cursor::{Page, SearchCursor};
+pub use error::LexemeError;
+pub use normalize::normalize_key;
+pub use pattern::{PatternQuery, PatternSyntax};
+pub use query::{DEFAULT_FUZZY_THRESHOLD, Query};
+pub use scorer::FuzzyScorer;
+pub use sense::SenseId;
+pub use snippet::Snippet;
+
 use data::{
-    ArchivedCompressedTextStore, ArchivedDataStore, ArchivedEntryRecord, ArchivedPackedStrings,
-    ArchivedRange, ArchivedSenseRecord, ArchivedStringId, ArchivedTextId, ArchivedU32,
+    ArchivedCompressedTextStore, ArchivedEntryRecord, ArchivedEntrySection, ArchivedPackedStrings,
+    ArchivedRange, ArchivedRelationSection, ArchivedSenseRecord, ArchivedSenseSection,
+    ArchivedStringId, ArchivedTextId, ArchivedTextSection, ArchivedU32, SECTION_ALIGN,
+    SECTION_COUNT, Section, StoreHeader,
 };
 use fst::Automaton;
-use fst::automaton::Str;
+use fst::automaton::{Levenshtein, Str};
 use fst::{IntoStreamer, Map, Streamer};
-use once_cell::sync::Lazy;
+use fulltext::{FIELD_COUNT, FullTextBuilder, FullTextIndex};
+use lru::LruCache;
+use memmap2::Mmap;
 use parking_lot::Mutex;
-use rapidfuzz::fuzz;
+use path::IncomingNeighbors;
+use pattern::PatternAutomaton;
+use rapidfuzz::distance::levenshtein;
 use rayon::prelude::*;
 use rkyv::access_unchecked;
+use rkyv::rancor::Error as RkyvError;
 use rkyv::util::AlignedVec;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashSet, VecDeque};
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
 use std::fmt;
-use std::io::{Cursor, Read};
+use std::fs::{self, File};
+use std::io::{self, Cursor, Read};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::str;
 use std::sync::OnceLock;
+use trigram::TrigramIndex;
+use zstd::dict::DecoderDictionary;
 use zstd::stream::{Decoder as ZstdDecoder, decode_all};
 
-static LEXEME_FST_BYTES: &[u8] = include_bytes!(env!("LEXEME_FST"));
-static DATA_BYTES: &[u8] = include_bytes!(env!("OPENGLOSS_DATA"));
-
-static LEXEME_MAP: Lazy<Map<&'static [u8]>> =
-    Lazy::new(|| Map::new(LEXEME_FST_BYTES).expect("valid lexeme fst"));
-static DATA_SLICE: Lazy<&'static AlignedVec> = Lazy::new(|| {
-    let decompressed = decode_all(Cursor::new(DATA_BYTES)).expect("decompress opengloss data");
-    let mut aligned = AlignedVec::with_capacity(decompressed.len());
-    aligned.extend_from_slice(&decompressed);
-    Box::leak(Box::new(aligned))
-});
-static DATA_STORE: Lazy<&'static ArchivedDataStore> =
-    Lazy::new(|| unsafe { access_unchecked::<ArchivedDataStore>(DATA_SLICE.as_slice()) });
-static STRING_CACHE: Lazy<Vec<OnceLock<&'static str>>> = Lazy::new(|| {
-    let len = data_store().strings.len();
-    (0..len).map(|_| OnceLock::new()).collect()
-});
-static ALL_WORDS: OnceLock<Vec<(String, u32)>> = OnceLock::new();
-#[allow(clippy::type_complexity)]
-static SUBSTRING_CACHE: Lazy<Mutex<lru::LruCache<String, Vec<(String, u32)>>>> =
-    Lazy::new(|| Mutex::new(lru::LruCache::new(std::num::NonZeroUsize::new(64).unwrap())));
-#[allow(clippy::type_complexity)]
-static FUZZY_CACHE: Lazy<Mutex<lru::LruCache<(String, SearchConfig, usize), Vec<SearchResult>>>> =
-    Lazy::new(|| Mutex::new(lru::LruCache::new(std::num::NonZeroUsize::new(32).unwrap())));
+/// File name of the lexeme FST artifact produced by `build.rs`.
+pub const LEXEME_FST_FILE: &str = "lexemes.fst";
+/// File name of the normalized-key FST artifact produced by `build.rs`.
+///
+/// Optional when loading from a directory; it is rebuilt in memory from the lexeme FST if absent.
+pub const NORMALIZED_FST_FILE: &str = "lexemes_normalized.fst";
+/// File name of the reversed-word FST artifact produced by `build.rs`, used for suffix lookups.
+///
+/// Optional when loading from a directory; it is rebuilt in memory from the lexeme FST if absent.
+pub const REVERSED_FST_FILE: &str = "lexemes_reversed.fst";
+/// File name of the inflected-form FST artifact produced by `build.rs` (see
+/// [`Lexicon::lemmatize`]).
+///
+/// Optional when loading from a directory; it is rebuilt from the data store on first use.
+pub const INFLECTIONS_FST_FILE: &str = "lexemes_inflections.fst";
+/// File name of the trigram postings index produced by `build.rs` for substring search.
+///
+/// Optional when loading from a directory; substring search falls back to a full FST scan.
+pub const TRIGRAM_INDEX_FILE: &str = "lexemes_trigrams.idx";
+/// File name of the BM25 full-text index produced by `build.rs` (see [`Lexicon::search_text`]).
+///
+/// Optional when loading from a directory; it is rebuilt from the data store on first use.
+pub const FULLTEXT_INDEX_FILE: &str = "opengloss_fulltext.idx";
+/// File name of the compressed rkyv data store produced by `build.rs`.
+pub const DATA_STORE_FILE: &str = "opengloss_data.rkyv";
+/// File name of the uncompressed, mmap-ready data store written by [`Lexicon::write_mmap_store`].
+pub const MMAP_STORE_FILE: &str = "opengloss_data.mmap";
+/// Environment variable consulted for the artifact directory when no data is embedded.
+pub const DATA_DIR_ENV: &str = "OPENGLOSS_DATA_DIR";
+
+const SUBSTRING_CACHE_SIZE: usize = 64;
+/// Substring queries fall back to a full scan when the trigram candidates exceed
+/// `1 / TRIGRAM_SCAN_RATIO` of the lexicon; verifying that many words costs more than streaming.
+const TRIGRAM_SCAN_RATIO: usize = 8;
+const FUZZY_CACHE_SIZE: usize = 32;
+/// Default [`SearchConfig::candidate_budget`].
+pub const DEFAULT_CANDIDATE_BUDGET: usize = 4096;
+/// Edit distance used by "did you mean" suggestions in the CLI and web server.
+pub const DEFAULT_SUGGEST_EDITS: u32 = 2;
+/// Upper bound on `max_edits` for [`Lexicon::suggest`]; the automaton grows quickly past this.
+pub const MAX_SUGGEST_EDITS: u32 = 3;
+/// Caps how many automaton matches are ranked before truncating to `limit`.
+const SUGGEST_CANDIDATE_CAP: usize = 512;
+
+#[cfg(feature = "embedded-data")]
+static EMBEDDED_FST_BYTES: &[u8] = include_bytes!(env!("LEXEME_FST"));
+#[cfg(feature = "embedded-data")]
+static EMBEDDED_NORMALIZED_FST_BYTES: &[u8] = include_bytes!(env!("LEXEME_NORMALIZED_FST"));
+#[cfg(feature = "embedded-data")]
+static EMBEDDED_REVERSED_FST_BYTES: &[u8] = include_bytes!(env!("LEXEME_REVERSED_FST"));
+#[cfg(feature = "embedded-data")]
+static EMBEDDED_INFLECTIONS_FST_BYTES: &[u8] = include_bytes!(env!("LEXEME_INFLECTIONS_FST"));
+#[cfg(feature = "embedded-data")]
+static EMBEDDED_TRIGRAM_BYTES: &[u8] = include_bytes!(env!("LEXEME_TRIGRAMS"));
+#[cfg(feature = "embedded-data")]
+static EMBEDDED_FULLTEXT_BYTES: &[u8] = include_bytes!(env!("OPENGLOSS_FULLTEXT"));
+#[cfg(feature = "embedded-data")]
+static EMBEDDED_DATA_BYTES: &[u8] = include_bytes!(env!("OPENGLOSS_DATA"));
+
+static DEFAULT_LEXICON: OnceLock<Lexicon> = OnceLock::new();
+
+type FuzzyCacheKey = (String, SearchConfig, Option<SearchCursor>, usize);
+/// Substring matches with the limit they were searched with.
+type SubstringCacheEntry = (usize, Vec<(String, u32)>);
+
+/// A loaded OpenGloss dataset: the lexeme FST, the archived data store, and its lookup caches.
+///
+/// Each instance is fully independent, so a process can hold several dataset versions side by
+/// side. Each section of the data store (entries, senses, relations, long texts) is only inflated
+/// on first use, so FST-only lookups stay cheap and entry lookups skip the encyclopedia prose.
+pub struct Lexicon {
+    map: Map<ArtifactBytes>,
+    normalized: OnceLock<Map<ArtifactBytes>>,
+    reversed: OnceLock<Map<ArtifactBytes>>,
+    inflections: OnceLock<Map<ArtifactBytes>>,
+    trigrams: Option<TrigramIndex<ArtifactBytes>>,
+    fulltext: OnceLock<FullTextIndex<ArtifactBytes>>,
+    store: SectionStore,
+    strings: OnceLock<Vec<OnceLock<Box<str>>>>,
+    string_dictionary: OnceLock<Option<DecoderDictionary<'static>>>,
+    text_dictionary: OnceLock<Option<DecoderDictionary<'static>>>,
+    all_words: OnceLock<Vec<(String, u32)>>,
+    incoming: OnceLock<IncomingNeighbors>,
+    substring_cache: Mutex<LruCache<String, SubstringCacheEntry>>,
+    fuzzy_cache: Mutex<LruCache<FuzzyCacheKey, SearchSummary>>,
+}
+
+/// Backing bytes for a loaded artifact.
+enum ArtifactBytes {
+    #[cfg(feature = "embedded-data")]
+    Static(&'static [u8]),
+    Owned(Vec<u8>),
+    Mapped(Mmap),
+}
+
+impl AsRef<[u8]> for ArtifactBytes {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            #[cfg(feature = "embedded-data")]
+            ArtifactBytes::Static(bytes) => bytes,
+            ArtifactBytes::Owned(bytes) => bytes,
+            ArtifactBytes::Mapped(map) => map,
+        }
+    }
+}
+
+/// The sectioned data store file; compressed sections are inflated on first use, uncompressed
+/// (mapped) ones are read in place.
+struct SectionStore {
+    bytes: ArtifactBytes,
+    header: StoreHeader,
+    loaded: [OnceLock<Result<AlignedVec, String>>; SECTION_COUNT],
+}
 
 /// Read-only access to the lexeme trie.
+///
+/// Thin facade over a process-wide default [`Lexicon`].
 pub struct LexemeIndex;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -87,12 +211,28 @@ impl fmt::Display for RelationKind {
     }
 }
 
+impl std::str::FromStr for RelationKind {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let wanted = value.trim().to_ascii_lowercase();
+        RelationKind::all()
+            .iter()
+            .copied()
+            .find(|relation| relation.label() == wanted)
+            .ok_or_else(|| format!("unknown relation {value:?}"))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct GraphOptions {
     pub max_depth: usize,
     pub max_nodes: usize,
     pub max_edges: usize,
     pub relations: Vec<RelationKind>,
+    /// Follow each sense's own relations (see [`SenseRef::neighbor_ids`]) instead of the
+    /// entry-level lists, recording the source sense on every edge.
+    pub sense_level: bool,
 }
 
 impl Default for GraphOptions {
@@ -102,6 +242,7 @@ impl Default for GraphOptions {
             max_nodes: usize::MAX,
             max_edges: usize::MAX,
             relations: Vec::new(),
+            sense_level: false,
         }
     }
 }
@@ -120,6 +261,30 @@ pub struct GraphEdge {
     pub from: u32,
     pub to: u32,
     pub relation: RelationKind,
+    /// Position in [`LexemeEntry::senses`] of the `from` sense the edge came from, for
+    /// [`GraphOptions::sense_level`] traversals.
+    pub sense: Option<u32>,
+}
+
+/// Limits for [`Lexicon::shortest_path`].
+#[derive(Debug, Clone)]
+pub struct PathOptions {
+    /// Longest path, in edges, to look for.
+    pub max_hops: usize,
+    /// Relations the path may follow; empty means all of them.
+    pub relations: Vec<RelationKind>,
+    /// Give up once this many lexemes have been reached from either end (0 = unlimited).
+    pub max_visited: usize,
+}
+
+impl Default for PathOptions {
+    fn default() -> Self {
+        Self {
+            max_hops: 6,
+            relations: Vec::new(),
+            max_visited: usize::MAX,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -130,40 +295,400 @@ pub struct GraphTraversal {
     pub max_depth_reached: usize,
 }
 
-impl LexemeIndex {
+impl Lexicon {
+    /// Loads the lexicon artifacts from `dir`.
+    ///
+    /// Prefers the mmap-ready `opengloss_data.mmap` when present and otherwise reads the
+    /// compressed `opengloss_data.rkyv`.
+    pub fn open(dir: impl AsRef<Path>) -> Result<Self, LexemeError> {
+        let dir = dir.as_ref();
+        if dir.join(MMAP_STORE_FILE).is_file() {
+            return Self::open_mmap(dir);
+        }
+        let fst = fs::read(dir.join(LEXEME_FST_FILE))?;
+        let derived = DerivedFsts {
+            normalized: read_optional(&dir.join(NORMALIZED_FST_FILE))?.map(ArtifactBytes::Owned),
+            reversed: read_optional(&dir.join(REVERSED_FST_FILE))?.map(ArtifactBytes::Owned),
+            inflections: read_optional(&dir.join(INFLECTIONS_FST_FILE))?.map(ArtifactBytes::Owned),
+            trigrams: read_optional(&dir.join(TRIGRAM_INDEX_FILE))?.map(ArtifactBytes::Owned),
+            fulltext: read_optional(&dir.join(FULLTEXT_INDEX_FILE))?.map(ArtifactBytes::Owned),
+        };
+        let data = fs::read(dir.join(DATA_STORE_FILE))?;
+        Self::from_parts(
+            ArtifactBytes::Owned(fst),
+            derived,
+            ArtifactBytes::Owned(data),
+        )
+    }
+
+    /// Like [`Lexicon::open`], but inflates and validates every section (rkyv `bytecheck`) up
+    /// front so a corrupt or truncated data file is reported here instead of by a later lookup.
+    /// Use this for artifacts that did not ship inside the binary.
+    pub fn open_validated(dir: impl AsRef<Path>) -> Result<Self, LexemeError> {
+        let lexicon = Self::open(dir)?;
+        lexicon.validate()?;
+        Ok(lexicon)
+    }
+
+    /// Memory-maps `lexemes.fst`, the normalized, reversed, and inflection FSTs (when present),
+    /// and `opengloss_data.mmap` from `dir`.
+    ///
+    /// The archive is accessed in place, so the OS pages in only what lookups touch and several
+    /// processes mapping the same file share page cache. The files must not be modified while
+    /// mapped; [`Lexicon::write_mmap_store`] replaces them atomically.
+    pub fn open_mmap(dir: impl AsRef<Path>) -> Result<Self, LexemeError> {
+        let dir = dir.as_ref();
+        let fst = map_file(&dir.join(LEXEME_FST_FILE))?;
+        let derived = DerivedFsts {
+            normalized: map_optional(&dir.join(NORMALIZED_FST_FILE))?.map(ArtifactBytes::Mapped),
+            reversed: map_optional(&dir.join(REVERSED_FST_FILE))?.map(ArtifactBytes::Mapped),
+            inflections: map_optional(&dir.join(INFLECTIONS_FST_FILE))?.map(ArtifactBytes::Mapped),
+            trigrams: map_optional(&dir.join(TRIGRAM_INDEX_FILE))?.map(ArtifactBytes::Mapped),
+            fulltext: map_optional(&dir.join(FULLTEXT_INDEX_FILE))?.map(ArtifactBytes::Mapped),
+        };
+        let data = map_file(&dir.join(MMAP_STORE_FILE))?;
+        Self::from_parts(
+            ArtifactBytes::Mapped(fst),
+            derived,
+            ArtifactBytes::Mapped(data),
+        )
+    }
+
+    /// Builds a lexicon from in-memory FST bytes and data store bytes (compressed or mmap-ready).
+    ///
+    /// The normalized-key and reversed FSTs are derived from the lexeme FST, and the full-text
+    /// index and inflection FST from the data store, on first use.
+    pub fn from_bytes(fst: Vec<u8>, data: Vec<u8>) -> Result<Self, LexemeError> {
+        Self::from_parts(
+            ArtifactBytes::Owned(fst),
+            DerivedFsts::default(),
+            ArtifactBytes::Owned(data),
+        )
+    }
+
+    /// Builds a lexicon over the artifacts baked into the binary.
+    #[cfg(feature = "embedded-data")]
+    pub fn embedded() -> Self {
+        Self::from_parts(
+            ArtifactBytes::Static(EMBEDDED_FST_BYTES),
+            DerivedFsts {
+                normalized: Some(ArtifactBytes::Static(EMBEDDED_NORMALIZED_FST_BYTES)),
+                reversed: Some(ArtifactBytes::Static(EMBEDDED_REVERSED_FST_BYTES)),
+                inflections: Some(ArtifactBytes::Static(EMBEDDED_INFLECTIONS_FST_BYTES)),
+                trigrams: Some(ArtifactBytes::Static(EMBEDDED_TRIGRAM_BYTES)),
+                fulltext: Some(ArtifactBytes::Static(EMBEDDED_FULLTEXT_BYTES)),
+            },
+            ArtifactBytes::Static(EMBEDDED_DATA_BYTES),
+        )
+        .expect("valid embedded artifacts")
+    }
+
+    fn from_parts(
+        fst: ArtifactBytes,
+        derived: DerivedFsts,
+        data: ArtifactBytes,
+    ) -> Result<Self, LexemeError> {
+        let map = Map::new(fst)?;
+        let normalized = preloaded_map(derived.normalized)?;
+        let reversed = preloaded_map(derived.reversed)?;
+        let inflections = preloaded_map(derived.inflections)?;
+        let trigrams = derived
+            .trigrams
+            .map(TrigramIndex::new)
+            .transpose()
+            .map_err(|err| LexemeError::CorruptStore(format!("trigram index: {err}")))?;
+        let fulltext = match derived.fulltext {
+            Some(bytes) => OnceLock::from(
+                FullTextIndex::new(bytes)
+                    .map_err(|err| LexemeError::CorruptStore(format!("full-text index: {err}")))?,
+            ),
+            None => OnceLock::new(),
+        };
+        let store = SectionStore::new(data)?;
+        Ok(Self {
+            map,
+            normalized,
+            reversed,
+            inflections,
+            trigrams,
+            fulltext,
+            store,
+            strings: OnceLock::new(),
+            string_dictionary: OnceLock::new(),
+            text_dictionary: OnceLock::new(),
+            all_words: OnceLock::new(),
+            incoming: OnceLock::new(),
+            substring_cache: Mutex::new(LruCache::new(
+                NonZeroUsize::new(SUBSTRING_CACHE_SIZE).unwrap(),
+            )),
+            fuzzy_cache: Mutex::new(LruCache::new(NonZeroUsize::new(FUZZY_CACHE_SIZE).unwrap())),
+        })
+    }
+
+    /// Inflates every section and checks it with rkyv's validator.
+    pub fn validate(&self) -> Result<(), LexemeError> {
+        for section in Section::ALL {
+            let bytes = self.store.try_section(section)?;
+            check_section(section, bytes).map_err(LexemeError::CorruptStore)?;
+        }
+        Ok(())
+    }
+
     /// Returns the lexeme ID for an exact word match.
-    pub fn get(word: &str) -> Option<u32> {
-        LEXEME_MAP.get(word).map(|value| value as u32)
+    pub fn get(&self, word: &str) -> Option<u32> {
+        self.map.get(word).map(|value| value as u32)
     }
 
     /// Returns up to `limit` lexemes that start with the provided prefix.
-    pub fn prefix(prefix: &str, limit: usize) -> Vec<(String, u32)> {
+    pub fn prefix(&self, prefix: &str, limit: usize) -> Vec<(String, u32)> {
+        self.try_prefix(prefix, limit)
+            .expect("stored lexeme is valid UTF-8")
+    }
+
+    /// Returns every lexeme ID whose word matches `word` after [`normalize_key`] folding, so
+    /// `"Dog"`, `"NAÏVE"`, and `"email"` find `dog`, `naïve`, and `e-mail`.
+    ///
+    /// IDs are returned in ascending order; the list is empty when nothing matches.
+    pub fn get_normalized(&self, word: &str) -> Vec<u32> {
+        normalized_matches(self.normalized_map(), word)
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Maps a surface form back to the lexemes it belongs to: first lexemes whose headword
+    /// matches `form` after [`normalize_key`] folding, then lexemes listing it among their
+    /// inflections, so `"ran"` and `"Running"` both find `run`.
+    ///
+    /// Each lexeme appears once, in ascending ID order within each group; the list is empty when
+    /// nothing matches.
+    pub fn lemmatize(&self, form: &str) -> Vec<(u32, InflectionMatch)> {
+        let mut matches: Vec<(u32, InflectionMatch)> = self
+            .get_normalized(form)
+            .into_iter()
+            .map(|id| (id, InflectionMatch::Headword))
+            .collect();
+        for (id, index) in normalized_matches(self.inflections_map(), form) {
+            if matches.iter().any(|(seen, _)| *seen == id) {
+                continue;
+            }
+            let Some(inflection) = self.entry_by_id(id).and_then(|entry| {
+                entry
+                    .all_inflections()
+                    .nth(index as usize)
+                    .map(str::to_owned)
+            }) else {
+                continue;
+            };
+            matches.push((id, InflectionMatch::Inflection(inflection)));
+        }
+        matches
+    }
+
+    /// Returns up to `limit` lexemes whose normalized form starts with the normalized `prefix`,
+    /// ordered by normalized form.
+    pub fn prefix_normalized(&self, prefix: &str, limit: usize) -> Vec<(String, u32)> {
+        let normalized = normalize_key(prefix);
+        let automaton = Str::new(&normalized).starts_with();
+        let mut stream = self.normalized_map().search(automaton).into_stream();
+        let mut results = Vec::new();
+        while results.len() < limit
+            && let Some((_, value)) = stream.next()
+        {
+            let id = value as u32;
+            if let Some(entry) = self.entry_by_id(id) {
+                results.push((entry.word().to_owned(), id));
+            }
+        }
+        results
+    }
+
+    /// Returns up to `limit` lexemes that end with the provided suffix, ordered by their
+    /// reversed spelling (so words sharing longer endings cluster together).
+    pub fn suffix(&self, suffix: &str, limit: usize) -> Vec<(String, u32)> {
+        let reversed = normalize::reverse_key(suffix);
+        let automaton = Str::new(&reversed).starts_with();
+        let mut stream = self.reversed_map().search(automaton).into_stream();
+        let mut results = Vec::new();
+        while results.len() < limit
+            && let Some((key, value)) = stream.next()
+        {
+            if let Ok(key) = str::from_utf8(key) {
+                results.push((normalize::reverse_key(key), value as u32));
+            }
+        }
+        results
+    }
+
+    /// Returns the page of up to `limit` lexemes starting with `prefix` that follows `cursor`
+    /// (the first page when `None`), in FST order.
+    pub fn prefix_page(
+        &self,
+        prefix: &str,
+        cursor: Option<&SearchCursor>,
+        limit: usize,
+    ) -> Page<(String, u32)> {
+        let limit = limit.max(1);
+        let mut builder = self.map.search(Str::new(prefix).starts_with());
+        if let Some(key) = cursor.and_then(SearchCursor::key) {
+            builder = builder.gt(key);
+        }
+        let mut stream = builder.into_stream();
+        let mut items = Vec::new();
+        while items.len() <= limit
+            && let Some((key, value)) = stream.next()
+        {
+            if let Ok(word) = str::from_utf8(key) {
+                items.push((word.to_owned(), value as u32));
+            }
+        }
+        Page::from_overfetch(items, limit, |(word, _)| {
+            SearchCursor::after_key(word.as_bytes())
+        })
+    }
+
+    /// Fallible variant of [`Lexicon::prefix`] that reports non-UTF-8 FST keys.
+    pub fn try_prefix(
+        &self,
+        prefix: &str,
+        limit: usize,
+    ) -> Result<Vec<(String, u32)>, LexemeError> {
         let automaton = Str::new(prefix).starts_with();
-        let mut stream = LEXEME_MAP.search(automaton).into_stream();
+        let mut stream = self.map.search(automaton).into_stream();
         let mut results = Vec::new();
         while let Some((key, value)) = stream.next() {
-            let word = String::from_utf8(key.to_vec()).expect("stored lexeme is valid UTF-8");
+            let word = String::from_utf8(key.to_vec()).map_err(|_| LexemeError::InvalidUtf8 {
+                arena: "lexeme key",
+                index: value as usize,
+            })?;
             results.push((word, value as u32));
             if results.len() >= limit {
                 break;
             }
         }
-        results
+        Ok(results)
     }
 
     /// Performs a substring search over all lexemes.
-    pub fn search_contains(pattern: &str, limit: usize) -> Vec<(String, u32)> {
+    ///
+    /// Patterns of three or more characters intersect the trigram postings to find candidates
+    /// and only verify those; shorter patterns (or lexicons without the index) stream the FST.
+    pub fn search_contains(&self, pattern: &str, limit: usize) -> Vec<(String, u32)> {
         if pattern.is_empty() {
             return Vec::new();
         }
         {
-            let mut cache = SUBSTRING_CACHE.lock();
-            if let Some(hit) = cache.get(pattern) {
+            let mut cache = self.substring_cache.lock();
+            // A hit that filled its own limit may have been cut short of this one.
+            if let Some((searched, hit)) = cache.get(pattern)
+                && (hit.len() < *searched || hit.len() >= limit)
+            {
                 return hit.iter().take(limit).cloned().collect();
             }
         }
 
-        let mut stream = LEXEME_MAP.stream();
+        let results = match self.contains_candidates(pattern) {
+            Some(candidates) => self.verify_contains(pattern, candidates, None, limit),
+            None => self.scan_contains(pattern, None, limit),
+        };
+
+        let mut cache = self.substring_cache.lock();
+        cache.put(pattern.to_owned(), (limit, results.clone()));
+        results
+    }
+
+    /// Returns the page of up to `limit` lexemes containing `pattern` that follows `cursor` (the
+    /// first page when `None`), in FST order.
+    ///
+    /// The unfiltered counterpart of [`Lexicon::search_contains_filtered`]: it stops after the
+    /// page, skips facet counts, and serves first pages through the [`Lexicon::search_contains`]
+    /// cache.
+    pub fn search_contains_page(
+        &self,
+        pattern: &str,
+        cursor: Option<&SearchCursor>,
+        limit: usize,
+    ) -> Page<(String, u32)> {
+        let limit = limit.max(1);
+        let items = match cursor.and_then(SearchCursor::key) {
+            None => self.search_contains(pattern, limit + 1),
+            Some(_) if pattern.is_empty() => Vec::new(),
+            Some(after) => match self.contains_candidates(pattern) {
+                Some(candidates) => {
+                    self.verify_contains(pattern, candidates, Some(after), limit + 1)
+                }
+                None => self.scan_contains(pattern, Some(after), limit + 1),
+            },
+        };
+        Page::from_overfetch(items, limit, |(word, _)| {
+            SearchCursor::after_key(word.as_bytes())
+        })
+    }
+
+    /// Substring search restricted to entries passing `filters`, returning the page of up to
+    /// `limit` matches after `cursor` (in FST order) and facet counts over every match. Results
+    /// are not cached.
+    pub fn search_contains_filtered(
+        &self,
+        pattern: &str,
+        filters: &SearchFilters,
+        cursor: Option<&SearchCursor>,
+        limit: usize,
+    ) -> (Page<(String, u32)>, SearchFacets) {
+        let mut facets = SearchFacets::default();
+        let limit = limit.max(1);
+        if pattern.is_empty() {
+            let page = Page {
+                items: Vec::new(),
+                next_cursor: None,
+            };
+            return (page, facets);
+        }
+        let after = cursor.and_then(SearchCursor::key);
+        let matches = match self.contains_candidates(pattern) {
+            Some(candidates) => self.verify_contains(pattern, candidates, None, usize::MAX),
+            None => self.scan_contains(pattern, None, usize::MAX),
+        };
+        let entries = &self.entries().entries;
+        let mut results = Vec::new();
+        for (word, id) in matches {
+            let Some(entry) = entries.get(id as usize) else {
+                continue;
+            };
+            if !filters.accepts(self, entry) {
+                continue;
+            }
+            facets.add(self, entry);
+            if results.len() <= limit && after.is_none_or(|key| word.as_bytes() > key) {
+                results.push((word, id));
+            }
+        }
+        let page = Page::from_overfetch(results, limit, |(word, _)| {
+            SearchCursor::after_key(word.as_bytes())
+        });
+        (page, facets)
+    }
+
+    /// Trigram candidates for `pattern`, or `None` when streaming the FST is cheaper.
+    fn contains_candidates(&self, pattern: &str) -> Option<Vec<u32>> {
+        self.trigrams
+            .as_ref()
+            .and_then(|index| index.candidates(pattern))
+            .filter(|candidates| candidates.len() <= self.map.len() / TRIGRAM_SCAN_RATIO)
+    }
+
+    /// Streams the FST for words containing `pattern`, starting after the key `after`.
+    fn scan_contains(
+        &self,
+        pattern: &str,
+        after: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<(String, u32)> {
+        let mut builder = self.map.range();
+        if let Some(key) = after {
+            builder = builder.gt(key);
+        }
+        let mut stream = builder.into_stream();
         let mut results = Vec::new();
         while let Some((key, value)) = stream.next() {
             if let Ok(word) = std::str::from_utf8(key)
@@ -175,18 +700,141 @@ impl LexemeIndex {
                 }
             }
         }
+        results
+    }
 
-        let mut cache = SUBSTRING_CACHE.lock();
-        cache.put(pattern.to_owned(), results.clone());
+    /// Keeps the trigram candidates whose word really contains `pattern` (and sorts after the
+    /// key `after`), in FST (byte) order.
+    fn verify_contains(
+        &self,
+        pattern: &str,
+        candidates: Vec<u32>,
+        after: Option<&[u8]>,
+        limit: usize,
+    ) -> Vec<(String, u32)> {
+        let entries = &self.entries().entries;
+        let mut results: Vec<(String, u32)> = candidates
+            .into_iter()
+            .filter_map(|id| {
+                let word = self.string(entries.get(id as usize)?.word);
+                (word.contains(pattern) && after.is_none_or(|key| word.as_bytes() > key))
+                    .then(|| (word.to_owned(), id))
+            })
+            .collect();
+        results.sort_unstable();
+        results.truncate(limit);
         results
     }
 
+    /// Returns up to `limit` lexemes whose whole word matches a glob or regex pattern, in
+    /// lexicographic order, after applying the query's length and part-of-speech filters.
+    ///
+    /// The pattern is compiled to a DFA and intersected with the lexeme FST, so only branches
+    /// that can still match are walked. Part-of-speech filtering loads the entries section.
+    pub fn search_pattern(
+        &self,
+        query: &PatternQuery,
+        limit: usize,
+    ) -> Result<Vec<(String, u32)>, LexemeError> {
+        let automaton = PatternAutomaton::new(&query.pattern, query.syntax).map_err(|message| {
+            LexemeError::InvalidPattern {
+                pattern: query.pattern.clone(),
+                message,
+            }
+        })?;
+        let mut stream = self.map.search(&automaton).into_stream();
+        let mut results = Vec::new();
+        while results.len() < limit
+            && let Some((key, value)) = stream.next()
+        {
+            let lexeme_id = value as u32;
+            let word = str::from_utf8(key).map_err(|_| LexemeError::InvalidUtf8 {
+                arena: "lexeme key",
+                index: value as usize,
+            })?;
+            if !query.accepts_len(word) {
+                continue;
+            }
+            if let Some(pos) = query.part_of_speech.as_deref() {
+                let Some(entry) = self.try_entry_by_id(lexeme_id)? else {
+                    continue;
+                };
+                if !entry
+                    .parts_of_speech()
+                    .any(|candidate| candidate.eq_ignore_ascii_case(pos))
+                {
+                    continue;
+                }
+            }
+            results.push((word.to_owned(), lexeme_id));
+        }
+        Ok(results)
+    }
+
+    /// Returns up to `limit` lexemes within `max_edits` edits of `word` (capped at
+    /// [`MAX_SUGGEST_EDITS`]), for "did you mean" hints after a failed lookup.
+    ///
+    /// Runs a Levenshtein automaton over the lexeme FST for `word` and its lowercase form, then
+    /// ranks candidates by edit distance, length difference, and word.
+    pub fn suggest(&self, word: &str, max_edits: u32, limit: usize) -> Vec<Suggestion> {
+        if word.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+        let max_edits = max_edits.min(MAX_SUGGEST_EDITS);
+        let lowered = word.to_lowercase();
+        let mut queries = vec![word];
+        if lowered != word {
+            queries.push(&lowered);
+        }
+
+        let mut seen = HashSet::new();
+        let mut suggestions = Vec::new();
+        for query in queries {
+            // Construction fails when the automaton would exceed its state budget (very long
+            // queries); there is nothing useful to suggest in that case.
+            let Ok(automaton) = Levenshtein::new(query, max_edits) else {
+                continue;
+            };
+            let mut stream = self.map.search(automaton).into_stream();
+            while suggestions.len() < SUGGEST_CANDIDATE_CAP
+                && let Some((key, value)) = stream.next()
+            {
+                let lexeme_id = value as u32;
+                if !seen.insert(lexeme_id) {
+                    continue;
+                }
+                let Ok(candidate) = str::from_utf8(key) else {
+                    continue;
+                };
+                let distance = levenshtein::distance(word.chars(), candidate.chars())
+                    .min(levenshtein::distance(lowered.chars(), candidate.chars()));
+                suggestions.push(Suggestion {
+                    lexeme_id,
+                    word: candidate.to_owned(),
+                    distance: distance as u32,
+                });
+            }
+        }
+
+        let query_len = word.chars().count();
+        suggestions.sort_by(|a, b| {
+            let a_len = a.word.chars().count().abs_diff(query_len);
+            let b_len = b.word.chars().count().abs_diff(query_len);
+            a.distance
+                .cmp(&b.distance)
+                .then(a_len.cmp(&b_len))
+                .then_with(|| a.word.cmp(&b.word))
+        });
+        suggestions.truncate(limit);
+        suggestions
+    }
+
     /// Returns all lexemes (word + ID) in lexicographic order.
-    pub fn all_words() -> &'static [(String, u32)] {
-        ALL_WORDS
+    pub fn all_words(&self) -> &[(String, u32)] {
+        self.all_words
             .get_or_init(|| {
-                let mut stream = LEXEME_MAP.stream();
-                let mut words = Vec::with_capacity(LEXEME_MAP.len());
+                let mut stream = self.map.stream();
+                let mut words = Vec::with_capacity(self.map.len());
                 while let Some((key, value)) = stream.next() {
                     let word =
                         String::from_utf8(key.to_vec()).expect("stored lexeme is valid UTF-8");
@@ -198,131 +846,538 @@ impl LexemeIndex {
     }
 
     /// Performs a weighted fuzzy search over all entries.
-    pub fn search_fuzzy(query: &str, config: &SearchConfig, limit: usize) -> Vec<SearchResult> {
-        Self::search_fuzzy_with_stats(query, config, limit).results
+    pub fn search_fuzzy(
+        &self,
+        query: &str,
+        config: &SearchConfig,
+        limit: usize,
+    ) -> Vec<SearchResult> {
+        self.search_fuzzy_with_stats(query, config, limit).results
     }
 
     /// Performs a weighted fuzzy search and returns cache insights.
+    ///
+    /// Unless [`SearchConfig::candidate_budget`] is 0, only a pre-filtered candidate set is
+    /// scored (see [`SearchSummary::candidates`]).
     pub fn search_fuzzy_with_stats(
+        &self,
+        query: &str,
+        config: &SearchConfig,
+        limit: usize,
+    ) -> SearchSummary {
+        self.search_fuzzy_page(query, config, None, limit)
+    }
+
+    /// Like [`Lexicon::search_fuzzy_with_stats`], but returns the page of results ranked after
+    /// `cursor` (best first; ties go to the lower ID) and sets [`SearchSummary::next_cursor`]
+    /// when more remain. Facets still cover every result above `min_score`.
+    pub fn search_fuzzy_page(
+        &self,
         query: &str,
         config: &SearchConfig,
+        cursor: Option<&SearchCursor>,
         limit: usize,
     ) -> SearchSummary {
         if query.trim().is_empty() || config.total_weight() <= 0.0 {
             return SearchSummary {
                 results: Vec::new(),
                 cache_hit: false,
+                candidates: None,
+                facets: SearchFacets::default(),
+                next_cursor: None,
             };
         }
-        let store = data_store();
+        let store = self.entries();
         let limit = limit.max(1);
         let config = config.clone();
-        let key = (query.to_owned(), config.clone(), limit);
+        let cursor = cursor.filter(|cursor| cursor.is_ranked()).cloned();
+        let key = (query.to_owned(), config.clone(), cursor.clone(), limit);
+        let after = cursor
+            .as_ref()
+            .and_then(SearchCursor::rank)
+            .map(|(score, lexeme_id)| RankedResult {
+                score,
+                lexeme_id,
+                word: String::new(),
+            });
         {
-            let mut cache = FUZZY_CACHE.lock();
-            if let Some(hit) = cache.get(&key) {
+            let mut cache = self.fuzzy_cache.lock();
+            if let Some(summary) = cache.get(&key) {
                 return SearchSummary {
-                    results: hit.clone(),
                     cache_hit: true,
+                    ..summary.clone()
                 };
             }
         }
 
-        let heap = store
-            .entries
-            .par_iter()
-            .filter_map(|entry| {
-                score_entry(query, store, entry, &config).and_then(|score| {
-                    if score < config.min_score {
-                        None
-                    } else {
-                        let word = store.string_from_archived(entry.word).to_owned();
-                        Some(RankedResult {
-                            score,
-                            lexeme_id: entry.lexeme_id.to_native(),
-                            word,
-                        })
-                    }
-                })
-            })
-            .fold(BinaryHeap::new, |mut heap, item| {
-                push_ranked(&mut heap, item, limit);
-                heap
-            })
-            .reduce(BinaryHeap::new, |mut left, mut right| {
-                if left.len() < right.len() {
-                    std::mem::swap(&mut left, &mut right);
-                }
-                for item in right.drain() {
-                    push_ranked(&mut left, item, limit);
+        let rank = |entry: &ArchivedEntryRecord| {
+            if !config.filters.accepts(self, entry) {
+                return None;
+            }
+            score_entry(query, self, entry, &config).and_then(|score| {
+                if score < config.min_score {
+                    None
+                } else {
+                    let word = self.string(entry.word).to_owned();
+                    Some(RankedResult {
+                        score,
+                        lexeme_id: entry.lexeme_id.to_native(),
+                        word,
+                    })
                 }
-                left
-            });
+            })
+        };
+        let candidates = self.fuzzy_candidates(query, &config);
+        // One extra result tells whether another page follows.
+        let (heap, facets) = match &candidates {
+            Some(ids) => top_ranked(
+                self,
+                ids.par_iter()
+                    .filter_map(|id| store.entries.get(*id as usize))
+                    .filter_map(|entry| Some((rank(entry)?, entry))),
+                after.as_ref(),
+                limit + 1,
+            ),
+            None => top_ranked(
+                self,
+                store
+                    .entries
+                    .par_iter()
+                    .filter_map(|entry| Some((rank(entry)?, entry))),
+                after.as_ref(),
+                limit + 1,
+            ),
+        };
 
-        let results = drain_heap(heap);
-        let mut cache = FUZZY_CACHE.lock();
-        cache.put(key, results.clone());
-        SearchSummary {
+        let page = Page::from_overfetch(drain_heap(heap), limit, |row| {
+            SearchCursor::after_rank(row.score, row.lexeme_id)
+        });
+        let mut results = page.items;
+        let terms = snippet::highlight_terms([query]);
+        self.attach_snippets(&terms, config.weight_encyclopedia > 0.0, &mut results);
+        let summary = SearchSummary {
             results,
             cache_hit: false,
-        }
+            candidates: candidates.map(|ids| ids.len()),
+            facets,
+            next_cursor: page.next_cursor,
+        };
+        let mut cache = self.fuzzy_cache.lock();
+        cache.put(key, summary.clone());
+        summary
     }
 
-    /// Returns the lexeme entry for the given ID, if available.
-    pub fn entry_by_id(lexeme_id: u32) -> Option<LexemeEntry<'static>> {
-        data_store()
-            .entries
-            .get(lexeme_id as usize)
-            .map(|entry| LexemeEntry {
-                store: data_store(),
-                entry,
-            })
+    /// Collects the entries worth scoring for a fuzzy query: up to `candidate_budget` BM25 hits
+    /// from the full-text index (weighted like the fuzzy fields) plus, when the word field is
+    /// weighted, up to the same number of words sharing the most trigrams with the query (or, for
+    /// queries under three characters, starting with it).
+    ///
+    /// `None` means every entry should be scored: the budget is 0 or covers the whole lexicon, or
+    /// the trigram index needed for word candidates is missing.
+    fn fuzzy_candidates(&self, query: &str, config: &SearchConfig) -> Option<Vec<u32>> {
+        let budget = config.candidate_budget;
+        if budget == 0 || budget >= self.map.len() {
+            return None;
+        }
+        let mut ids = Vec::new();
+        if config.weight_word > 0.0 {
+            let trigrams = self.trigrams.as_ref()?;
+            let lowercase = query.to_lowercase();
+            ids.extend(trigrams.overlapping(&[query, &lowercase], budget));
+            if query.chars().count() < 3 {
+                ids.extend(self.prefix(query, budget).into_iter().map(|(_, id)| id));
+                ids.extend(
+                    self.prefix(&lowercase, budget)
+                        .into_iter()
+                        .map(|(_, id)| id),
+                );
+            }
+        }
+        let boosts = fulltext_boosts(config);
+        if boosts.iter().any(|boost| *boost > 0.0) {
+            ids.extend(
+                self.fulltext_index()
+                    .search(query, &boosts, budget)
+                    .into_iter()
+                    .map(|(id, _)| id),
+            );
+        }
+        ids.sort_unstable();
+        ids.dedup();
+        Some(ids)
     }
 
-    /// Resolves a word to its entry.
-    pub fn entry_by_word(word: &str) -> Option<LexemeEntry<'static>> {
-        Self::get(word).and_then(Self::entry_by_id)
+    /// Empties the substring and fuzzy result caches.
+    pub fn clear_caches(&self) {
+        self.substring_cache.lock().clear();
+        self.fuzzy_cache.lock().clear();
     }
 
-    /// Produces detailed score breakdowns for a set of results.
-    pub fn explain_search(
+    /// Ranks entries against the words of `query` with BM25 over an inverted index of every
+    /// [`FieldKind`].
+    ///
+    /// Each field's contribution is boosted by the matching [`SearchConfig`] weight. Scores are
+    /// unnormalized BM25 sums, so `min_score` does not apply.
+    pub fn search_text(
+        &self,
         query: &str,
         config: &SearchConfig,
-        results: &[SearchResult],
-    ) -> Vec<SearchBreakdown> {
-        let store = data_store();
+        limit: usize,
+    ) -> Vec<SearchResult> {
+        let boosts = fulltext_boosts(config);
+        if query.trim().is_empty() || boosts.iter().all(|boost| *boost <= 0.0) {
+            return Vec::new();
+        }
+        let mut results: Vec<SearchResult> = self
+            .fulltext_index()
+            .search(query, &boosts, limit.max(1))
+            .into_iter()
+            .filter_map(|(lexeme_id, score)| {
+                let entry = self.entry_by_id(lexeme_id)?;
+                Some(SearchResult {
+                    lexeme_id,
+                    word: entry.word().to_owned(),
+                    score,
+                    snippet: None,
+                })
+            })
+            .collect();
+        let terms = snippet::highlight_terms([query]);
+        self.attach_snippets(&terms, config.weight_encyclopedia > 0.0, &mut results);
         results
+    }
+
+    /// Reverse dictionary: returns up to `limit` entries whose definitions best describe
+    /// `description` ("fear of enclosed spaces" finds `claustrophobia`), best first.
+    ///
+    /// The description's words are weighted by their BM25 IDF, dropping words whose own entry is
+    /// marked as a stopword. Entries are gathered from the definitions in the full-text index;
+    /// each one is scored by its best definition (from `all_definitions` or a sense) as the share
+    /// of the description's weight it covers, ties going to the tighter definition and then the
+    /// lower ID.
+    pub fn reverse_lookup(&self, description: &str, limit: usize) -> Vec<ReverseMatch> {
+        let index = self.fulltext_index();
+        let mut terms: Vec<(String, f32)> = Vec::new();
+        fulltext::tokenize(description, |token| {
+            if !terms.iter().any(|(term, _)| term == token) {
+                terms.push((token.to_owned(), 0.0));
+            }
+        });
+        terms.retain_mut(|(term, weight)| {
+            let stopword = self
+                .get(term)
+                .and_then(|id| self.entry_by_id(id))
+                .is_some_and(|entry| entry.is_stopword());
+            match index.idf(term) {
+                Some(idf) if !stopword => {
+                    *weight = idf;
+                    true
+                }
+                _ => false,
+            }
+        });
+        if terms.is_empty() || limit == 0 {
+            return Vec::new();
+        }
+
+        let mut boosts = [0.0; FIELD_COUNT];
+        boosts[fulltext::Field::Definitions as usize] = 1.0;
+        let query = terms
             .iter()
-            .filter_map(|row| {
-                store
-                    .entries
-                    .get(row.lexeme_id as usize)
-                    .and_then(|entry| explain_entry(query, store, entry, config))
+            .map(|(term, _)| term.as_str())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let candidates = index.search(&query, &boosts, DEFAULT_CANDIDATE_BUDGET);
+        let mut matches: Vec<(reverse::Overlap, ReverseMatch)> = candidates
+            .par_iter()
+            .filter_map(|&(lexeme_id, _)| {
+                let entry = self.entry_by_id(lexeme_id)?;
+                let senses = entry.senses();
+                let (overlap, definition) = entry
+                    .all_definitions()
+                    .chain(senses.filter_map(|sense| sense.definition()))
+                    .filter_map(|definition| {
+                        reverse::definition_overlap(&terms, definition)
+                            .map(|overlap| (overlap, definition))
+                    })
+                    .max_by(|a, b| reverse::compare_overlap(&a.0, &b.0))?;
+                Some((
+                    overlap,
+                    ReverseMatch {
+                        lexeme_id,
+                        word: entry.word().to_owned(),
+                        score: overlap.coverage,
+                        definition: definition.to_owned(),
+                    },
+                ))
             })
-            .collect()
+            .collect();
+        matches.sort_unstable_by(|a, b| {
+            reverse::compare_overlap(&b.0, &a.0).then(a.1.lexeme_id.cmp(&b.1.lexeme_id))
+        });
+        matches.truncate(limit);
+        matches.into_iter().map(|(_, hit)| hit).collect()
     }
 
-    /// Traverses the neighbor graph with a depth-limited BFS.
-    pub fn traverse_graph(lexeme_id: u32, options: &GraphOptions) -> Option<GraphTraversal> {
-        let opts = GraphOptions {
-            max_depth: options.max_depth,
-            max_nodes: if options.max_nodes == 0 {
-                usize::MAX
-            } else {
-                options.max_nodes
-            },
-            max_edges: if options.max_edges == 0 {
-                usize::MAX
-            } else {
-                options.max_edges
-            },
-            relations: if options.relations.is_empty() {
+    /// Parses `query` with [`Query::parse`] and returns up to `limit` matching entries (see
+    /// [`Lexicon::run_query`]).
+    pub fn query(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>, LexemeError> {
+        let parsed = Query::parse(query).map_err(|message| LexemeError::InvalidQuery {
+            query: query.to_owned(),
+            message,
+        })?;
+        self.run_query(&parsed, limit)
+    }
+
+    /// Returns up to `limit` entries matching `query`, best first; ties go to the lower ID.
+    ///
+    /// `word:` clauses are resolved against the lexeme FSTs up front. When the query requires
+    /// one (as a top-level clause or in every branch of a top-level `OR`), only those entries
+    /// are checked; otherwise every entry is. A substring match scores the share of the best
+    /// matching value it covers, a fuzzy match its similarity, and a facet or `word:` match 1;
+    /// `AND` averages its clauses and `OR` keeps the best one.
+    pub fn run_query(&self, query: &Query, limit: usize) -> Result<Vec<SearchResult>, LexemeError> {
+        let mut words = HashMap::new();
+        self.resolve_query_words(query, &mut words)?;
+        let store = self.entries();
+        let score = |entry: &ArchivedEntryRecord| {
+            query_score(query, self, entry, &words)
+                .map(|score| (score, entry.lexeme_id.to_native()))
+        };
+        let mut hits: Vec<(f32, u32)> = match query_candidates(query, &words) {
+            Some(ids) => ids
+                .par_iter()
+                .filter_map(|id| store.entries.get(*id as usize))
+                .filter_map(score)
+                .collect(),
+            None => store.entries.par_iter().filter_map(score).collect(),
+        };
+        hits.sort_unstable_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+        hits.truncate(limit);
+        let mut results: Vec<SearchResult> = hits
+            .into_iter()
+            .filter_map(|(score, lexeme_id)| {
+                let entry = self.entry_by_id(lexeme_id)?;
+                Some(SearchResult {
+                    lexeme_id,
+                    word: entry.word().to_owned(),
+                    score,
+                    snippet: None,
+                })
+            })
+            .collect();
+        let terms = snippet::highlight_terms(query.positive_values());
+        self.attach_snippets(&terms, false, &mut results);
+        Ok(results)
+    }
+
+    /// Fills in [`SearchResult::snippet`] from each hit's definitions and examples, highlighting
+    /// `terms`.
+    ///
+    /// Encyclopedia paragraphs (skipping headings) are only decompressed and considered when
+    /// `encyclopedia` is set (the field was searched) or no definition or example matches.
+    fn attach_snippets(&self, terms: &[String], encyclopedia: bool, results: &mut [SearchResult]) {
+        for result in results {
+            let Some(entry) = self.entry_by_id(result.lexeme_id) else {
+                continue;
+            };
+            let short = || {
+                entry
+                    .all_definitions()
+                    .map(|text| (FieldKind::Definitions, text))
+                    .chain(entry.all_examples().map(|text| (FieldKind::Examples, text)))
+            };
+            let mut snippet = snippet::best_snippet(terms, short());
+            let unmatched = snippet
+                .as_ref()
+                .is_none_or(|snippet| snippet.highlights.is_empty());
+            if (encyclopedia || unmatched)
+                && let Some(article) = entry.encyclopedia_entry()
+            {
+                let paragraphs = article
+                    .split("\n\n")
+                    .filter(|paragraph| !paragraph.trim_start().starts_with('#'))
+                    .map(|paragraph| (FieldKind::Encyclopedia, paragraph));
+                snippet = snippet::best_snippet(terms, short().chain(paragraphs));
+            }
+            result.snippet = snippet;
+        }
+    }
+
+    /// Looks up the lexeme IDs of every `word:` pattern in `query`: exact patterns through the
+    /// normalized FST, `*suffix` through the reversed FST, and other globs through the lexeme FST.
+    fn resolve_query_words(
+        &self,
+        query: &Query,
+        words: &mut HashMap<String, HashSet<u32>>,
+    ) -> Result<(), LexemeError> {
+        const GLOB: [char; 3] = ['*', '?', '['];
+        match query {
+            Query::And(clauses) | Query::Or(clauses) => {
+                for clause in clauses {
+                    self.resolve_query_words(clause, words)?;
+                }
+            }
+            Query::Not(inner) => self.resolve_query_words(inner, words)?,
+            Query::Word(pattern) if !words.contains_key(pattern) => {
+                let ids = if !pattern.contains(GLOB) {
+                    self.get_normalized(pattern).into_iter().collect()
+                } else if let Some(suffix) = pattern.strip_prefix('*')
+                    && !suffix.contains(GLOB)
+                {
+                    self.suffix(suffix, usize::MAX)
+                        .into_iter()
+                        .map(|(_, id)| id)
+                        .collect()
+                } else {
+                    self.search_pattern(&PatternQuery::glob(pattern.as_str()), usize::MAX)?
+                        .into_iter()
+                        .map(|(_, id)| id)
+                        .collect()
+                };
+                words.insert(pattern.clone(), ids);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Returns the lexeme entry for the given ID, if available.
+    pub fn entry_by_id(&self, lexeme_id: u32) -> Option<LexemeEntry<'_>> {
+        self.entries()
+            .entries
+            .get(lexeme_id as usize)
+            .map(|entry| LexemeEntry {
+                lexicon: self,
+                entry,
+            })
+    }
+
+    /// Fallible variant of [`Lexicon::entry_by_id`].
+    ///
+    /// Reports a section, word, or entry-level list range that fails to load or validate instead
+    /// of panicking; once it returns an entry, [`LexemeEntry::word`] is served from the cache.
+    pub fn try_entry_by_id(&self, lexeme_id: u32) -> Result<Option<LexemeEntry<'_>>, LexemeError> {
+        let entries = self.try_entries()?;
+        let Some(entry) = entries.entries.get(lexeme_id as usize) else {
+            return Ok(None);
+        };
+        self.try_string(entry.word)?;
+        for (range, bucket) in [
+            (&entry.parts_of_speech, &entries.entry_parts_of_speech),
+            (&entry.etymology_cognates, &entries.entry_etymology_cognates),
+            (&entry.all_definitions, &entries.entry_all_definitions),
+            (&entry.all_synonyms, &entries.entry_all_synonyms),
+            (&entry.all_antonyms, &entries.entry_all_antonyms),
+            (&entry.all_hypernyms, &entries.entry_all_hypernyms),
+            (&entry.all_hyponyms, &entries.entry_all_hyponyms),
+            (&entry.all_collocations, &entries.entry_all_collocations),
+            (&entry.all_inflections, &entries.entry_all_inflections),
+            (&entry.all_derivations, &entries.entry_all_derivations),
+            (&entry.all_examples, &entries.entry_all_examples),
+        ] {
+            range_slice(bucket.as_slice(), range)?;
+        }
+        Ok(Some(LexemeEntry {
+            lexicon: self,
+            entry,
+        }))
+    }
+
+    /// Resolves a word to its entry.
+    pub fn entry_by_word(&self, word: &str) -> Option<LexemeEntry<'_>> {
+        self.get(word).and_then(|id| self.entry_by_id(id))
+    }
+
+    /// Returns the sense addressed by `id`, if its lexeme has that many senses.
+    pub fn sense(&self, id: SenseId) -> Option<SenseRef<'_>> {
+        let entry = self.entries().entries.get(id.lexeme_id as usize)?;
+        let position = id.ordinal.checked_sub(1)? as usize;
+        let sense = range_slice(self.senses().senses.as_slice(), &entry.senses)
+            .ok()?
+            .get(position)?;
+        Some(SenseRef {
+            lexicon: self,
+            sense,
+            ordinal: id.ordinal,
+        })
+    }
+
+    /// Resolves a word-based sense reference, `dog#2` or `dog#n.2` (see [`SenseRef::key`]).
+    ///
+    /// The word is matched exactly, then after [`normalize_key`] folding. A part-of-speech tag
+    /// (`n`, `v`, `adj`, `adv`, or the full name) must match the sense's own. Returns `Ok(None)`
+    /// when the word, the sense, or the tag does not match, and an error for malformed
+    /// references.
+    pub fn resolve_sense(&self, reference: &str) -> Result<Option<SenseId>, LexemeError> {
+        let (word, selector) = sense::split_reference(reference)?;
+        let Some(lexeme_id) = self
+            .get(word)
+            .or_else(|| self.get_normalized(word).first().copied())
+        else {
+            return Ok(None);
+        };
+        let id = SenseId::new(lexeme_id, selector.ordinal);
+        let Some(found) = self.sense(id) else {
+            return Ok(None);
+        };
+        let pos_ok = selector.pos.is_none_or(|tag| {
+            found
+                .part_of_speech()
+                .is_some_and(|pos| sense::pos_matches(tag, pos))
+        });
+        Ok(pos_ok.then_some(id))
+    }
+
+    /// Fallible variant of [`Lexicon::entry_by_word`].
+    pub fn try_entry_by_word(&self, word: &str) -> Result<Option<LexemeEntry<'_>>, LexemeError> {
+        match self.get(word) {
+            Some(id) => self.try_entry_by_id(id),
+            None => Ok(None),
+        }
+    }
+
+    /// Produces detailed score breakdowns for a set of results.
+    pub fn explain_search(
+        &self,
+        query: &str,
+        config: &SearchConfig,
+        results: &[SearchResult],
+    ) -> Vec<SearchBreakdown> {
+        let store = self.entries();
+        results
+            .iter()
+            .filter_map(|row| {
+                store
+                    .entries
+                    .get(row.lexeme_id as usize)
+                    .and_then(|entry| explain_entry(query, self, entry, config))
+            })
+            .collect()
+    }
+
+    /// Traverses the neighbor graph with a depth-limited BFS.
+    pub fn traverse_graph(&self, lexeme_id: u32, options: &GraphOptions) -> Option<GraphTraversal> {
+        let opts = GraphOptions {
+            max_depth: options.max_depth,
+            max_nodes: if options.max_nodes == 0 {
+                usize::MAX
+            } else {
+                options.max_nodes
+            },
+            max_edges: if options.max_edges == 0 {
+                usize::MAX
+            } else {
+                options.max_edges
+            },
+            relations: if options.relations.is_empty() {
                 RelationKind::all().to_vec()
             } else {
                 options.relations.clone()
             },
+            sense_level: options.sense_level,
         };
-        let _ = Self::entry_by_id(lexeme_id)?;
+        let _ = self.entry_by_id(lexeme_id)?;
 
         let mut visited: HashSet<u32> = HashSet::new();
         visited.insert(lexeme_id);
@@ -337,7 +1392,7 @@ impl LexemeIndex {
             if nodes.len() >= opts.max_nodes {
                 break;
             }
-            let entry = match Self::entry_by_id(current_id) {
+            let entry = match self.entry_by_id(current_id) {
                 Some(e) => e,
                 None => continue,
             };
@@ -355,8 +1410,25 @@ impl LexemeIndex {
                 continue;
             }
             for relation in &opts.relations {
-                let neighbors = entry.neighbor_ids(*relation);
-                for neighbor_id in neighbors {
+                let neighbors: Vec<(u32, Option<u32>)> = if opts.sense_level {
+                    entry
+                        .senses()
+                        .enumerate()
+                        .flat_map(|(position, sense)| {
+                            sense
+                                .neighbor_ids(*relation)
+                                .into_iter()
+                                .map(move |id| (id, Some(position as u32)))
+                        })
+                        .collect()
+                } else {
+                    entry
+                        .neighbor_ids(*relation)
+                        .into_iter()
+                        .map(|id| (id, None))
+                        .collect()
+                };
+                for (neighbor_id, sense) in neighbors {
                     if visited.contains(&neighbor_id) {
                         continue;
                     }
@@ -370,6 +1442,7 @@ impl LexemeIndex {
                         from: current_id,
                         to: neighbor_id,
                         relation: *relation,
+                        sense,
                     });
                     visited.insert(neighbor_id);
                     queue.push_back((neighbor_id, depth + 1, Some(current_id), Some(*relation)));
@@ -387,197 +1460,865 @@ impl LexemeIndex {
             max_depth_reached,
         })
     }
-}
 
-fn data_store() -> &'static ArchivedDataStore {
-    *DATA_STORE
-}
+    /// Finds a shortest chain of relation links from `from` to `to`.
+    ///
+    /// Runs a bidirectional BFS: forwards over each entry's neighbor lists and backwards over an
+    /// incoming-link index built from them on first use. Every edge follows a link in its stored
+    /// direction, so `dog → animal` may exist without `animal → dog`. Returns the edges in
+    /// order (empty when `from == to`), or `None` when either ID is unknown or no path fits the
+    /// limits.
+    pub fn shortest_path(
+        &self,
+        from: u32,
+        to: u32,
+        options: &PathOptions,
+    ) -> Option<Vec<GraphEdge>> {
+        self.entry_by_id(from)?;
+        self.entry_by_id(to)?;
+        let relations = if options.relations.is_empty() {
+            RelationKind::all().to_vec()
+        } else {
+            options.relations.clone()
+        };
+        let max_visited = if options.max_visited == 0 {
+            usize::MAX
+        } else {
+            options.max_visited
+        };
+        let forward = |id: u32| {
+            let Some(entry) = self.entry_by_id(id) else {
+                return Vec::new();
+            };
+            relations
+                .iter()
+                .flat_map(|relation| {
+                    entry
+                        .neighbor_ids(*relation)
+                        .into_iter()
+                        .map(|neighbor| (neighbor, *relation))
+                })
+                .collect()
+        };
+        let incoming = self.incoming_neighbors();
+        let backward = |id: u32| {
+            relations
+                .iter()
+                .flat_map(|relation| {
+                    incoming
+                        .sources(id, *relation)
+                        .iter()
+                        .map(|source| (*source, *relation))
+                })
+                .collect()
+        };
+        let links =
+            path::shortest_links(from, to, options.max_hops, max_visited, forward, backward)?;
+        Some(
+            links
+                .into_iter()
+                .map(|(from, to, relation)| GraphEdge {
+                    from,
+                    to,
+                    relation,
+                    sense: None,
+                })
+                .collect(),
+        )
+    }
 
-fn string_cache() -> &'static [OnceLock<&'static str>] {
-    STRING_CACHE.as_slice()
-}
+    fn incoming_neighbors(&self) -> &IncomingNeighbors {
+        self.incoming.get_or_init(|| {
+            let count = self.entries().entries.len();
+            let mut links = Vec::new();
+            for id in 0..count as u32 {
+                let Some(entry) = self.entry_by_id(id) else {
+                    continue;
+                };
+                for relation in RelationKind::all() {
+                    links.extend(
+                        entry
+                            .neighbor_ids(*relation)
+                            .into_iter()
+                            .map(|to| (id, to, *relation)),
+                    );
+                }
+            }
+            IncomingNeighbors::build(count, links)
+        })
+    }
 
-pub struct LexemeEntry<'a> {
-    store: &'a ArchivedDataStore,
-    entry: &'a ArchivedEntryRecord,
-}
+    /// Writes `lexemes.fst`, the normalized, reversed, and inflection FSTs, the trigram and
+    /// full-text indexes, and the uncompressed `opengloss_data.mmap` into `dir`.
+    ///
+    /// The resulting directory can be loaded with [`Lexicon::open`] or [`Lexicon::open_mmap`].
+    /// Files are written to a temporary name and renamed into place so running processes that
+    /// still map the previous version are unaffected.
+    pub fn write_mmap_store(&self, dir: impl AsRef<Path>) -> io::Result<PathBuf> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        write_atomic(&dir.join(LEXEME_FST_FILE), self.map.as_fst().as_bytes())?;
+        write_atomic(
+            &dir.join(NORMALIZED_FST_FILE),
+            self.normalized_map().as_fst().as_bytes(),
+        )?;
+        write_atomic(
+            &dir.join(REVERSED_FST_FILE),
+            self.reversed_map().as_fst().as_bytes(),
+        )?;
+        write_atomic(
+            &dir.join(INFLECTIONS_FST_FILE),
+            self.inflections_map().as_fst().as_bytes(),
+        )?;
+        let trigram_path = dir.join(TRIGRAM_INDEX_FILE);
+        match &self.trigrams {
+            Some(index) => write_atomic(&trigram_path, index.as_bytes())?,
+            None => write_atomic(&trigram_path, &self.build_trigram_index())?,
+        }
+        write_atomic(
+            &dir.join(FULLTEXT_INDEX_FILE),
+            self.fulltext_index().as_bytes(),
+        )?;
+        let path = dir.join(MMAP_STORE_FILE);
+        let sections = [
+            self.store.section(Section::Entries),
+            self.store.section(Section::Senses),
+            self.store.section(Section::Relations),
+            self.store.section(Section::Texts),
+        ];
+        write_atomic(&path, &StoreHeader::encode(0, sections))?;
+        Ok(path)
+    }
 
-impl<'a> LexemeEntry<'a> {
-    pub fn lexeme_id(&self) -> u32 {
-        self.entry.lexeme_id.to_native()
+    fn normalized_map(&self) -> &Map<ArtifactBytes> {
+        self.normalized.get_or_init(|| {
+            let mut rows = Vec::with_capacity(self.map.len());
+            let mut stream = self.map.stream();
+            while let Some((key, value)) = stream.next() {
+                if let Ok(word) = str::from_utf8(key) {
+                    rows.push((word.to_owned(), value as u32));
+                }
+            }
+            let bytes =
+                normalize::build_normalized_fst(rows.iter().map(|(word, id)| (word.as_str(), *id)))
+                    .expect("build normalized FST");
+            Map::new(ArtifactBytes::Owned(bytes)).expect("valid normalized FST")
+        })
     }
 
-    pub fn word(&self) -> &'a str {
-        self.store.string_from_archived(self.entry.word)
+    fn reversed_map(&self) -> &Map<ArtifactBytes> {
+        self.reversed.get_or_init(|| {
+            let mut rows = Vec::with_capacity(self.map.len());
+            let mut stream = self.map.stream();
+            while let Some((key, value)) = stream.next() {
+                if let Ok(word) = str::from_utf8(key) {
+                    rows.push((word.to_owned(), value as u32));
+                }
+            }
+            let bytes =
+                normalize::build_reversed_fst(rows.iter().map(|(word, id)| (word.as_str(), *id)))
+                    .expect("build reversed FST");
+            Map::new(ArtifactBytes::Owned(bytes)).expect("valid reversed FST")
+        })
     }
 
-    pub fn text(&self) -> Option<String> {
-        self.entry
-            .text
-            .as_ref()
-            .map(|id| self.store.decompress_long_text(*id))
+    fn inflections_map(&self) -> &Map<ArtifactBytes> {
+        self.inflections.get_or_init(|| {
+            let mut rows = Vec::new();
+            for id in 0..self.entries().entries.len() as u32 {
+                let Some(entry) = self.entry_by_id(id) else {
+                    continue;
+                };
+                rows.extend(
+                    entry
+                        .all_inflections()
+                        .enumerate()
+                        .map(|(idx, form)| (form.to_owned(), id, idx as u32)),
+                );
+            }
+            let bytes = normalize::build_inflection_fst(
+                rows.iter()
+                    .map(|(form, id, idx)| (form.as_str(), *id, *idx)),
+            )
+            .expect("build inflection FST");
+            Map::new(ArtifactBytes::Owned(bytes)).expect("valid inflection FST")
+        })
     }
 
-    pub fn entry_id(&self) -> &'a str {
-        self.store.string_from_archived(self.entry.entry_id)
+    fn build_trigram_index(&self) -> Vec<u8> {
+        let mut rows = Vec::with_capacity(self.map.len());
+        let mut stream = self.map.stream();
+        while let Some((key, value)) = stream.next() {
+            if let Ok(word) = str::from_utf8(key) {
+                rows.push((word.to_owned(), value as u32));
+            }
+        }
+        trigram::build_trigram_index(rows.iter().map(|(word, id)| (word.as_str(), *id)))
     }
 
-    pub fn is_stopword(&self) -> bool {
-        self.entry.is_stopword
+    fn fulltext_index(&self) -> &FullTextIndex<ArtifactBytes> {
+        self.fulltext.get_or_init(|| {
+            let mut builder = FullTextBuilder::default();
+            let count = self.entries().entries.len();
+            for id in 0..count as u32 {
+                let Some(entry) = self.entry_by_id(id) else {
+                    continue;
+                };
+                builder.add(id, fulltext::Field::Word, entry.word());
+                for definition in entry.all_definitions() {
+                    builder.add(id, fulltext::Field::Definitions, definition);
+                }
+                for synonym in entry.all_synonyms() {
+                    builder.add(id, fulltext::Field::Synonyms, synonym);
+                }
+                for example in entry.all_examples() {
+                    builder.add(id, fulltext::Field::Examples, example);
+                }
+                if let Some(text) = entry.text() {
+                    builder.add(id, fulltext::Field::Text, &text);
+                }
+                if let Some(article) = entry.encyclopedia_entry() {
+                    builder.add(id, fulltext::Field::Encyclopedia, &article);
+                }
+                for collocation in entry.all_collocations() {
+                    builder.add(id, fulltext::Field::Collocations, collocation);
+                }
+                for inflection in entry.all_inflections() {
+                    builder.add(id, fulltext::Field::Inflections, inflection);
+                }
+                for derivation in entry.all_derivations() {
+                    builder.add(id, fulltext::Field::Derivations, derivation);
+                }
+                for antonym in entry.all_antonyms() {
+                    builder.add(id, fulltext::Field::Antonyms, antonym);
+                }
+                for hypernym in entry.all_hypernyms() {
+                    builder.add(id, fulltext::Field::Hypernyms, hypernym);
+                }
+                if let Some(summary) = entry.etymology_summary() {
+                    builder.add(id, fulltext::Field::Etymology, summary);
+                }
+            }
+            let bytes = builder.finish(count).expect("build full-text index");
+            FullTextIndex::new(ArtifactBytes::Owned(bytes)).expect("valid full-text index")
+        })
     }
 
-    pub fn stopword_reason(&self) -> Option<&'a str> {
-        self.entry
-            .stopword_reason
-            .as_ref()
-            .map(|id| self.store.string_from_archived(*id))
+    fn entries(&self) -> &ArchivedEntrySection {
+        self.try_entries().expect("load entries section")
     }
 
-    pub fn parts_of_speech(&'a self) -> impl Iterator<Item = &'a str> + 'a {
-        string_iter(
-            self.store,
-            &self.entry.parts_of_speech,
-            self.store.entry_parts_of_speech.as_slice(),
-        )
+    fn try_entries(&self) -> Result<&ArchivedEntrySection, LexemeError> {
+        let bytes = self.store.try_section(Section::Entries)?;
+        Ok(unsafe { access_unchecked::<ArchivedEntrySection>(bytes) })
     }
 
-    pub fn senses(&'a self) -> SenseIter<'a> {
-        let slice = range_slice(self.store.senses.as_slice(), &self.entry.senses);
-        SenseIter {
-            store: self.store,
-            senses: slice,
-            index: 0,
-        }
+    fn senses(&self) -> &ArchivedSenseSection {
+        unsafe { access_unchecked::<ArchivedSenseSection>(self.store.section(Section::Senses)) }
     }
 
-    pub fn etymology_summary(&self) -> Option<&'a str> {
-        self.entry
-            .etymology_summary
-            .as_ref()
-            .map(|id| self.store.string_from_archived(*id))
+    fn relations(&self) -> &ArchivedRelationSection {
+        unsafe {
+            access_unchecked::<ArchivedRelationSection>(self.store.section(Section::Relations))
+        }
     }
 
-    pub fn has_etymology(&self) -> bool {
-        self.entry.has_etymology
+    fn try_texts(&self) -> Result<&ArchivedTextSection, LexemeError> {
+        let bytes = self.store.try_section(Section::Texts)?;
+        Ok(unsafe { access_unchecked::<ArchivedTextSection>(bytes) })
     }
 
-    pub fn etymology_cognates(&'a self) -> impl Iterator<Item = &'a str> + 'a {
-        string_iter(
-            self.store,
-            &self.entry.etymology_cognates,
-            self.store.entry_etymology_cognates.as_slice(),
-        )
+    fn string(&self, id: ArchivedStringId) -> &str {
+        self.try_string(id).expect("string chunk decompresses")
     }
 
-    pub fn encyclopedia_entry(&self) -> Option<String> {
-        self.entry
-            .encyclopedia_entry
-            .as_ref()
-            .map(|id| self.store.decompress_long_text(*id))
+    fn try_string(&self, id: ArchivedStringId) -> Result<&str, LexemeError> {
+        let entries = self.try_entries()?;
+        let idx = id.to_native() as usize;
+        let cache = self.strings.get_or_init(|| {
+            let len = entries.strings.len();
+            (0..len).map(|_| OnceLock::new()).collect()
+        });
+        let slot = cache.get(idx).ok_or(LexemeError::CorruptStore(format!(
+            "string #{idx} is out of range"
+        )))?;
+        if let Some(value) = slot.get() {
+            return Ok(value);
+        }
+        let dictionary = self
+            .string_dictionary
+            .get_or_init(|| prepare_dictionary(entries.strings.dictionary.as_slice()));
+        let value = entries.strings.decompress(idx, dictionary.as_ref())?;
+        Ok(slot.get_or_init(|| value.into_boxed_str()))
     }
 
-    pub fn has_encyclopedia(&self) -> bool {
-        self.entry.has_encyclopedia
+    fn long_text(&self, id: ArchivedTextId) -> String {
+        self.try_long_text(id)
+            .expect("long text chunk decompresses")
     }
 
-    pub fn all_definitions(&'a self) -> impl Iterator<Item = &'a str> + 'a {
-        string_iter(
-            self.store,
-            &self.entry.all_definitions,
-            self.store.entry_all_definitions.as_slice(),
-        )
+    fn try_long_text(&self, id: ArchivedTextId) -> Result<String, LexemeError> {
+        let long_texts = &self.try_texts()?.long_texts;
+        let dictionary = self
+            .text_dictionary
+            .get_or_init(|| prepare_dictionary(long_texts.dictionary.as_slice()));
+        long_texts.decompress(id, dictionary.as_ref())
     }
+}
 
-    pub fn all_synonyms(&'a self) -> impl Iterator<Item = &'a str> + 'a {
-        string_iter(
-            self.store,
-            &self.entry.all_synonyms,
-            self.store.entry_all_synonyms.as_slice(),
-        )
+impl SectionStore {
+    fn new(bytes: ArtifactBytes) -> Result<Self, LexemeError> {
+        let header = StoreHeader::decode(bytes.as_ref()).map_err(LexemeError::CorruptStore)?;
+        Ok(Self {
+            bytes,
+            header,
+            loaded: std::array::from_fn(|_| OnceLock::new()),
+        })
     }
 
-    pub fn all_antonyms(&'a self) -> impl Iterator<Item = &'a str> + 'a {
-        string_iter(
-            self.store,
-            &self.entry.all_antonyms,
-            self.store.entry_all_antonyms.as_slice(),
-        )
+    fn section(&self, section: Section) -> &[u8] {
+        self.try_section(section)
+            .expect("load opengloss data section")
     }
 
-    pub fn all_hypernyms(&'a self) -> impl Iterator<Item = &'a str> + 'a {
-        string_iter(
-            self.store,
-            &self.entry.all_hypernyms,
-            self.store.entry_all_hypernyms.as_slice(),
-        )
+    /// Returns the aligned, uncompressed bytes of `section`, inflating it on first use.
+    fn try_section(&self, section: Section) -> Result<&[u8], LexemeError> {
+        let raw = self.header.section(self.bytes.as_ref(), section);
+        if !self.header.is_compressed() && raw.as_ptr().align_offset(SECTION_ALIGN) == 0 {
+            return Ok(raw);
+        }
+        let loaded = self.loaded[section as usize].get_or_init(|| {
+            let decompressed = if self.header.is_compressed() {
+                decode_all(Cursor::new(raw)).map_err(|err| {
+                    format!("failed to decompress {} section: {err}", section.name())
+                })?
+            } else {
+                raw.to_vec()
+            };
+            let mut aligned = AlignedVec::with_capacity(decompressed.len());
+            aligned.extend_from_slice(&decompressed);
+            Ok(aligned)
+        });
+        match loaded {
+            Ok(aligned) => Ok(aligned.as_slice()),
+            Err(msg) => Err(LexemeError::CorruptStore(msg.clone())),
+        }
     }
 
-    pub fn all_hyponyms(&'a self) -> impl Iterator<Item = &'a str> + 'a {
-        string_iter(
-            self.store,
-            &self.entry.all_hyponyms,
-            self.store.entry_all_hyponyms.as_slice(),
-        )
+    #[cfg(all(test, feature = "embedded-data"))]
+    fn is_loaded(&self, section: Section) -> bool {
+        self.loaded[section as usize].get().is_some()
     }
+}
 
-    pub fn all_collocations(&'a self) -> impl Iterator<Item = &'a str> + 'a {
-        string_iter(
-            self.store,
-            &self.entry.all_collocations,
-            self.store.entry_all_collocations.as_slice(),
-        )
+/// Checks `bytes` against the archived type stored in `section`.
+fn check_section(section: Section, bytes: &[u8]) -> Result<(), String> {
+    let checked = match section {
+        Section::Entries => rkyv::access::<ArchivedEntrySection, RkyvError>(bytes).map(drop),
+        Section::Senses => rkyv::access::<ArchivedSenseSection, RkyvError>(bytes).map(drop),
+        Section::Relations => rkyv::access::<ArchivedRelationSection, RkyvError>(bytes).map(drop),
+        Section::Texts => rkyv::access::<ArchivedTextSection, RkyvError>(bytes).map(drop),
+    };
+    checked.map_err(|err| format!("{} section: {err}", section.name()))
+}
+
+/// Lookup artifacts that can be derived from `lexemes.fst` (or, for the full-text index and the
+/// inflection FST, the data store) when a data directory lacks them.
+#[derive(Default)]
+struct DerivedFsts {
+    normalized: Option<ArtifactBytes>,
+    reversed: Option<ArtifactBytes>,
+    inflections: Option<ArtifactBytes>,
+    trigrams: Option<ArtifactBytes>,
+    fulltext: Option<ArtifactBytes>,
+}
+
+/// Scans a normalized-layout FST (`normalized \0 id` keys) for every key of `word`'s normalized
+/// form, yielding `(lexeme_id, value)` pairs in ascending ID order.
+fn normalized_matches(map: &Map<ArtifactBytes>, word: &str) -> Vec<(u32, u64)> {
+    let normalized = normalize_key(word);
+    if normalized.is_empty() {
+        return Vec::new();
     }
+    // Every key for this form sorts between `normalized \0` and `normalized \1`.
+    let mut lower = normalized.into_bytes();
+    lower.push(normalize::KEY_SEPARATOR);
+    let mut upper = lower.clone();
+    if let Some(last) = upper.last_mut() {
+        *last = normalize::KEY_SEPARATOR + 1;
+    }
+    let mut stream = map.range().ge(&lower).lt(&upper).into_stream();
+    let mut matches = Vec::new();
+    while let Some((key, value)) = stream.next() {
+        if let Some((_, id)) = key.split_last_chunk::<4>() {
+            matches.push((u32::from_be_bytes(*id), value));
+        }
+    }
+    matches
+}
 
-    pub fn all_inflections(&'a self) -> impl Iterator<Item = &'a str> + 'a {
-        string_iter(
-            self.store,
-            &self.entry.all_inflections,
-            self.store.entry_all_inflections.as_slice(),
-        )
+fn preloaded_map(
+    bytes: Option<ArtifactBytes>,
+) -> Result<OnceLock<Map<ArtifactBytes>>, LexemeError> {
+    Ok(match bytes {
+        Some(bytes) => OnceLock::from(Map::new(bytes)?),
+        None => OnceLock::new(),
+    })
+}
+
+fn map_optional(path: &Path) -> io::Result<Option<Mmap>> {
+    if path.is_file() {
+        map_file(path).map(Some)
+    } else {
+        Ok(None)
     }
+}
 
-    pub fn all_derivations(&'a self) -> impl Iterator<Item = &'a str> + 'a {
-        string_iter(
-            self.store,
-            &self.entry.all_derivations,
-            self.store.entry_all_derivations.as_slice(),
-        )
+fn read_optional(path: &Path) -> io::Result<Option<Vec<u8>>> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(Some(bytes)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
     }
+}
 
-    pub fn all_examples(&'a self) -> impl Iterator<Item = &'a str> + 'a {
-        string_iter(
-            self.store,
-            &self.entry.all_examples,
-            self.store.entry_all_examples.as_slice(),
-        )
+fn map_file(path: &Path) -> io::Result<Mmap> {
+    let file = File::open(path)?;
+    // SAFETY: the artifacts are treated as read-only; writers replace them via rename instead of
+    // truncating in place, so an existing mapping never observes a partial file.
+    unsafe { Mmap::map(&file) }
+}
+
+fn write_atomic(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+    fs::write(&tmp, bytes)?;
+    fs::rename(&tmp, path)
+}
+
+impl LexemeIndex {
+    /// Loads the artifacts in `dir` (see [`Lexicon::open`]) instead of the embedded ones.
+    ///
+    /// Must be called before the first lookup; once the index is initialized it cannot be swapped.
+    pub fn open(dir: impl AsRef<Path>) -> Result<(), LexemeError> {
+        Self::install(Lexicon::open(dir)?)
     }
 
-    pub fn synonym_neighbor_ids(&'a self) -> impl Iterator<Item = u32> + 'a {
-        id_iter(
-            &self.entry.synonym_neighbors,
-            self.store.entry_synonym_neighbors.as_slice(),
-        )
+    /// Like [`LexemeIndex::open`], but validates the data store first (see
+    /// [`Lexicon::open_validated`]).
+    pub fn open_validated(dir: impl AsRef<Path>) -> Result<(), LexemeError> {
+        Self::install(Lexicon::open_validated(dir)?)
     }
 
-    pub fn antonym_neighbor_ids(&'a self) -> impl Iterator<Item = u32> + 'a {
-        id_iter(
-            &self.entry.antonym_neighbors,
-            self.store.entry_antonym_neighbors.as_slice(),
-        )
+    fn install(lexicon: Lexicon) -> Result<(), LexemeError> {
+        DEFAULT_LEXICON
+            .set(lexicon)
+            .map_err(|_| LexemeError::AlreadyInitialized)
     }
 
-    pub fn hypernym_neighbor_ids(&'a self) -> impl Iterator<Item = u32> + 'a {
-        id_iter(
-            &self.entry.hypernym_neighbors,
-            self.store.entry_hypernym_neighbors.as_slice(),
-        )
+    /// Returns the process-wide lexicon backing the associated functions.
+    ///
+    /// # Panics
+    ///
+    /// When no lexicon was installed and the fallback (see [`LexemeIndex::try_lexicon`]) fails.
+    pub fn lexicon() -> &'static Lexicon {
+        Self::try_lexicon().unwrap_or_else(|err| panic!("load OpenGloss data: {err}"))
     }
 
-    pub fn hyponym_neighbor_ids(&'a self) -> impl Iterator<Item = u32> + 'a {
-        id_iter(
-            &self.entry.hyponym_neighbors,
-            self.store.entry_hyponym_neighbors.as_slice(),
-        )
+    /// Returns the process-wide lexicon, loading it from `$OPENGLOSS_DATA_DIR` or the embedded
+    /// artifacts on first use, and reporting a missing or unreadable dataset as an error.
+    pub fn try_lexicon() -> Result<&'static Lexicon, LexemeError> {
+        if let Some(lexicon) = DEFAULT_LEXICON.get() {
+            return Ok(lexicon);
+        }
+        let lexicon = default_lexicon()?;
+        Ok(DEFAULT_LEXICON.get_or_init(|| lexicon))
+    }
+
+    /// Returns the lexeme ID for an exact word match.
+    pub fn get(word: &str) -> Option<u32> {
+        Self::lexicon().get(word)
+    }
+
+    /// Returns up to `limit` lexemes that start with the provided prefix.
+    pub fn prefix(prefix: &str, limit: usize) -> Vec<(String, u32)> {
+        Self::lexicon().prefix(prefix, limit)
+    }
+
+    /// Returns every lexeme ID whose word matches `word` after [`normalize_key`] folding.
+    pub fn get_normalized(word: &str) -> Vec<u32> {
+        Self::lexicon().get_normalized(word)
+    }
+
+    /// Maps a surface form (headword or inflection) back to its lexemes (see
+    /// [`Lexicon::lemmatize`]).
+    pub fn lemmatize(form: &str) -> Vec<(u32, InflectionMatch)> {
+        Self::lexicon().lemmatize(form)
+    }
+
+    /// Returns up to `limit` lexemes whose normalized form starts with the normalized `prefix`.
+    pub fn prefix_normalized(prefix: &str, limit: usize) -> Vec<(String, u32)> {
+        Self::lexicon().prefix_normalized(prefix, limit)
+    }
+
+    /// Returns up to `limit` lexemes that end with the provided suffix.
+    pub fn suffix(suffix: &str, limit: usize) -> Vec<(String, u32)> {
+        Self::lexicon().suffix(suffix, limit)
+    }
+
+    /// One page of lexemes starting with `prefix` (see [`Lexicon::prefix_page`]).
+    pub fn prefix_page(
+        prefix: &str,
+        cursor: Option<&SearchCursor>,
+        limit: usize,
+    ) -> Page<(String, u32)> {
+        Self::lexicon().prefix_page(prefix, cursor, limit)
+    }
+
+    /// Fallible variant of [`LexemeIndex::prefix`].
+    pub fn try_prefix(prefix: &str, limit: usize) -> Result<Vec<(String, u32)>, LexemeError> {
+        Self::lexicon().try_prefix(prefix, limit)
+    }
+
+    /// Performs a substring search over all lexemes.
+    pub fn search_contains(pattern: &str, limit: usize) -> Vec<(String, u32)> {
+        Self::lexicon().search_contains(pattern, limit)
+    }
+
+    /// Returns up to `limit` lexemes whose whole word matches a glob or regex pattern.
+    pub fn search_pattern(
+        query: &PatternQuery,
+        limit: usize,
+    ) -> Result<Vec<(String, u32)>, LexemeError> {
+        Self::lexicon().search_pattern(query, limit)
+    }
+
+    /// Returns up to `limit` lexemes within `max_edits` edits of `word`.
+    pub fn suggest(word: &str, max_edits: u32, limit: usize) -> Vec<Suggestion> {
+        Self::lexicon().suggest(word, max_edits, limit)
+    }
+
+    /// Returns all lexemes (word + ID) in lexicographic order.
+    pub fn all_words() -> &'static [(String, u32)] {
+        Self::lexicon().all_words()
+    }
+
+    /// Performs a weighted fuzzy search over all entries.
+    pub fn search_fuzzy(query: &str, config: &SearchConfig, limit: usize) -> Vec<SearchResult> {
+        Self::lexicon().search_fuzzy(query, config, limit)
+    }
+
+    /// Performs a weighted fuzzy search and returns cache insights.
+    pub fn search_fuzzy_with_stats(
+        query: &str,
+        config: &SearchConfig,
+        limit: usize,
+    ) -> SearchSummary {
+        Self::lexicon().search_fuzzy_with_stats(query, config, limit)
+    }
+
+    /// One page of weighted fuzzy results (see [`Lexicon::search_fuzzy_page`]).
+    pub fn search_fuzzy_page(
+        query: &str,
+        config: &SearchConfig,
+        cursor: Option<&SearchCursor>,
+        limit: usize,
+    ) -> SearchSummary {
+        Self::lexicon().search_fuzzy_page(query, config, cursor, limit)
+    }
+
+    /// One page of substring matches (see [`Lexicon::search_contains_page`]).
+    pub fn search_contains_page(
+        pattern: &str,
+        cursor: Option<&SearchCursor>,
+        limit: usize,
+    ) -> Page<(String, u32)> {
+        Self::lexicon().search_contains_page(pattern, cursor, limit)
+    }
+
+    /// Filtered substring search with paging and facet counts (see
+    /// [`Lexicon::search_contains_filtered`]).
+    pub fn search_contains_filtered(
+        pattern: &str,
+        filters: &SearchFilters,
+        cursor: Option<&SearchCursor>,
+        limit: usize,
+    ) -> (Page<(String, u32)>, SearchFacets) {
+        Self::lexicon().search_contains_filtered(pattern, filters, cursor, limit)
+    }
+
+    /// Ranks entries with BM25 over the full-text index (see [`Lexicon::search_text`]).
+    pub fn search_text(query: &str, config: &SearchConfig, limit: usize) -> Vec<SearchResult> {
+        Self::lexicon().search_text(query, config, limit)
+    }
+
+    /// Finds entries whose definitions describe `description` (see [`Lexicon::reverse_lookup`]).
+    pub fn reverse_lookup(description: &str, limit: usize) -> Vec<ReverseMatch> {
+        Self::lexicon().reverse_lookup(description, limit)
+    }
+
+    /// Runs a structured query (see [`Query`] and [`Lexicon::run_query`]).
+    pub fn query(query: &str, limit: usize) -> Result<Vec<SearchResult>, LexemeError> {
+        Self::lexicon().query(query, limit)
+    }
+
+    /// Returns the lexeme entry for the given ID, if available.
+    pub fn entry_by_id(lexeme_id: u32) -> Option<LexemeEntry<'static>> {
+        Self::lexicon().entry_by_id(lexeme_id)
+    }
+
+    /// Fallible variant of [`LexemeIndex::entry_by_id`].
+    pub fn try_entry_by_id(lexeme_id: u32) -> Result<Option<LexemeEntry<'static>>, LexemeError> {
+        Self::lexicon().try_entry_by_id(lexeme_id)
+    }
+
+    /// Resolves a word to its entry.
+    pub fn entry_by_word(word: &str) -> Option<LexemeEntry<'static>> {
+        Self::lexicon().entry_by_word(word)
+    }
+
+    /// Fallible variant of [`LexemeIndex::entry_by_word`].
+    pub fn try_entry_by_word(word: &str) -> Result<Option<LexemeEntry<'static>>, LexemeError> {
+        Self::lexicon().try_entry_by_word(word)
+    }
+
+    /// Returns the sense addressed by `id` (see [`Lexicon::sense`]).
+    pub fn sense(id: SenseId) -> Option<SenseRef<'static>> {
+        Self::lexicon().sense(id)
+    }
+
+    /// Resolves `dog#2` / `dog#n.2` to a [`SenseId`] (see [`Lexicon::resolve_sense`]).
+    pub fn resolve_sense(reference: &str) -> Result<Option<SenseId>, LexemeError> {
+        Self::lexicon().resolve_sense(reference)
+    }
+
+    /// Produces detailed score breakdowns for a set of results.
+    pub fn explain_search(
+        query: &str,
+        config: &SearchConfig,
+        results: &[SearchResult],
+    ) -> Vec<SearchBreakdown> {
+        Self::lexicon().explain_search(query, config, results)
+    }
+
+    /// Traverses the neighbor graph with a depth-limited BFS.
+    pub fn traverse_graph(lexeme_id: u32, options: &GraphOptions) -> Option<GraphTraversal> {
+        Self::lexicon().traverse_graph(lexeme_id, options)
+    }
+
+    /// Finds a shortest chain of relation links between two lexemes (see
+    /// [`Lexicon::shortest_path`]).
+    pub fn shortest_path(from: u32, to: u32, options: &PathOptions) -> Option<Vec<GraphEdge>> {
+        Self::lexicon().shortest_path(from, to, options)
+    }
+}
+
+fn default_lexicon() -> Result<Lexicon, LexemeError> {
+    if let Some(dir) = std::env::var_os(DATA_DIR_ENV) {
+        return Lexicon::open(&dir);
+    }
+    #[cfg(feature = "embedded-data")]
+    {
+        Ok(Lexicon::embedded())
+    }
+    #[cfg(not(feature = "embedded-data"))]
+    {
+        Err(LexemeError::NoData)
+    }
+}
+
+pub struct LexemeEntry<'a> {
+    lexicon: &'a Lexicon,
+    entry: &'a ArchivedEntryRecord,
+}
+
+impl<'a> LexemeEntry<'a> {
+    pub fn lexeme_id(&self) -> u32 {
+        self.entry.lexeme_id.to_native()
+    }
+
+    pub fn word(&self) -> &'a str {
+        self.lexicon.string(self.entry.word)
+    }
+
+    pub fn text(&self) -> Option<String> {
+        self.entry
+            .text
+            .as_ref()
+            .map(|id| self.lexicon.long_text(*id))
+    }
+
+    /// Fallible variant of [`LexemeEntry::text`].
+    pub fn try_text(&self) -> Result<Option<String>, LexemeError> {
+        self.entry
+            .text
+            .as_ref()
+            .map(|id| self.lexicon.try_long_text(*id))
+            .transpose()
+    }
+
+    pub fn entry_id(&self) -> &'a str {
+        self.lexicon.string(self.entry.entry_id)
+    }
+
+    pub fn is_stopword(&self) -> bool {
+        self.entry.is_stopword
+    }
+
+    pub fn stopword_reason(&self) -> Option<&'a str> {
+        self.entry
+            .stopword_reason
+            .as_ref()
+            .map(|id| self.lexicon.string(*id))
+    }
+
+    pub fn parts_of_speech(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        string_iter(
+            self.lexicon,
+            &self.entry.parts_of_speech,
+            self.lexicon.entries().entry_parts_of_speech.as_slice(),
+        )
+    }
+
+    pub fn senses(&'a self) -> SenseIter<'a> {
+        let slice = range_slice(self.lexicon.senses().senses.as_slice(), &self.entry.senses)
+            .unwrap_or_default();
+        SenseIter {
+            lexicon: self.lexicon,
+            senses: slice,
+            index: 0,
+        }
+    }
+
+    pub fn etymology_summary(&self) -> Option<&'a str> {
+        self.entry
+            .etymology_summary
+            .as_ref()
+            .map(|id| self.lexicon.string(*id))
+    }
+
+    pub fn has_etymology(&self) -> bool {
+        self.entry.has_etymology
+    }
+
+    pub fn etymology_cognates(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        string_iter(
+            self.lexicon,
+            &self.entry.etymology_cognates,
+            self.lexicon.entries().entry_etymology_cognates.as_slice(),
+        )
+    }
+
+    pub fn encyclopedia_entry(&self) -> Option<String> {
+        self.entry
+            .encyclopedia_entry
+            .as_ref()
+            .map(|id| self.lexicon.long_text(*id))
+    }
+
+    pub fn has_encyclopedia(&self) -> bool {
+        self.entry.has_encyclopedia
+    }
+
+    pub fn all_definitions(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        string_iter(
+            self.lexicon,
+            &self.entry.all_definitions,
+            self.lexicon.entries().entry_all_definitions.as_slice(),
+        )
+    }
+
+    pub fn all_synonyms(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        string_iter(
+            self.lexicon,
+            &self.entry.all_synonyms,
+            self.lexicon.entries().entry_all_synonyms.as_slice(),
+        )
+    }
+
+    pub fn all_antonyms(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        string_iter(
+            self.lexicon,
+            &self.entry.all_antonyms,
+            self.lexicon.entries().entry_all_antonyms.as_slice(),
+        )
+    }
+
+    pub fn all_hypernyms(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        string_iter(
+            self.lexicon,
+            &self.entry.all_hypernyms,
+            self.lexicon.entries().entry_all_hypernyms.as_slice(),
+        )
+    }
+
+    pub fn all_hyponyms(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        string_iter(
+            self.lexicon,
+            &self.entry.all_hyponyms,
+            self.lexicon.entries().entry_all_hyponyms.as_slice(),
+        )
+    }
+
+    pub fn all_collocations(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        string_iter(
+            self.lexicon,
+            &self.entry.all_collocations,
+            self.lexicon.entries().entry_all_collocations.as_slice(),
+        )
+    }
+
+    pub fn all_inflections(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        string_iter(
+            self.lexicon,
+            &self.entry.all_inflections,
+            self.lexicon.entries().entry_all_inflections.as_slice(),
+        )
+    }
+
+    pub fn all_derivations(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        string_iter(
+            self.lexicon,
+            &self.entry.all_derivations,
+            self.lexicon.entries().entry_all_derivations.as_slice(),
+        )
+    }
+
+    pub fn all_examples(&'a self) -> impl Iterator<Item = &'a str> + 'a {
+        string_iter(
+            self.lexicon,
+            &self.entry.all_examples,
+            self.lexicon.entries().entry_all_examples.as_slice(),
+        )
+    }
+
+    pub fn synonym_neighbor_ids(&'a self) -> impl Iterator<Item = u32> + 'a {
+        id_iter(
+            &self.entry.synonym_neighbors,
+            self.lexicon.relations().entry_synonym_neighbors.as_slice(),
+        )
+    }
+
+    pub fn antonym_neighbor_ids(&'a self) -> impl Iterator<Item = u32> + 'a {
+        id_iter(
+            &self.entry.antonym_neighbors,
+            self.lexicon.relations().entry_antonym_neighbors.as_slice(),
+        )
+    }
+
+    pub fn hypernym_neighbor_ids(&'a self) -> impl Iterator<Item = u32> + 'a {
+        id_iter(
+            &self.entry.hypernym_neighbors,
+            self.lexicon.relations().entry_hypernym_neighbors.as_slice(),
+        )
+    }
+
+    pub fn hyponym_neighbor_ids(&'a self) -> impl Iterator<Item = u32> + 'a {
+        id_iter(
+            &self.entry.hyponym_neighbors,
+            self.lexicon.relations().entry_hyponym_neighbors.as_slice(),
+        )
     }
 
     pub fn neighbor_ids(&'a self, relation: RelationKind) -> Vec<u32> {
@@ -590,14 +2331,43 @@ impl<'a> LexemeEntry<'a> {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Fuzzy and full-text search tuning.
+///
+/// Serializes with the field names below; missing fields take their [`Default`] values, so a
+/// JSON profile only needs the settings it changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SearchConfig {
     pub weight_word: f32,
     pub weight_definitions: f32,
     pub weight_synonyms: f32,
     pub weight_text: f32,
     pub weight_encyclopedia: f32,
+    pub weight_collocations: f32,
+    pub weight_examples: f32,
+    pub weight_inflections: f32,
+    pub weight_derivations: f32,
+    pub weight_antonyms: f32,
+    pub weight_hypernyms: f32,
+    pub weight_etymology: f32,
     pub min_score: f32,
+    pub scorer_word: FuzzyScorer,
+    pub scorer_definitions: FuzzyScorer,
+    pub scorer_synonyms: FuzzyScorer,
+    pub scorer_text: FuzzyScorer,
+    pub scorer_encyclopedia: FuzzyScorer,
+    pub scorer_collocations: FuzzyScorer,
+    pub scorer_examples: FuzzyScorer,
+    pub scorer_inflections: FuzzyScorer,
+    pub scorer_derivations: FuzzyScorer,
+    pub scorer_antonyms: FuzzyScorer,
+    pub scorer_hypernyms: FuzzyScorer,
+    pub scorer_etymology: FuzzyScorer,
+    /// How many candidates each pre-filter (word trigrams, full-text postings) may contribute
+    /// before fuzzy scoring; 0 scores every entry.
+    pub candidate_budget: usize,
+    /// Entry facets every fuzzy result must have.
+    pub filters: SearchFilters,
 }
 
 impl Default for SearchConfig {
@@ -608,18 +2378,132 @@ impl Default for SearchConfig {
             weight_synonyms: 1.0,
             weight_text: 1.5,
             weight_encyclopedia: 1.5,
+            weight_collocations: 0.0,
+            weight_examples: 0.0,
+            weight_inflections: 0.0,
+            weight_derivations: 0.0,
+            weight_antonyms: 0.0,
+            weight_hypernyms: 0.0,
+            weight_etymology: 0.0,
             min_score: 0.15,
+            scorer_word: FuzzyScorer::Ratio,
+            scorer_definitions: FuzzyScorer::Ratio,
+            scorer_synonyms: FuzzyScorer::Ratio,
+            scorer_text: FuzzyScorer::Ratio,
+            scorer_encyclopedia: FuzzyScorer::Ratio,
+            scorer_collocations: FuzzyScorer::Ratio,
+            scorer_examples: FuzzyScorer::Ratio,
+            scorer_inflections: FuzzyScorer::Ratio,
+            scorer_derivations: FuzzyScorer::Ratio,
+            scorer_antonyms: FuzzyScorer::Ratio,
+            scorer_hypernyms: FuzzyScorer::Ratio,
+            scorer_etymology: FuzzyScorer::Ratio,
+            candidate_budget: DEFAULT_CANDIDATE_BUDGET,
+            filters: SearchFilters::default(),
         }
     }
 }
 
 impl SearchConfig {
     pub fn total_weight(&self) -> f32 {
-        self.weight_word
-            + self.weight_definitions
-            + self.weight_synonyms
-            + self.weight_text
-            + self.weight_encyclopedia
+        FieldKind::ALL.iter().map(|field| self.weight(*field)).sum()
+    }
+
+    /// The weight of `field` in fuzzy search (and its boost in full-text search); 0 skips it.
+    pub fn weight(&self, field: FieldKind) -> f32 {
+        match field {
+            FieldKind::Word => self.weight_word,
+            FieldKind::Definitions => self.weight_definitions,
+            FieldKind::Synonyms => self.weight_synonyms,
+            FieldKind::Text => self.weight_text,
+            FieldKind::Encyclopedia => self.weight_encyclopedia,
+            FieldKind::Collocations => self.weight_collocations,
+            FieldKind::Examples => self.weight_examples,
+            FieldKind::Inflections => self.weight_inflections,
+            FieldKind::Derivations => self.weight_derivations,
+            FieldKind::Antonyms => self.weight_antonyms,
+            FieldKind::Hypernyms => self.weight_hypernyms,
+            FieldKind::Etymology => self.weight_etymology,
+        }
+    }
+
+    pub fn set_weight(&mut self, field: FieldKind, weight: f32) {
+        let slot = match field {
+            FieldKind::Word => &mut self.weight_word,
+            FieldKind::Definitions => &mut self.weight_definitions,
+            FieldKind::Synonyms => &mut self.weight_synonyms,
+            FieldKind::Text => &mut self.weight_text,
+            FieldKind::Encyclopedia => &mut self.weight_encyclopedia,
+            FieldKind::Collocations => &mut self.weight_collocations,
+            FieldKind::Examples => &mut self.weight_examples,
+            FieldKind::Inflections => &mut self.weight_inflections,
+            FieldKind::Derivations => &mut self.weight_derivations,
+            FieldKind::Antonyms => &mut self.weight_antonyms,
+            FieldKind::Hypernyms => &mut self.weight_hypernyms,
+            FieldKind::Etymology => &mut self.weight_etymology,
+        };
+        *slot = weight;
+    }
+
+    /// The scorer used for `field` in fuzzy search.
+    pub fn scorer(&self, field: FieldKind) -> FuzzyScorer {
+        match field {
+            FieldKind::Word => self.scorer_word,
+            FieldKind::Definitions => self.scorer_definitions,
+            FieldKind::Synonyms => self.scorer_synonyms,
+            FieldKind::Text => self.scorer_text,
+            FieldKind::Encyclopedia => self.scorer_encyclopedia,
+            FieldKind::Collocations => self.scorer_collocations,
+            FieldKind::Examples => self.scorer_examples,
+            FieldKind::Inflections => self.scorer_inflections,
+            FieldKind::Derivations => self.scorer_derivations,
+            FieldKind::Antonyms => self.scorer_antonyms,
+            FieldKind::Hypernyms => self.scorer_hypernyms,
+            FieldKind::Etymology => self.scorer_etymology,
+        }
+    }
+
+    pub fn set_scorer(&mut self, field: FieldKind, scorer: FuzzyScorer) {
+        let slot = match field {
+            FieldKind::Word => &mut self.scorer_word,
+            FieldKind::Definitions => &mut self.scorer_definitions,
+            FieldKind::Synonyms => &mut self.scorer_synonyms,
+            FieldKind::Text => &mut self.scorer_text,
+            FieldKind::Encyclopedia => &mut self.scorer_encyclopedia,
+            FieldKind::Collocations => &mut self.scorer_collocations,
+            FieldKind::Examples => &mut self.scorer_examples,
+            FieldKind::Inflections => &mut self.scorer_inflections,
+            FieldKind::Derivations => &mut self.scorer_derivations,
+            FieldKind::Antonyms => &mut self.scorer_antonyms,
+            FieldKind::Hypernyms => &mut self.scorer_hypernyms,
+            FieldKind::Etymology => &mut self.scorer_etymology,
+        };
+        *slot = scorer;
+    }
+
+    /// Applies a comma-separated scorer spec such as `token-set-ratio` (every field) or
+    /// `partial-ratio,word:ratio` (every field, then an override for the word). Items apply in
+    /// order, so later ones win.
+    pub fn apply_scorer_spec(&mut self, spec: &str) -> Result<(), String> {
+        for item in spec
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+        {
+            match item.split_once(':') {
+                Some((field, scorer)) => {
+                    let field: FieldKind = field.parse()?;
+                    self.set_scorer(field, scorer.parse()?);
+                }
+                None => {
+                    let scorer: FuzzyScorer = item.parse()?;
+                    for field in FieldKind::ALL {
+                        self.set_scorer(field, scorer);
+                    }
+                }
+            }
+        }
+        Ok(())
     }
 }
 
@@ -630,7 +2514,28 @@ impl PartialEq for SearchConfig {
             && self.weight_synonyms.to_bits() == other.weight_synonyms.to_bits()
             && self.weight_text.to_bits() == other.weight_text.to_bits()
             && self.weight_encyclopedia.to_bits() == other.weight_encyclopedia.to_bits()
+            && self.weight_collocations.to_bits() == other.weight_collocations.to_bits()
+            && self.weight_examples.to_bits() == other.weight_examples.to_bits()
+            && self.weight_inflections.to_bits() == other.weight_inflections.to_bits()
+            && self.weight_derivations.to_bits() == other.weight_derivations.to_bits()
+            && self.weight_antonyms.to_bits() == other.weight_antonyms.to_bits()
+            && self.weight_hypernyms.to_bits() == other.weight_hypernyms.to_bits()
+            && self.weight_etymology.to_bits() == other.weight_etymology.to_bits()
             && self.min_score.to_bits() == other.min_score.to_bits()
+            && self.scorer_word == other.scorer_word
+            && self.scorer_definitions == other.scorer_definitions
+            && self.scorer_synonyms == other.scorer_synonyms
+            && self.scorer_text == other.scorer_text
+            && self.scorer_encyclopedia == other.scorer_encyclopedia
+            && self.scorer_collocations == other.scorer_collocations
+            && self.scorer_examples == other.scorer_examples
+            && self.scorer_inflections == other.scorer_inflections
+            && self.scorer_derivations == other.scorer_derivations
+            && self.scorer_antonyms == other.scorer_antonyms
+            && self.scorer_hypernyms == other.scorer_hypernyms
+            && self.scorer_etymology == other.scorer_etymology
+            && self.candidate_budget == other.candidate_budget
+            && self.filters == other.filters
     }
 }
 
@@ -643,7 +2548,124 @@ impl std::hash::Hash for SearchConfig {
         self.weight_synonyms.to_bits().hash(state);
         self.weight_text.to_bits().hash(state);
         self.weight_encyclopedia.to_bits().hash(state);
+        self.weight_collocations.to_bits().hash(state);
+        self.weight_examples.to_bits().hash(state);
+        self.weight_inflections.to_bits().hash(state);
+        self.weight_derivations.to_bits().hash(state);
+        self.weight_antonyms.to_bits().hash(state);
+        self.weight_hypernyms.to_bits().hash(state);
+        self.weight_etymology.to_bits().hash(state);
         self.min_score.to_bits().hash(state);
+        self.scorer_word.hash(state);
+        self.scorer_definitions.hash(state);
+        self.scorer_synonyms.hash(state);
+        self.scorer_text.hash(state);
+        self.scorer_encyclopedia.hash(state);
+        self.scorer_collocations.hash(state);
+        self.scorer_examples.hash(state);
+        self.scorer_inflections.hash(state);
+        self.scorer_derivations.hash(state);
+        self.scorer_antonyms.hash(state);
+        self.scorer_hypernyms.hash(state);
+        self.scorer_etymology.hash(state);
+        self.candidate_budget.hash(state);
+        self.filters.hash(state);
+    }
+}
+
+/// Restricts fuzzy and substring search to entries with the given facets; `None` fields (and
+/// the default) place no restriction.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SearchFilters {
+    /// Part of speech the entry must list (case-insensitive, e.g. `noun`).
+    pub part_of_speech: Option<String>,
+    /// `Some(false)` drops stopwords; `Some(true)` keeps only stopwords.
+    pub stopword: Option<bool>,
+    pub has_encyclopedia: Option<bool>,
+    pub has_etymology: Option<bool>,
+    /// Minimum number of senses.
+    pub min_senses: Option<usize>,
+    /// Maximum number of senses.
+    pub max_senses: Option<usize>,
+}
+
+impl SearchFilters {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn accepts(&self, lexicon: &Lexicon, entry: &ArchivedEntryRecord) -> bool {
+        let senses = entry.senses.len.to_native() as usize;
+        self.stopword
+            .is_none_or(|wanted| entry.is_stopword == wanted)
+            && self
+                .has_encyclopedia
+                .is_none_or(|wanted| entry.has_encyclopedia == wanted)
+            && self
+                .has_etymology
+                .is_none_or(|wanted| entry.has_etymology == wanted)
+            && self.min_senses.is_none_or(|min| senses >= min)
+            && self.max_senses.is_none_or(|max| senses <= max)
+            && self.part_of_speech.as_deref().is_none_or(|pos| {
+                string_iter(
+                    lexicon,
+                    &entry.parts_of_speech,
+                    lexicon.entries().entry_parts_of_speech.as_slice(),
+                )
+                .any(|candidate| candidate.eq_ignore_ascii_case(pos))
+            })
+    }
+}
+
+/// Facet counts over every entry a search matched (after [`SearchFilters`], before `limit`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchFacets {
+    /// Number of matching entries.
+    pub total: usize,
+    /// Matches per part of speech; an entry listing several counts toward each.
+    pub parts_of_speech: BTreeMap<String, usize>,
+    pub stopwords: usize,
+    pub with_encyclopedia: usize,
+    pub with_etymology: usize,
+    /// Matches per number of senses.
+    pub sense_counts: BTreeMap<usize, usize>,
+}
+
+impl SearchFacets {
+    fn add(&mut self, lexicon: &Lexicon, entry: &ArchivedEntryRecord) {
+        self.total += 1;
+        for pos in string_iter(
+            lexicon,
+            &entry.parts_of_speech,
+            lexicon.entries().entry_parts_of_speech.as_slice(),
+        ) {
+            match self.parts_of_speech.get_mut(pos) {
+                Some(count) => *count += 1,
+                None => {
+                    self.parts_of_speech.insert(pos.to_owned(), 1);
+                }
+            }
+        }
+        self.stopwords += usize::from(entry.is_stopword);
+        self.with_encyclopedia += usize::from(entry.has_encyclopedia);
+        self.with_etymology += usize::from(entry.has_etymology);
+        *self
+            .sense_counts
+            .entry(entry.senses.len.to_native() as usize)
+            .or_default() += 1;
+    }
+
+    fn merge(&mut self, other: SearchFacets) {
+        self.total += other.total;
+        for (pos, count) in other.parts_of_speech {
+            *self.parts_of_speech.entry(pos).or_default() += count;
+        }
+        self.stopwords += other.stopwords;
+        self.with_encyclopedia += other.with_encyclopedia;
+        self.with_etymology += other.with_etymology;
+        for (senses, count) in other.sense_counts {
+            *self.sense_counts.entry(senses).or_default() += count;
+        }
     }
 }
 
@@ -652,24 +2674,86 @@ pub struct SearchResult {
     pub lexeme_id: u32,
     pub word: String,
     pub score: f32,
+    /// Passage from the definition, example, or encyclopedia paragraph that best matches the
+    /// query, with the matching words marked.
+    pub snippet: Option<Snippet>,
+}
+
+/// A candidate word for a description, returned by [`Lexicon::reverse_lookup`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReverseMatch {
+    pub lexeme_id: u32,
+    pub word: String,
+    /// Share (0–1) of the description's term weight covered by `definition`.
+    pub score: f32,
+    /// The entry's definition that best matches the description.
+    pub definition: String,
+}
+
+/// A "did you mean" candidate returned by [`Lexicon::suggest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub lexeme_id: u32,
+    pub word: String,
+    /// Levenshtein distance from the query (or its lowercase form).
+    pub distance: u32,
+}
+
+/// How a surface form matched a lexeme in [`Lexicon::lemmatize`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InflectionMatch {
+    /// The form is the lexeme's own headword (after [`normalize_key`] folding).
+    Headword,
+    /// The form is one of the lexeme's inflections, as stored in `all_inflections`.
+    Inflection(String),
 }
 
 #[derive(Debug, Clone)]
 pub struct SearchSummary {
     pub results: Vec<SearchResult>,
     pub cache_hit: bool,
+    /// Number of pre-filtered candidates that were scored; `None` when every entry was scored.
+    pub candidates: Option<usize>,
+    /// Facet counts over every result above `min_score`, not just the first `limit`.
+    pub facets: SearchFacets,
+    /// Cursor for the next page of results, if any (see [`Lexicon::search_fuzzy_page`]).
+    pub next_cursor: Option<SearchCursor>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum FieldKind {
     Word,
     Definitions,
     Synonyms,
     Text,
     Encyclopedia,
+    Collocations,
+    Examples,
+    Inflections,
+    Derivations,
+    Antonyms,
+    Hypernyms,
+    /// The etymology summary.
+    Etymology,
 }
 
 impl FieldKind {
+    pub const ALL: [FieldKind; 12] = [
+        FieldKind::Word,
+        FieldKind::Definitions,
+        FieldKind::Synonyms,
+        FieldKind::Text,
+        FieldKind::Encyclopedia,
+        FieldKind::Collocations,
+        FieldKind::Examples,
+        FieldKind::Inflections,
+        FieldKind::Derivations,
+        FieldKind::Antonyms,
+        FieldKind::Hypernyms,
+        FieldKind::Etymology,
+    ];
+
     fn label(self) -> &'static str {
         match self {
             FieldKind::Word => "word",
@@ -677,25 +2761,45 @@ impl FieldKind {
             FieldKind::Synonyms => "synonyms",
             FieldKind::Text => "text",
             FieldKind::Encyclopedia => "encyclopedia",
+            FieldKind::Collocations => "collocations",
+            FieldKind::Examples => "examples",
+            FieldKind::Inflections => "inflections",
+            FieldKind::Derivations => "derivations",
+            FieldKind::Antonyms => "antonyms",
+            FieldKind::Hypernyms => "hypernyms",
+            FieldKind::Etymology => "etymology",
         }
     }
 }
 
 impl fmt::Display for FieldKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(self.label())
+        f.pad(self.label())
     }
 }
 
-#[derive(Debug, Clone)]
+impl std::str::FromStr for FieldKind {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let wanted = value.trim().to_ascii_lowercase();
+        FieldKind::ALL
+            .into_iter()
+            .find(|field| field.label() == wanted)
+            .ok_or_else(|| format!("unknown search field {value:?}"))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FieldContribution {
     pub field: FieldKind,
+    pub scorer: FuzzyScorer,
     pub score: f32,
     pub weight: f32,
     pub sample: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchBreakdown {
     pub lexeme_id: u32,
     pub word: String,
@@ -704,7 +2808,7 @@ pub struct SearchBreakdown {
 }
 
 pub struct SenseIter<'a> {
-    store: &'a ArchivedDataStore,
+    lexicon: &'a Lexicon,
     senses: &'a [ArchivedSenseRecord],
     index: usize,
 }
@@ -719,15 +2823,17 @@ impl<'a> Iterator for SenseIter<'a> {
         let sense = &self.senses[self.index];
         self.index += 1;
         Some(SenseRef {
-            store: self.store,
+            lexicon: self.lexicon,
             sense,
+            ordinal: self.index as u32,
         })
     }
 }
 
 pub struct SenseRef<'a> {
-    store: &'a ArchivedDataStore,
+    lexicon: &'a Lexicon,
     sense: &'a ArchivedSenseRecord,
+    ordinal: u32,
 }
 
 impl<'a> SenseRef<'a> {
@@ -735,18 +2841,41 @@ impl<'a> SenseRef<'a> {
         self.sense.lexeme_id.to_native()
     }
 
+    /// 1-based position of the sense within its entry.
+    pub fn ordinal(&self) -> u32 {
+        self.ordinal
+    }
+
+    /// Stable identifier for this sense (see [`SenseId`]).
+    pub fn id(&self) -> SenseId {
+        SenseId::new(self.lexeme_id(), self.ordinal)
+    }
+
+    /// Human-readable reference such as `dog#n.2`: the headword, the part-of-speech tag (when the
+    /// sense has one), and the ordinal. [`Lexicon::resolve_sense`] maps it back to [`SenseId`].
+    pub fn key(&self) -> String {
+        let word = self
+            .lexicon
+            .entry_by_id(self.lexeme_id())
+            .map_or("", |entry| entry.word());
+        match self.part_of_speech() {
+            Some(pos) => format!("{word}#{}.{}", sense::pos_tag(pos), self.ordinal),
+            None => format!("{word}#{}", self.ordinal),
+        }
+    }
+
     pub fn part_of_speech(&self) -> Option<&'a str> {
         self.sense
             .part_of_speech
             .as_ref()
-            .map(|id| self.store.string_from_archived(*id))
+            .map(|id| self.lexicon.string(*id))
     }
 
     pub fn definition(&self) -> Option<&'a str> {
         self.sense
             .definition
             .as_ref()
-            .map(|id| self.store.string_from_archived(*id))
+            .map(|id| self.lexicon.string(*id))
     }
 
     pub fn sense_index(&self) -> i32 {
@@ -755,80 +2884,101 @@ impl<'a> SenseRef<'a> {
 
     pub fn synonyms(&'a self) -> impl Iterator<Item = &'a str> + 'a {
         string_iter(
-            self.store,
+            self.lexicon,
             &self.sense.synonyms,
-            self.store.sense_synonyms.as_slice(),
+            self.lexicon.senses().sense_synonyms.as_slice(),
         )
     }
 
     pub fn antonyms(&'a self) -> impl Iterator<Item = &'a str> + 'a {
         string_iter(
-            self.store,
+            self.lexicon,
             &self.sense.antonyms,
-            self.store.sense_antonyms.as_slice(),
+            self.lexicon.senses().sense_antonyms.as_slice(),
         )
     }
 
     pub fn hypernyms(&'a self) -> impl Iterator<Item = &'a str> + 'a {
         string_iter(
-            self.store,
+            self.lexicon,
             &self.sense.hypernyms,
-            self.store.sense_hypernyms.as_slice(),
+            self.lexicon.senses().sense_hypernyms.as_slice(),
         )
     }
 
     pub fn hyponyms(&'a self) -> impl Iterator<Item = &'a str> + 'a {
         string_iter(
-            self.store,
+            self.lexicon,
             &self.sense.hyponyms,
-            self.store.sense_hyponyms.as_slice(),
+            self.lexicon.senses().sense_hyponyms.as_slice(),
         )
     }
 
     pub fn examples(&'a self) -> impl Iterator<Item = &'a str> + 'a {
         string_iter(
-            self.store,
+            self.lexicon,
             &self.sense.examples,
-            self.store.sense_examples.as_slice(),
+            self.lexicon.senses().sense_examples.as_slice(),
         )
     }
+
+    /// Lexeme IDs of this sense's related words that have their own entries, resolved at build
+    /// time like [`LexemeEntry::neighbor_ids`] but from the sense's own relation lists.
+    pub fn neighbor_ids(&self, relation: RelationKind) -> Vec<u32> {
+        let relations = self.lexicon.relations();
+        let (range, bucket) = match relation {
+            RelationKind::Synonym => (
+                &self.sense.synonym_neighbors,
+                &relations.sense_synonym_neighbors,
+            ),
+            RelationKind::Antonym => (
+                &self.sense.antonym_neighbors,
+                &relations.sense_antonym_neighbors,
+            ),
+            RelationKind::Hypernym => (
+                &self.sense.hypernym_neighbors,
+                &relations.sense_hypernym_neighbors,
+            ),
+            RelationKind::Hyponym => (
+                &self.sense.hyponym_neighbors,
+                &relations.sense_hyponym_neighbors,
+            ),
+        };
+        id_iter(range, bucket.as_slice()).collect()
+    }
 }
 
 fn string_iter<'a>(
-    store: &'a ArchivedDataStore,
+    lexicon: &'a Lexicon,
     range: &'a ArchivedRange,
     bucket: &'a [ArchivedStringId],
 ) -> impl Iterator<Item = &'a str> + 'a {
-    let slice = range_slice(bucket, range);
-    slice.iter().map(move |id| store.string_from_archived(*id))
+    let slice = range_slice(bucket, range).unwrap_or_default();
+    slice.iter().map(move |id| lexicon.string(*id))
 }
 
 fn id_iter<'a>(
     range: &'a ArchivedRange,
     bucket: &'a [ArchivedU32],
 ) -> impl Iterator<Item = u32> + 'a {
-    let slice = range_slice(bucket, range);
+    let slice = range_slice(bucket, range).unwrap_or_default();
     slice.iter().map(|id| id.to_native())
 }
 
-fn range_slice<'a, T>(data: &'a [T], range: &'a ArchivedRange) -> &'a [T] {
+/// The `range` window of `data`. A range past the end of its bucket means the store is corrupt;
+/// the infallible accessors read it as empty and [`Lexicon::try_entry_by_id`] reports it.
+fn range_slice<'a, T>(data: &'a [T], range: &ArchivedRange) -> Result<&'a [T], LexemeError> {
     let start = range.start.to_native() as usize;
     let len = range.len.to_native() as usize;
-    &data[start..start + len]
-}
-
-trait StoreStrings {
-    fn string_from_archived(&self, id: ArchivedStringId) -> &str;
-}
-
-impl StoreStrings for ArchivedDataStore {
-    fn string_from_archived(&self, id: ArchivedStringId) -> &str {
-        let idx = id.to_native() as usize;
-        string_cache()[idx].get_or_init(|| {
-            let owned = self.strings.decompress(idx);
-            Box::leak(owned.into_boxed_str())
+    start
+        .checked_add(len)
+        .and_then(|end| data.get(start..end))
+        .ok_or_else(|| {
+            LexemeError::CorruptStore(format!(
+                "range {start}..+{len} exceeds its {}-element list",
+                data.len()
+            ))
         })
-    }
 }
 
 impl ArchivedPackedStrings {
@@ -836,40 +2986,89 @@ impl ArchivedPackedStrings {
         self.offsets.as_slice().len()
     }
 
-    fn compressed_slice(&self, idx: usize) -> &[u8] {
-        let start = self.offsets.as_slice()[idx].to_native() as usize;
-        let len = self.lengths.as_slice()[idx].to_native() as usize;
-        let data = self.data.as_slice();
-        &data[start..start + len]
+    fn compressed_slice(&self, idx: usize) -> Option<&[u8]> {
+        frame_slice(
+            self.offsets.as_slice(),
+            self.lengths.as_slice(),
+            self.data.as_slice(),
+            idx,
+        )
     }
 
-    fn decompress(&self, idx: usize) -> String {
-        let bytes = self.compressed_slice(idx);
-        let decoded = decode_all(Cursor::new(bytes)).expect("string chunk decompresses");
-        String::from_utf8(decoded).expect("string chunk valid UTF-8")
+    fn decompress(
+        &self,
+        idx: usize,
+        dictionary: Option<&DecoderDictionary<'_>>,
+    ) -> Result<String, LexemeError> {
+        let bytes = self.compressed_slice(idx).ok_or_else(|| {
+            LexemeError::CorruptStore(format!("string #{idx} frame is out of bounds"))
+        })?;
+        decode_utf8_frame("string", idx, bytes, dictionary)
     }
 }
 
 impl ArchivedCompressedTextStore {
-    fn decompress(&self, id: ArchivedTextId) -> String {
+    fn decompress(
+        &self,
+        id: ArchivedTextId,
+        dictionary: Option<&DecoderDictionary<'_>>,
+    ) -> Result<String, LexemeError> {
         let idx = id.to_native() as usize;
-        let start = self.offsets.as_slice()[idx].to_native() as usize;
-        let len = self.lengths.as_slice()[idx].to_native() as usize;
-        let data = self.data.as_slice();
-        let bytes = &data[start..start + len];
-        let mut decoder = ZstdDecoder::new(Cursor::new(bytes)).expect("long text chunk decoder");
-        let mut output = Vec::new();
-        decoder
-            .read_to_end(&mut output)
-            .expect("long text chunk decompresses");
-        String::from_utf8(output).expect("long text chunk is valid UTF-8")
+        let bytes = frame_slice(
+            self.offsets.as_slice(),
+            self.lengths.as_slice(),
+            self.data.as_slice(),
+            idx,
+        )
+        .ok_or_else(|| {
+            LexemeError::CorruptStore(format!("long text #{idx} frame is out of bounds"))
+        })?;
+        decode_utf8_frame("long text", idx, bytes, dictionary)
     }
 }
 
-impl ArchivedDataStore {
-    fn decompress_long_text(&self, id: ArchivedTextId) -> String {
-        self.long_texts.decompress(id)
+fn frame_slice<'a>(
+    offsets: &[ArchivedU32],
+    lengths: &[ArchivedU32],
+    data: &'a [u8],
+    idx: usize,
+) -> Option<&'a [u8]> {
+    let start = offsets.get(idx)?.to_native() as usize;
+    let len = lengths.get(idx)?.to_native() as usize;
+    data.get(start..start.checked_add(len)?)
+}
+
+fn decode_utf8_frame(
+    arena: &'static str,
+    index: usize,
+    bytes: &[u8],
+    dictionary: Option<&DecoderDictionary<'_>>,
+) -> Result<String, LexemeError> {
+    let decoded = decode_frame(bytes, dictionary).map_err(|source| LexemeError::Decompress {
+        arena,
+        index,
+        source,
+    })?;
+    String::from_utf8(decoded).map_err(|_| LexemeError::InvalidUtf8 { arena, index })
+}
+
+/// Prepares an arena's shared dictionary once; `None` when its frames are standalone.
+fn prepare_dictionary(raw: &[u8]) -> Option<DecoderDictionary<'static>> {
+    (!raw.is_empty()).then(|| DecoderDictionary::copy(raw))
+}
+
+fn decode_frame(bytes: &[u8], dictionary: Option<&DecoderDictionary<'_>>) -> io::Result<Vec<u8>> {
+    let mut output = Vec::new();
+    match dictionary {
+        Some(dictionary) => {
+            ZstdDecoder::with_prepared_dictionary(Cursor::new(bytes), dictionary)?
+                .read_to_end(&mut output)?;
+        }
+        None => {
+            ZstdDecoder::new(Cursor::new(bytes))?.read_to_end(&mut output)?;
+        }
     }
+    Ok(output)
 }
 
 #[derive(Clone)]
@@ -883,17 +3082,18 @@ impl Eq for RankedResult {}
 
 impl PartialEq for RankedResult {
     fn eq(&self, other: &Self) -> bool {
-        self.score.eq(&other.score)
+        self.cmp(other) == Ordering::Equal
     }
 }
 
+/// Orders from best to worst (higher score, then lower ID), so the max-heap keeps the worst
+/// kept result on top.
 impl Ord for RankedResult {
     fn cmp(&self, other: &Self) -> Ordering {
         self.score
-            .partial_cmp(&other.score)
-            .unwrap_or(Ordering::Equal)
+            .total_cmp(&other.score)
             .reverse()
-            .then_with(|| self.lexeme_id.cmp(&other.lexeme_id).reverse())
+            .then_with(|| self.lexeme_id.cmp(&other.lexeme_id))
     }
 }
 
@@ -903,101 +3103,278 @@ impl PartialOrd for RankedResult {
     }
 }
 
+/// Keeps the best `limit` results ranked after `after` while counting facets over every item.
+fn top_ranked<'a>(
+    lexicon: &Lexicon,
+    items: impl ParallelIterator<Item = (RankedResult, &'a ArchivedEntryRecord)>,
+    after: Option<&RankedResult>,
+    limit: usize,
+) -> (BinaryHeap<RankedResult>, SearchFacets) {
+    items
+        .fold(
+            || (BinaryHeap::new(), SearchFacets::default()),
+            |(mut heap, mut facets), (item, entry)| {
+                facets.add(lexicon, entry);
+                if after.is_none_or(|after| item > *after) {
+                    push_ranked(&mut heap, item, limit);
+                }
+                (heap, facets)
+            },
+        )
+        .reduce(
+            || (BinaryHeap::new(), SearchFacets::default()),
+            |(mut left, mut left_facets), (mut right, right_facets)| {
+                if left.len() < right.len() {
+                    std::mem::swap(&mut left, &mut right);
+                }
+                for item in right.drain() {
+                    push_ranked(&mut left, item, limit);
+                }
+                left_facets.merge(right_facets);
+                (left, left_facets)
+            },
+        )
+}
+
 fn push_ranked(heap: &mut BinaryHeap<RankedResult>, item: RankedResult, limit: usize) {
     if heap.len() < limit {
         heap.push(item);
     } else if let Some(mut peek) = heap.peek_mut()
-        && item.score > peek.score
+        && item < *peek
     {
         *peek = item;
     }
 }
 
-fn drain_heap(mut heap: BinaryHeap<RankedResult>) -> Vec<SearchResult> {
-    let mut out = Vec::with_capacity(heap.len());
-    while let Some(item) = heap.pop() {
-        out.push(SearchResult {
+fn drain_heap(heap: BinaryHeap<RankedResult>) -> Vec<SearchResult> {
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|item| SearchResult {
             lexeme_id: item.lexeme_id,
             word: item.word,
             score: item.score,
-        });
-    }
-    out.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
-    out
+            snippet: None,
+        })
+        .collect()
 }
 
 fn score_entry(
     query: &str,
-    store: &ArchivedDataStore,
+    lexicon: &Lexicon,
     entry: &ArchivedEntryRecord,
     config: &SearchConfig,
 ) -> Option<f32> {
     let mut total_weight = 0.0;
     let mut accum = 0.0;
 
-    if config.weight_word > 0.0 {
-        let word = store.string_from_archived(entry.word);
-        let s = fuzzy_score(query, word);
-        total_weight += config.weight_word;
-        accum += s * config.weight_word;
+    for field in FieldKind::ALL {
+        let weight = config.weight(field);
+        if weight <= 0.0 {
+            continue;
+        }
+        let scorer = config.scorer(field);
+        let s = match field_value(lexicon, entry, field) {
+            FieldValue::Missing => continue,
+            FieldValue::Single(text) => scorer.score(query, &text),
+            FieldValue::List(range, bucket) => {
+                best_range_score(query, scorer, lexicon, range, bucket)
+            }
+        };
+        total_weight += weight;
+        accum += s * weight;
     }
 
-    if config.weight_definitions > 0.0 {
-        let s = best_range_score(
-            query,
-            store,
-            &entry.all_definitions,
-            store.entry_all_definitions.as_slice(),
-        );
-        total_weight += config.weight_definitions;
-        accum += s * config.weight_definitions;
+    if total_weight > 0.0 {
+        Some(accum / total_weight)
+    } else {
+        None
     }
+}
 
-    if config.weight_synonyms > 0.0 {
-        let s = best_range_score(
-            query,
-            store,
-            &entry.all_synonyms,
-            store.entry_all_synonyms.as_slice(),
-        );
-        total_weight += config.weight_synonyms;
-        accum += s * config.weight_synonyms;
-    }
+/// Per-field BM25 boosts (in [`fulltext::Field::ALL`] order) taken from the fuzzy weights.
+fn fulltext_boosts(config: &SearchConfig) -> [f32; FIELD_COUNT] {
+    [
+        config.weight_word,
+        config.weight_definitions,
+        config.weight_synonyms,
+        config.weight_examples,
+        config.weight_text,
+        config.weight_encyclopedia,
+        config.weight_collocations,
+        config.weight_inflections,
+        config.weight_derivations,
+        config.weight_antonyms,
+        config.weight_hypernyms,
+        config.weight_etymology,
+    ]
+}
 
-    if config.weight_text > 0.0
-        && let Some(text_id) = entry.text.as_ref()
-    {
-        let text = store.decompress_long_text(*text_id);
-        let s = fuzzy_score(query, &text);
-        total_weight += config.weight_text;
-        accum += s * config.weight_text;
-    }
+/// The stored value of one searchable field.
+enum FieldValue<'a> {
+    /// The entry has no value for an optional field (text, encyclopedia, etymology); the field
+    /// drops out of the weighted average instead of scoring 0.
+    Missing,
+    Single(std::borrow::Cow<'a, str>),
+    List(&'a ArchivedRange, &'a [ArchivedStringId]),
+}
+
+fn field_value<'a>(
+    lexicon: &'a Lexicon,
+    entry: &'a ArchivedEntryRecord,
+    field: FieldKind,
+) -> FieldValue<'a> {
+    let entries = lexicon.entries();
+    let (range, bucket) = match field {
+        FieldKind::Word => return FieldValue::Single(lexicon.string(entry.word).into()),
+        FieldKind::Text => {
+            return entry.text.as_ref().map_or(FieldValue::Missing, |id| {
+                FieldValue::Single(lexicon.long_text(*id).into())
+            });
+        }
+        FieldKind::Encyclopedia => {
+            return entry
+                .encyclopedia_entry
+                .as_ref()
+                .map_or(FieldValue::Missing, |id| {
+                    FieldValue::Single(lexicon.long_text(*id).into())
+                });
+        }
+        FieldKind::Etymology => {
+            return entry
+                .etymology_summary
+                .as_ref()
+                .map_or(FieldValue::Missing, |id| {
+                    FieldValue::Single(lexicon.string(*id).into())
+                });
+        }
+        FieldKind::Definitions => (&entry.all_definitions, &entries.entry_all_definitions),
+        FieldKind::Synonyms => (&entry.all_synonyms, &entries.entry_all_synonyms),
+        FieldKind::Collocations => (&entry.all_collocations, &entries.entry_all_collocations),
+        FieldKind::Examples => (&entry.all_examples, &entries.entry_all_examples),
+        FieldKind::Inflections => (&entry.all_inflections, &entries.entry_all_inflections),
+        FieldKind::Derivations => (&entry.all_derivations, &entries.entry_all_derivations),
+        FieldKind::Antonyms => (&entry.all_antonyms, &entries.entry_all_antonyms),
+        FieldKind::Hypernyms => (&entry.all_hypernyms, &entries.entry_all_hypernyms),
+    };
+    FieldValue::List(range, bucket.as_slice())
+}
 
-    if config.weight_encyclopedia > 0.0
-        && let Some(enc_id) = entry.encyclopedia_entry.as_ref()
-    {
-        let text = store.decompress_long_text(*enc_id);
-        let s = fuzzy_score(query, &text);
-        total_weight += config.weight_encyclopedia;
-        accum += s * config.weight_encyclopedia;
+/// Entries that can match `query`, when a required `word:` clause narrows them down.
+fn query_candidates(query: &Query, words: &HashMap<String, HashSet<u32>>) -> Option<Vec<u32>> {
+    match query {
+        Query::Word(pattern) => {
+            let mut ids: Vec<u32> = words.get(pattern)?.iter().copied().collect();
+            ids.sort_unstable();
+            Some(ids)
+        }
+        Query::And(clauses) => clauses
+            .iter()
+            .filter_map(|clause| query_candidates(clause, words))
+            .min_by_key(Vec::len),
+        Query::Or(clauses) => {
+            let mut ids = Vec::new();
+            for clause in clauses {
+                ids.extend(query_candidates(clause, words)?);
+            }
+            ids.sort_unstable();
+            ids.dedup();
+            Some(ids)
+        }
+        _ => None,
     }
+}
 
-    if total_weight > 0.0 {
-        Some(accum / total_weight)
-    } else {
-        None
+/// Scores `entry` against `query`, or `None` when it does not match.
+fn query_score(
+    query: &Query,
+    lexicon: &Lexicon,
+    entry: &ArchivedEntryRecord,
+    words: &HashMap<String, HashSet<u32>>,
+) -> Option<f32> {
+    let matched = |hit: bool| hit.then_some(1.0);
+    match query {
+        Query::And(clauses) => {
+            let mut total = 0.0;
+            for clause in clauses {
+                total += query_score(clause, lexicon, entry, words)?;
+            }
+            Some(total / clauses.len().max(1) as f32)
+        }
+        Query::Or(clauses) => clauses
+            .iter()
+            .filter_map(|clause| query_score(clause, lexicon, entry, words))
+            .reduce(f32::max),
+        Query::Not(inner) => matched(query_score(inner, lexicon, entry, words).is_none()),
+        Query::Word(pattern) => matched(
+            words
+                .get(pattern)
+                .is_some_and(|ids| ids.contains(&entry.lexeme_id.to_native())),
+        ),
+        Query::Match {
+            field,
+            value,
+            fuzzy,
+        } => {
+            let fields = match field {
+                Some(field) => std::slice::from_ref(field),
+                None => &[FieldKind::Word, FieldKind::Definitions][..],
+            };
+            let needle = value.to_lowercase();
+            let score = |text: &str| {
+                let text = text.to_lowercase();
+                match fuzzy {
+                    Some(threshold) => Some(FuzzyScorer::PartialRatio.score(&needle, &text))
+                        .filter(|score| score >= threshold),
+                    None => text
+                        .contains(&needle)
+                        .then(|| needle.chars().count() as f32 / text.chars().count() as f32),
+                }
+            };
+            fields
+                .iter()
+                .filter_map(|field| match field_value(lexicon, entry, *field) {
+                    FieldValue::Missing => None,
+                    FieldValue::Single(text) => score(&text),
+                    FieldValue::List(range, bucket) => string_iter(lexicon, range, bucket)
+                        .filter_map(score)
+                        .reduce(f32::max),
+                })
+                .reduce(f32::max)
+        }
+        Query::PartOfSpeech(pos) => matched(
+            string_iter(
+                lexicon,
+                &entry.parts_of_speech,
+                lexicon.entries().entry_parts_of_speech.as_slice(),
+            )
+            .any(|candidate| candidate.eq_ignore_ascii_case(pos)),
+        ),
+        Query::Has(field) => matched(match field {
+            FieldKind::Text => entry.text.is_some(),
+            FieldKind::Encyclopedia => entry.has_encyclopedia,
+            FieldKind::Etymology => entry.has_etymology,
+            _ => match field_value(lexicon, entry, *field) {
+                FieldValue::Missing => false,
+                FieldValue::Single(text) => !text.is_empty(),
+                FieldValue::List(range, bucket) => {
+                    range_slice(bucket, range).is_ok_and(|slice| !slice.is_empty())
+                }
+            },
+        }),
+        Query::Stopword => matched(entry.is_stopword),
     }
 }
 
 fn best_range_score(
     query: &str,
-    store: &ArchivedDataStore,
+    scorer: FuzzyScorer,
+    lexicon: &Lexicon,
     range: &ArchivedRange,
     bucket: &[ArchivedStringId],
 ) -> f32 {
     let mut best = 0.0;
-    for value in string_iter(store, range, bucket) {
-        let s = fuzzy_score(query, value);
+    for value in string_iter(lexicon, range, bucket) {
+        let s = scorer.score(query, value);
         if s > best {
             best = s;
         }
@@ -1005,17 +3382,9 @@ fn best_range_score(
     best
 }
 
-fn fuzzy_score(query: &str, value: &str) -> f32 {
-    if value.is_empty() {
-        0.0
-    } else {
-        fuzz::ratio(query.chars(), value.chars()) as f32
-    }
-}
-
 fn explain_entry(
     query: &str,
-    store: &ArchivedDataStore,
+    lexicon: &Lexicon,
     entry: &ArchivedEntryRecord,
     config: &SearchConfig,
 ) -> Option<SearchBreakdown> {
@@ -1023,96 +3392,37 @@ fn explain_entry(
     let mut accum = 0.0;
     let mut fields = Vec::new();
 
-    if config.weight_word > 0.0 {
-        let word = store.string_from_archived(entry.word);
-        let score = fuzzy_score(query, word);
-        total_weight += config.weight_word;
-        accum += score * config.weight_word;
-        fields.push(FieldContribution {
-            field: FieldKind::Word,
-            score,
-            weight: config.weight_word,
-            sample: Some(word.to_string()),
-        });
-    }
-
-    if config.weight_definitions > 0.0 {
-        let (score, sample) = best_range_score_with_sample(
-            query,
-            store,
-            &entry.all_definitions,
-            store.entry_all_definitions.as_slice(),
-        );
-        total_weight += config.weight_definitions;
-        accum += score * config.weight_definitions;
-        fields.push(FieldContribution {
-            field: FieldKind::Definitions,
-            score,
-            weight: config.weight_definitions,
-            sample,
-        });
-    }
-
-    if config.weight_synonyms > 0.0 {
-        let (score, sample) = best_range_score_with_sample(
-            query,
-            store,
-            &entry.all_synonyms,
-            store.entry_all_synonyms.as_slice(),
-        );
-        total_weight += config.weight_synonyms;
-        accum += score * config.weight_synonyms;
+    for field in FieldKind::ALL {
+        let weight = config.weight(field);
+        if weight <= 0.0 {
+            continue;
+        }
+        let scorer = config.scorer(field);
+        let (score, sample) = match field_value(lexicon, entry, field) {
+            FieldValue::Missing => continue,
+            FieldValue::Single(text) => (scorer.score(query, &text), Some(truncate_sample(&text))),
+            FieldValue::List(range, bucket) => {
+                best_range_score_with_sample(query, scorer, lexicon, range, bucket)
+            }
+        };
+        total_weight += weight;
+        accum += score * weight;
         fields.push(FieldContribution {
-            field: FieldKind::Synonyms,
+            field,
+            scorer,
             score,
-            weight: config.weight_synonyms,
+            weight,
             sample,
         });
     }
 
-    if config.weight_text > 0.0 {
-        let text = entry
-            .text
-            .as_ref()
-            .map(|id| store.decompress_long_text(*id));
-        if let Some(body) = text {
-            let score = fuzzy_score(query, &body);
-            total_weight += config.weight_text;
-            accum += score * config.weight_text;
-            fields.push(FieldContribution {
-                field: FieldKind::Text,
-                score,
-                weight: config.weight_text,
-                sample: Some(truncate_sample(&body)),
-            });
-        }
-    }
-
-    if config.weight_encyclopedia > 0.0 {
-        let text = entry
-            .encyclopedia_entry
-            .as_ref()
-            .map(|id| store.decompress_long_text(*id));
-        if let Some(body) = text {
-            let score = fuzzy_score(query, &body);
-            total_weight += config.weight_encyclopedia;
-            accum += score * config.weight_encyclopedia;
-            fields.push(FieldContribution {
-                field: FieldKind::Encyclopedia,
-                score,
-                weight: config.weight_encyclopedia,
-                sample: Some(truncate_sample(&body)),
-            });
-        }
-    }
-
     if total_weight <= 0.0 {
         return None;
     }
 
     Some(SearchBreakdown {
         lexeme_id: entry.lexeme_id.to_native(),
-        word: store.string_from_archived(entry.word).to_string(),
+        word: lexicon.string(entry.word).to_string(),
         total_score: accum / total_weight,
         fields,
     })
@@ -1120,14 +3430,15 @@ fn explain_entry(
 
 fn best_range_score_with_sample(
     query: &str,
-    store: &ArchivedDataStore,
+    scorer: FuzzyScorer,
+    lexicon: &Lexicon,
     range: &ArchivedRange,
     bucket: &[ArchivedStringId],
 ) -> (f32, Option<String>) {
     let mut best = 0.0;
     let mut sample = None;
-    for value in string_iter(store, range, bucket) {
-        let s = fuzzy_score(query, value);
+    for value in string_iter(lexicon, range, bucket) {
+        let s = scorer.score(query, value);
         if s >= best {
             best = s;
             sample = Some(value.to_string());
@@ -1148,3 +3459,1048 @@ fn truncate_sample(text: &str) -> String {
     }
     snippet
 }
+
+#[cfg(all(test, feature = "embedded-data"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn independent_lexicons_resolve_entries() {
+        let first = Lexicon::embedded();
+        let second = Lexicon::embedded();
+        let id = first.get("dog").expect("dog in fst");
+        assert_eq!(second.get("dog"), Some(id));
+        let entry = second.entry_by_id(id).expect("dog entry");
+        assert_eq!(entry.word(), "dog");
+        assert_eq!(first.prefix("do", 8), second.prefix("do", 8));
+        assert!(
+            !first.store.is_loaded(Section::Entries),
+            "prefix lookups stay lazy"
+        );
+    }
+
+    #[test]
+    fn entry_lookups_skip_long_texts() {
+        let lexicon = Lexicon::embedded();
+        let entry = lexicon.entry_by_word("dog").expect("dog entry");
+        assert_eq!(entry.word(), "dog");
+        assert!(lexicon.store.is_loaded(Section::Entries));
+        assert!(!lexicon.store.is_loaded(Section::Senses));
+        assert!(!lexicon.store.is_loaded(Section::Texts));
+        assert!(entry.senses().count() > 0);
+        assert!(lexicon.store.is_loaded(Section::Senses));
+        assert!(!lexicon.store.is_loaded(Section::Texts));
+    }
+
+    #[test]
+    fn corrupt_stores_are_reported() {
+        let fst = EMBEDDED_FST_BYTES.to_vec();
+        let truncated = EMBEDDED_DATA_BYTES[..8].to_vec();
+        assert!(matches!(
+            Lexicon::from_bytes(fst.clone(), truncated),
+            Err(LexemeError::CorruptStore(_))
+        ));
+
+        let mut data = EMBEDDED_DATA_BYTES.to_vec();
+        let header = StoreHeader::decode(&data).unwrap();
+        let (offset, len) = header.sections[Section::Texts as usize];
+        data[offset..offset + len].fill(0xAB);
+        let lexicon = Lexicon::from_bytes(fst, data).expect("header still parses");
+        assert!(lexicon.try_entry_by_word("dog").unwrap().is_some());
+        assert!(matches!(
+            lexicon.validate(),
+            Err(LexemeError::CorruptStore(_))
+        ));
+        let entry = lexicon.try_entry_by_word("dog").unwrap().unwrap();
+        assert!(entry.try_text().is_err());
+    }
+
+    #[test]
+    fn dictionary_frames_round_trip() {
+        let samples: Vec<String> = (0..2000)
+            .map(|i| format!("a domesticated carnivorous mammal, variant {i}"))
+            .collect();
+        let raw = zstd::dict::from_samples(&samples, 4096).expect("train dictionary");
+        let mut compressor = zstd::bulk::Compressor::with_dictionary(5, &raw).unwrap();
+        let frame = compressor.compress(samples[7].as_bytes()).unwrap();
+        let dictionary = prepare_dictionary(&raw);
+        let decoded = decode_frame(&frame, dictionary.as_ref()).expect("decode with dictionary");
+        assert_eq!(decoded, samples[7].as_bytes());
+        assert!(prepare_dictionary(&[]).is_none());
+    }
+
+    #[test]
+    fn mmap_store_round_trips() {
+        let dir = std::env::temp_dir().join(format!("opengloss-mmap-{}", std::process::id()));
+        Lexicon::embedded()
+            .write_mmap_store(&dir)
+            .expect("write mmap store");
+        let mapped = Lexicon::open(&dir).expect("open mmap store");
+        assert!(matches!(mapped.store.bytes, ArtifactBytes::Mapped(_)));
+        assert!(!mapped.store.header.is_compressed());
+        let id = mapped.get("dog").expect("dog in fst");
+        assert_eq!(mapped.entry_by_id(id).expect("dog entry").word(), "dog");
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn open_validated_rejects_a_corrupt_section() {
+        let dir = std::env::temp_dir().join(format!("opengloss-corrupt-{}", std::process::id()));
+        let path = Lexicon::embedded()
+            .write_mmap_store(&dir)
+            .expect("write mmap store");
+        let mut data = fs::read(&path).unwrap();
+        let header = StoreHeader::decode(&data).unwrap();
+        let (offset, len) = header.sections[Section::Entries as usize];
+        // rkyv keeps the root object at the end of the buffer.
+        data[offset + len - 16..offset + len].fill(0xFF);
+        fs::write(&path, &data).unwrap();
+        assert!(Lexicon::open(&dir).is_ok(), "header still parses");
+        assert!(matches!(
+            Lexicon::open_validated(&dir),
+            Err(LexemeError::CorruptStore(_))
+        ));
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn normalized_lookup_folds_case_accents_and_punctuation() {
+        let embedded = Lexicon::embedded();
+        let dog = embedded.get("dog").expect("dog in fst");
+        assert!(embedded.get_normalized(" DOG ").contains(&dog));
+        assert!(embedded.get_normalized("--").is_empty());
+        assert!(
+            embedded
+                .prefix_normalized("DOG", 8)
+                .contains(&("dog".to_string(), dog))
+        );
+
+        // Headwords that folding changes are found through their folded key (and any casing of
+        // it), and every lexeme a key finds folds to that key.
+        let folded = embedded
+            .all_words()
+            .iter()
+            .filter(|(word, _)| normalize_key(word) != *word)
+            .take(200);
+        for (word, id) in folded {
+            let key = normalize_key(word);
+            if key.is_empty() {
+                assert!(embedded.get_normalized(word).is_empty(), "{word:?}");
+                continue;
+            }
+            let hits = embedded.get_normalized(&key);
+            assert!(hits.contains(id), "{word:?}");
+            assert_eq!(embedded.get_normalized(word), hits);
+            if key.is_ascii() {
+                assert_eq!(embedded.get_normalized(&key.to_uppercase()), hits);
+            }
+            for hit in hits {
+                let entry = embedded.entry_by_id(hit).expect("normalized hit");
+                assert_eq!(normalize_key(entry.word()), key);
+            }
+        }
+    }
+
+    #[test]
+    fn lemmatize_maps_inflections_to_base_lexemes() {
+        let embedded = Lexicon::embedded();
+        // An inflected form that is not a headword itself, like "went".
+        let (base, form) = embedded
+            .all_words()
+            .iter()
+            .find_map(|(_, id)| {
+                let entry = embedded.entry_by_id(*id)?;
+                let form = entry
+                    .all_inflections()
+                    .find(|form| embedded.get_normalized(form).is_empty())?;
+                Some((*id, form.to_owned()))
+            })
+            .expect("some inflection is not a headword");
+        let lemmas = embedded.lemmatize(&form);
+        assert!(lemmas.contains(&(base, InflectionMatch::Inflection(form.clone()))));
+        for (id, found) in &lemmas {
+            let InflectionMatch::Inflection(stored) = found else {
+                panic!("{form:?} is not a headword");
+            };
+            assert_eq!(normalize_key(stored), normalize_key(&form));
+            let entry = embedded.entry_by_id(*id).expect("lemma entry");
+            assert!(
+                entry
+                    .all_inflections()
+                    .any(|inflection| inflection == stored)
+            );
+        }
+
+        let dog = embedded.get("dog").expect("dog in fst");
+        assert!(
+            embedded
+                .lemmatize("Dog")
+                .contains(&(dog, InflectionMatch::Headword))
+        );
+        let entry = embedded.entry_by_id(dog).expect("dog entry");
+        let inflection = entry
+            .all_inflections()
+            .find(|form| normalize_key(form) != "dog")
+            .expect("dog has an inflection");
+        let expected = (dog, InflectionMatch::Inflection(inflection.to_owned()));
+        for form in [inflection.to_uppercase(), inflection.to_lowercase()] {
+            assert!(embedded.lemmatize(&form).contains(&expected), "{form:?}");
+        }
+        assert!(embedded.lemmatize("--").is_empty());
+    }
+
+    #[test]
+    fn reverse_lookup_ranks_entries_by_definition_overlap() {
+        let lexicon = Lexicon::embedded();
+        let dog = lexicon.get("dog").expect("dog in fst");
+        let entry = lexicon.entry_by_id(dog).expect("dog entry");
+        let description = entry.all_definitions().next().expect("dog definition");
+        let mut terms = Vec::new();
+        fulltext::tokenize(description, |token| terms.push(token.to_owned()));
+
+        let hits = lexicon.reverse_lookup(description, 10);
+        assert!(hits.iter().any(|hit| hit.lexeme_id == dog));
+        assert!(hits.windows(2).all(|pair| pair[0].score >= pair[1].score));
+        for hit in &hits {
+            assert!(hit.score > 0.0 && hit.score <= 1.0, "{hit:?}");
+            let mut overlaps = false;
+            fulltext::tokenize(&hit.definition, |token| {
+                overlaps |= terms.iter().any(|term| term == token)
+            });
+            assert!(overlaps, "{hit:?}");
+            let entry = lexicon.entry_by_id(hit.lexeme_id).expect("hit entry");
+            assert_eq!(entry.word(), hit.word);
+            assert!(
+                entry
+                    .all_definitions()
+                    .chain(entry.senses().filter_map(|sense| sense.definition()))
+                    .any(|definition| definition == hit.definition)
+            );
+        }
+        assert!(lexicon.reverse_lookup("the of", 5).is_empty());
+        assert!(lexicon.reverse_lookup(description, 0).is_empty());
+    }
+
+    #[test]
+    fn sense_level_relations_resolve_to_lexeme_ids() {
+        let lexicon = Lexicon::embedded();
+        let related = lexicon
+            .all_words()
+            .iter()
+            .map(|(_, id)| *id)
+            .find(|id| {
+                lexicon.entry_by_id(*id).is_some_and(|entry| {
+                    entry.senses().any(|sense| {
+                        sense
+                            .neighbor_ids(RelationKind::Synonym)
+                            .iter()
+                            .any(|neighbor| neighbor != id)
+                    })
+                })
+            })
+            .expect("some sense has a synonym with its own entry");
+        let dog = lexicon.get("dog").expect("dog in fst");
+
+        for id in [related, dog] {
+            let entry = lexicon.entry_by_id(id).expect("entry");
+            for sense in entry.senses() {
+                for relation in RelationKind::all() {
+                    let words: Vec<&str> = match relation {
+                        RelationKind::Synonym => sense.synonyms().collect(),
+                        RelationKind::Antonym => sense.antonyms().collect(),
+                        RelationKind::Hypernym => sense.hypernyms().collect(),
+                        RelationKind::Hyponym => sense.hyponyms().collect(),
+                    };
+                    let ids = sense.neighbor_ids(*relation);
+                    for neighbor in &ids {
+                        let word = lexicon.entry_by_id(*neighbor).expect("neighbor").word();
+                        assert!(
+                            words.contains(&word),
+                            "{} {relation:?}: {word}",
+                            sense.key()
+                        );
+                    }
+                    let resolvable: Vec<u32> =
+                        words.iter().filter_map(|word| lexicon.get(word)).collect();
+                    assert_eq!(ids, resolvable, "{} {relation:?}", sense.key());
+                }
+            }
+
+            let options = GraphOptions {
+                max_depth: 1,
+                relations: vec![RelationKind::Synonym, RelationKind::Hypernym],
+                sense_level: true,
+                ..GraphOptions::default()
+            };
+            let senses: Vec<_> = entry.senses().collect();
+            let graph = lexicon.traverse_graph(id, &options).expect("graph");
+            for edge in &graph.edges {
+                assert_eq!(edge.from, id);
+                let sense = &senses[edge.sense.expect("sense-level edge") as usize];
+                assert!(sense.neighbor_ids(edge.relation).contains(&edge.to));
+            }
+            if id == related {
+                assert!(!graph.edges.is_empty());
+            }
+            let entry_level = GraphOptions {
+                sense_level: false,
+                ..options
+            };
+            let graph = lexicon.traverse_graph(id, &entry_level).expect("graph");
+            for edge in &graph.edges {
+                assert_eq!(edge.sense, None);
+                assert!(entry.neighbor_ids(edge.relation).contains(&edge.to));
+            }
+        }
+    }
+
+    #[test]
+    fn shortest_path_follows_relation_links() {
+        let lexicon = Lexicon::embedded();
+        let assert_valid = |path: &[GraphEdge], from: u32, to: u32, options: &PathOptions| {
+            assert!(path.len() <= options.max_hops);
+            assert_eq!(path.first().map(|edge| edge.from), Some(from));
+            assert_eq!(path.last().map(|edge| edge.to), Some(to));
+            assert!(path.windows(2).all(|pair| pair[0].to == pair[1].from));
+            for edge in path {
+                let entry = lexicon.entry_by_id(edge.from).expect("path node");
+                assert!(
+                    entry.neighbor_ids(edge.relation).contains(&edge.to),
+                    "{edge:?}"
+                );
+                assert!(options.relations.is_empty() || options.relations.contains(&edge.relation));
+                assert_eq!(edge.sense, None);
+            }
+        };
+        let dog = lexicon.get("dog").expect("dog in fst");
+        let reachable = lexicon
+            .traverse_graph(
+                dog,
+                &GraphOptions {
+                    max_depth: 3,
+                    relations: RelationKind::all().to_vec(),
+                    ..GraphOptions::default()
+                },
+            )
+            .expect("graph");
+        let farthest = reachable
+            .nodes
+            .iter()
+            .max_by_key(|node| node.depth)
+            .filter(|node| node.depth > 0)
+            .expect("dog has related lexemes");
+
+        let options = PathOptions::default();
+        let path = lexicon
+            .shortest_path(dog, farthest.lexeme_id, &options)
+            .expect("path");
+        assert_valid(&path, dog, farthest.lexeme_id, &options);
+        assert_eq!(path.len(), farthest.depth);
+
+        let too_short = PathOptions {
+            max_hops: farthest.depth - 1,
+            ..PathOptions::default()
+        };
+        assert!(
+            lexicon
+                .shortest_path(dog, farthest.lexeme_id, &too_short)
+                .is_none()
+        );
+        let single = PathOptions {
+            relations: vec![path[0].relation],
+            ..PathOptions::default()
+        };
+        let hop = lexicon
+            .shortest_path(dog, path[0].to, &single)
+            .expect("one-hop path");
+        assert_valid(&hop, dog, path[0].to, &single);
+        assert_eq!(hop.len(), 1);
+        assert!(
+            lexicon
+                .shortest_path(dog, dog, &options)
+                .expect("self")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn sense_ids_round_trip_through_keys() {
+        let lexicon = Lexicon::embedded();
+        let dog = lexicon.get("dog").expect("dog in fst");
+        let entry = lexicon.entry_by_id(dog).expect("dog entry");
+        let senses: Vec<_> = entry.senses().collect();
+        assert!(!senses.is_empty());
+        for (idx, sense) in senses.iter().enumerate() {
+            let id = sense.id();
+            assert_eq!(id, SenseId::new(dog, idx as u32 + 1));
+            let expected = match sense.part_of_speech() {
+                Some(pos) => format!("dog#{}.{}", sense::pos_tag(pos), id.ordinal),
+                None => format!("dog#{}", id.ordinal),
+            };
+            assert_eq!(sense.key(), expected);
+            for reference in [expected, format!("DOG#{}", id.ordinal)] {
+                assert_eq!(lexicon.resolve_sense(&reference).unwrap(), Some(id));
+            }
+            if let Some(pos) = sense.part_of_speech() {
+                let full = format!("dog#{pos}.{}", id.ordinal);
+                assert_eq!(lexicon.resolve_sense(&full).unwrap(), Some(id));
+            }
+            let resolved = lexicon.sense(id).expect("sense by id");
+            assert_eq!(resolved.key(), sense.key());
+            assert_eq!(resolved.definition(), sense.definition());
+            assert_eq!(id.to_string().parse::<SenseId>().unwrap(), id);
+        }
+
+        let past_end = senses.len() + 1;
+        assert_eq!(
+            lexicon.resolve_sense(&format!("dog#{past_end}")).unwrap(),
+            None
+        );
+        assert_eq!(lexicon.resolve_sense("dog#no-such-pos.1").unwrap(), None);
+        assert!(lexicon.sense(SenseId::new(dog, past_end as u32)).is_none());
+        assert!(lexicon.sense(SenseId::new(dog, 0)).is_none());
+        assert!(lexicon.resolve_sense("dog#0").is_err());
+        assert!(lexicon.resolve_sense("dog").is_err());
+    }
+
+    #[test]
+    fn suggestions_rank_by_edit_distance() {
+        let lexicon = Lexicon::embedded();
+        let dog = lexicon.get("dog").expect("dog in fst");
+        let suggestions = lexicon.suggest("dgo", DEFAULT_SUGGEST_EDITS, 5);
+        assert!(
+            suggestions
+                .iter()
+                .any(|s| s.lexeme_id == dog && s.distance == 2)
+        );
+        assert!(
+            suggestions
+                .windows(2)
+                .all(|pair| pair[0].distance <= pair[1].distance)
+        );
+        assert_eq!(lexicon.suggest("Dog", 1, 1)[0].word, "dog");
+        assert!(lexicon.suggest("dgo", DEFAULT_SUGGEST_EDITS, 0).is_empty());
+    }
+
+    #[test]
+    fn pattern_search_applies_globs_regexes_and_filters() {
+        let lexicon = Lexicon::embedded();
+        let words = |query: &PatternQuery| -> Vec<String> {
+            lexicon
+                .search_pattern(query, 100)
+                .unwrap()
+                .into_iter()
+                .map(|(word, _)| word)
+                .collect()
+        };
+        let three = words(&PatternQuery::glob("d?g"));
+        assert!(three.contains(&"dog".to_string()));
+        assert!(three.iter().all(|word| {
+            word.starts_with('d') && word.ends_with('g') && word.chars().count() == 3
+        }));
+        assert!(
+            words(&PatternQuery::glob("d[!o]g"))
+                .iter()
+                .all(|word| word.chars().nth(1) != Some('o'))
+        );
+        assert!(words(&PatternQuery::regex("do(g|gs)")).contains(&"dog".to_string()));
+        assert!(
+            words(&PatternQuery::glob("*-*"))
+                .iter()
+                .all(|word| word.contains('-'))
+        );
+
+        let mut sized = PatternQuery::glob("*");
+        sized.min_len = Some(3);
+        sized.max_len = Some(3);
+        assert!(words(&sized).iter().all(|word| word.chars().count() == 3));
+
+        let dog = lexicon.entry_by_word("dog").expect("dog");
+        let pos = dog
+            .parts_of_speech()
+            .next()
+            .expect("dog has a part of speech");
+        let mut tagged = PatternQuery::glob("d?g");
+        tagged.part_of_speech = Some(pos.to_uppercase());
+        let tagged = words(&tagged);
+        assert!(tagged.contains(&"dog".to_string()));
+        for word in &tagged {
+            let entry = lexicon.entry_by_word(word).expect("pattern hits resolve");
+            assert!(
+                entry
+                    .parts_of_speech()
+                    .any(|candidate| candidate.eq_ignore_ascii_case(pos))
+            );
+        }
+        let mut untagged = PatternQuery::glob("dog");
+        untagged.part_of_speech = Some("no such part of speech".to_string());
+        assert!(words(&untagged).is_empty());
+
+        assert!(matches!(
+            lexicon.search_pattern(&PatternQuery::regex("(dog"), 10),
+            Err(LexemeError::InvalidPattern { .. })
+        ));
+    }
+
+    #[test]
+    fn fuzzy_scorers_are_selectable_per_field() {
+        let mut config = SearchConfig::default();
+        config
+            .apply_scorer_spec("partial_ratio, word:jaro-winkler")
+            .unwrap();
+        assert_eq!(config.scorer(FieldKind::Word), FuzzyScorer::JaroWinkler);
+        assert_eq!(config.scorer_encyclopedia, FuzzyScorer::PartialRatio);
+        assert_ne!(config, SearchConfig::default());
+        assert!(config.apply_scorer_spec("word:cosine").is_err());
+        assert!(config.apply_scorer_spec("pronunciation:ratio").is_err());
+
+        let lexicon = Lexicon::embedded();
+        let results = lexicon.search_fuzzy("dog", &config, 3);
+        assert!(!results.is_empty());
+        let breakdowns = lexicon.explain_search("dog", &config, &results);
+        assert_eq!(breakdowns[0].fields[0].scorer, FuzzyScorer::JaroWinkler);
+    }
+
+    #[test]
+    fn fuzzy_search_covers_extra_fields() {
+        let lexicon = Lexicon::embedded();
+        let mut config = SearchConfig::default();
+        for field in FieldKind::ALL {
+            config.set_weight(field, 0.0);
+        }
+        config.set_weight(FieldKind::Inflections, 1.0);
+        assert_eq!(config.total_weight(), 1.0);
+
+        let (inflected, inflection) = lexicon
+            .all_words()
+            .iter()
+            .find_map(|(word, id)| {
+                let entry = lexicon.entry_by_id(*id)?;
+                let inflection = entry
+                    .all_inflections()
+                    .find(|form| form.chars().count() >= 4 && !form.eq_ignore_ascii_case(word))?;
+                Some((*id, inflection.to_owned()))
+            })
+            .expect("some entry lists inflections");
+        let results = lexicon.search_fuzzy(&inflection, &config, 100);
+        let hit = results
+            .iter()
+            .find(|row| row.lexeme_id == inflected)
+            .expect("the inflected lexeme");
+        let breakdowns = lexicon.explain_search(&inflection, &config, std::slice::from_ref(hit));
+        let field = &breakdowns[0].fields[0];
+        assert_eq!(field.field, FieldKind::Inflections);
+        assert_eq!(field.sample.as_deref(), Some(inflection.as_str()));
+
+        config.set_weight(FieldKind::Inflections, 0.0);
+        config.set_weight(FieldKind::Examples, 1.0);
+        config.scorer_examples = FuzzyScorer::TokenSetRatio;
+        let dog = lexicon.get("dog").expect("dog in fst");
+        let entry = lexicon.entry_by_id(dog).expect("dog entry");
+        let example = entry.all_examples().next().expect("dog example");
+        let results = lexicon.search_fuzzy(example, &config, 20);
+        assert!(results.iter().any(|row| row.lexeme_id == dog));
+
+        let mut term = None;
+        fulltext::tokenize(example, |token| {
+            term.get_or_insert_with(|| token.to_owned());
+        });
+        let term = term.expect("example term");
+        for row in lexicon.search_text(&term, &config, 10) {
+            let entry = lexicon.entry_by_id(row.lexeme_id).expect("text hit");
+            let mut found = false;
+            for example in entry.all_examples() {
+                fulltext::tokenize(example, |token| found |= token == term);
+            }
+            assert!(found, "{} has no example mentioning {term}", row.word);
+        }
+        assert_eq!("etymology".parse(), Ok(FieldKind::Etymology));
+    }
+
+    #[test]
+    fn search_filters_narrow_results_and_count_facets() {
+        let lexicon = Lexicon::embedded();
+        let matches = lexicon.search_contains("dog", usize::MAX);
+        assert!(!matches.is_empty());
+        let entries: Vec<LexemeEntry<'_>> = matches
+            .iter()
+            .map(|(_, id)| lexicon.entry_by_id(*id).expect("match entry"))
+            .collect();
+        let (page, facets) =
+            lexicon.search_contains_filtered("dog", &SearchFilters::default(), None, matches.len());
+        assert_eq!(page.items, matches);
+        assert!(page.next_cursor.is_none());
+        assert_eq!(facets.total, matches.len());
+        let count =
+            |keep: &dyn Fn(&LexemeEntry<'_>) -> bool| entries.iter().filter(|e| keep(e)).count();
+        assert_eq!(facets.with_encyclopedia, count(&|e| e.has_encyclopedia()));
+        assert_eq!(facets.with_etymology, count(&|e| e.has_etymology()));
+        assert_eq!(facets.stopwords, count(&|e| e.is_stopword()));
+        assert_eq!(facets.sense_counts.values().sum::<usize>(), facets.total);
+        for (pos, total) in &facets.parts_of_speech {
+            let listed = entries
+                .iter()
+                .flat_map(|entry| entry.parts_of_speech())
+                .filter(|candidate| candidate == pos)
+                .count();
+            assert_eq!(*total, listed, "{pos}");
+        }
+
+        let dog = lexicon.entry_by_word("dog").expect("dog entry");
+        let pos = dog.parts_of_speech().next().expect("dog part of speech");
+        for filters in [
+            SearchFilters {
+                has_encyclopedia: Some(true),
+                ..SearchFilters::default()
+            },
+            SearchFilters {
+                has_etymology: Some(false),
+                stopword: Some(false),
+                ..SearchFilters::default()
+            },
+            SearchFilters {
+                part_of_speech: Some(pos.to_uppercase()),
+                min_senses: Some(2),
+                ..SearchFilters::default()
+            },
+            SearchFilters {
+                max_senses: Some(1),
+                ..SearchFilters::default()
+            },
+        ] {
+            let accepts = |entry: &LexemeEntry<'_>| {
+                let senses = entry.senses().count();
+                filters
+                    .has_encyclopedia
+                    .is_none_or(|wanted| entry.has_encyclopedia() == wanted)
+                    && filters
+                        .has_etymology
+                        .is_none_or(|wanted| entry.has_etymology() == wanted)
+                    && filters
+                        .stopword
+                        .is_none_or(|wanted| entry.is_stopword() == wanted)
+                    && filters.min_senses.is_none_or(|min| senses >= min)
+                    && filters.max_senses.is_none_or(|max| senses <= max)
+                    && filters.part_of_speech.as_deref().is_none_or(|wanted| {
+                        entry
+                            .parts_of_speech()
+                            .any(|candidate| candidate.eq_ignore_ascii_case(wanted))
+                    })
+            };
+            let expected: Vec<(String, u32)> = matches
+                .iter()
+                .zip(&entries)
+                .filter(|(_, entry)| accepts(entry))
+                .map(|(hit, _)| hit.clone())
+                .collect();
+            let (page, facets) =
+                lexicon.search_contains_filtered("dog", &filters, None, matches.len());
+            assert_eq!(page.items, expected, "{filters:?}");
+            assert_eq!(facets.total, expected.len());
+            if filters.has_encyclopedia == Some(true) {
+                assert_eq!(facets.with_encyclopedia, facets.total);
+            }
+        }
+
+        let config = SearchConfig {
+            filters: SearchFilters {
+                part_of_speech: Some(pos.to_string()),
+                ..SearchFilters::default()
+            },
+            ..SearchConfig::default()
+        };
+        let summary = lexicon.search_fuzzy_with_stats("dog", &config, 5);
+        assert!(summary.results.iter().any(|row| row.word == "dog"));
+        assert_eq!(summary.facets.total, summary.facets.parts_of_speech[pos]);
+        assert!(summary.results.iter().all(|row| {
+            lexicon
+                .entry_by_id(row.lexeme_id)
+                .is_some_and(|entry| entry.parts_of_speech().any(|candidate| candidate == pos))
+        }));
+        assert_ne!(config, SearchConfig::default());
+    }
+
+    #[test]
+    fn search_results_carry_highlighted_snippets() {
+        let lexicon = Lexicon::embedded();
+        let dog = lexicon.entry_by_word("dog").expect("dog entry");
+        let (field, term) = dog
+            .all_examples()
+            .map(|text| (FieldKind::Examples, text))
+            .chain(
+                dog.all_definitions()
+                    .map(|text| (FieldKind::Definitions, text)),
+            )
+            .find_map(|(field, text)| {
+                let word = text
+                    .split(|ch: char| !ch.is_ascii_alphabetic())
+                    .find(|word| {
+                        word.len() >= 5 && !snippet::highlight_terms([*word]).is_empty()
+                    })?;
+                Some((field, word.to_lowercase()))
+            })
+            .expect("dog has a long word in an example or definition");
+        let short = match field {
+            FieldKind::Examples => "ex",
+            _ => "def",
+        };
+        let results = lexicon
+            .query(&format!("word:dog {short}:{term}"), 1)
+            .unwrap();
+        let snippet = results[0].snippet.as_ref().expect("dog snippet");
+        assert!(
+            snippet
+                .segments()
+                .iter()
+                .any(|(piece, marked)| *marked && piece.to_lowercase().starts_with(&term)),
+            "{snippet:?}"
+        );
+        let joined: String = snippet
+            .segments()
+            .into_iter()
+            .map(|(piece, _)| piece)
+            .collect();
+        assert_eq!(joined, snippet.text);
+
+        let config = SearchConfig {
+            weight_examples: 1.0,
+            ..SearchConfig::default()
+        };
+        for result in lexicon.search_text(&term, &config, 10) {
+            let Some(snippet) = result.snippet else {
+                continue;
+            };
+            assert!(snippet.text.chars().count() <= 162);
+            for span in &snippet.highlights {
+                assert!(snippet.text.get(span.clone()).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn snippets_read_encyclopedia_only_when_needed() {
+        let lexicon = Lexicon::embedded();
+        let (entry, terms) = lexicon
+            .all_words()
+            .iter()
+            .find_map(|(_, id)| {
+                let entry = lexicon.entry_by_id(*id)?;
+                if !entry.has_encyclopedia() {
+                    return None;
+                }
+                let terms = snippet::highlight_terms(entry.all_definitions().take(1));
+                (!terms.is_empty()).then_some((entry, terms))
+            })
+            .expect("an entry with an encyclopedia article and a definition");
+        let mut results = vec![SearchResult {
+            lexeme_id: entry.lexeme_id(),
+            word: entry.word().to_owned(),
+            score: 1.0,
+            snippet: None,
+        }];
+        lexicon.attach_snippets(&terms, false, &mut results);
+        let snippet = results[0].snippet.as_ref().expect("definition snippet");
+        assert_ne!(snippet.field, FieldKind::Encyclopedia);
+        assert!(!snippet.highlights.is_empty());
+        assert!(!lexicon.store.is_loaded(Section::Texts));
+
+        lexicon.attach_snippets(&terms, true, &mut results);
+        assert!(results[0].snippet.is_some());
+        assert!(lexicon.store.is_loaded(Section::Texts));
+    }
+
+    #[test]
+    fn cursors_page_through_prefix_substring_and_fuzzy_results() {
+        let lexicon = Lexicon::embedded();
+        let mut paged = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = lexicon.prefix_page("dog", cursor.as_ref(), 2);
+            assert!(page.items.len() <= 2);
+            paged.extend(page.items);
+            match page.next_cursor {
+                Some(next) => cursor = Some(next.to_string().parse().unwrap()),
+                None => break,
+            }
+        }
+        assert_eq!(paged, lexicon.prefix("dog", usize::MAX));
+
+        let filters = SearchFilters::default();
+        let (all, facets) = lexicon.search_contains_filtered("dog", &filters, None, usize::MAX);
+        assert_eq!(all.items.len(), facets.total);
+        assert_eq!(all.next_cursor, None);
+        let mut paged = Vec::new();
+        let mut cursor = None;
+        loop {
+            let (page, _) = lexicon.search_contains_filtered("dog", &filters, cursor.as_ref(), 3);
+            assert!(page.items.len() <= 3);
+            paged.extend(page.items);
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+        assert_eq!(paged, all.items);
+
+        // A cached short search must not cut the unfiltered pages short.
+        lexicon.search_contains("dog", 1);
+        for (pattern, expected) in [
+            ("dog", all.items.clone()),
+            ("do", lexicon.search_contains("do", 12)),
+        ] {
+            let mut paged = Vec::new();
+            let mut cursor = None;
+            while paged.len() < expected.len() {
+                let page = lexicon.search_contains_page(pattern, cursor.as_ref(), 3);
+                assert!(page.items.len() <= 3);
+                paged.extend(page.items);
+                match page.next_cursor {
+                    Some(next) => cursor = Some(next),
+                    None => break,
+                }
+            }
+            assert_eq!(paged, expected, "{pattern}");
+        }
+
+        let config = SearchConfig {
+            candidate_budget: 0,
+            min_score: 0.0,
+            ..SearchConfig::default()
+        };
+        let expected: Vec<u32> = lexicon
+            .search_fuzzy("dog", &config, 12)
+            .iter()
+            .map(|row| row.lexeme_id)
+            .collect();
+        let mut ids = Vec::new();
+        let mut cursor = None;
+        while ids.len() < expected.len() {
+            let summary = lexicon.search_fuzzy_page("dog", &config, cursor.as_ref(), 5);
+            assert!(!summary.results.is_empty());
+            ids.extend(summary.results.iter().map(|row| row.lexeme_id));
+            cursor = summary.next_cursor;
+            match &cursor {
+                Some(next) => assert!(next.is_ranked()),
+                None => break,
+            }
+        }
+        assert_eq!(ids[..expected.len()], expected[..]);
+    }
+
+    #[test]
+    fn structured_queries_combine_fields_and_facets() {
+        let lexicon = Lexicon::embedded();
+        let hits = |query: &Query| -> Vec<LexemeEntry<'_>> {
+            lexicon
+                .run_query(query, 10_000)
+                .unwrap()
+                .into_iter()
+                .map(|row| {
+                    lexicon
+                        .entry_by_id(row.lexeme_id)
+                        .expect("query hits resolve")
+                })
+                .collect()
+        };
+        let mentions = |mut values: Box<dyn Iterator<Item = &str> + '_>, needle: &str| {
+            let needle = needle.to_lowercase();
+            values.any(|value| value.to_lowercase().contains(&needle))
+        };
+        let dog = lexicon.get("dog").expect("dog in fst");
+        let entry = lexicon.entry_by_id(dog).expect("dog entry");
+
+        // Field and facet clauses built from dog's own entry must find it, and every hit must
+        // satisfy each clause.
+        let pos = entry.parts_of_speech().next().expect("dog part of speech");
+        let term = entry
+            .all_definitions()
+            .flat_map(str::split_whitespace)
+            .find(|word| word.len() >= 6 && word.chars().all(char::is_alphabetic))
+            .expect("dog definition word");
+        let query = Query::parse(&format!("pos:{pos} def:{term} -word:doghouse")).unwrap();
+        let found = hits(&query);
+        assert!(found.iter().any(|hit| hit.lexeme_id() == dog));
+        for hit in &found {
+            assert!(hit.parts_of_speech().any(|p| p.eq_ignore_ascii_case(pos)));
+            assert!(mentions(Box::new(hit.all_definitions()), term));
+            assert_ne!(normalize_key(hit.word()), "doghouse");
+        }
+
+        // A typo still matches fuzzily.
+        let mut typo = term.to_string();
+        typo.remove(typo.len() / 2);
+        let fuzzy = Query::parse(&format!("def:{typo}~")).unwrap();
+        let found = hits(&fuzzy);
+        assert!(found.iter().any(|hit| hit.lexeme_id() == dog));
+        for hit in &found {
+            assert!(hit.all_definitions().any(|text| {
+                FuzzyScorer::PartialRatio.score(&typo.to_lowercase(), &text.to_lowercase())
+                    >= DEFAULT_FUZZY_THRESHOLD
+            }));
+        }
+
+        for hit in hits(&Query::parse("word:dog* -word:doghouse").unwrap()) {
+            let word = normalize_key(hit.word());
+            assert!(word.starts_with("dog") && word != "doghouse", "{word}");
+        }
+        for hit in hits(&Query::parse("is:stopword has:encyclopedia").unwrap()) {
+            assert!(hit.is_stopword() && hit.has_encyclopedia());
+        }
+
+        // `OR` keeps entries matching either side.
+        let (inflected, inflection) = lexicon
+            .all_words()
+            .iter()
+            .find_map(|(_, id)| {
+                let entry = lexicon.entry_by_id(*id)?;
+                let inflection = entry.all_inflections().next()?.to_owned();
+                Some((*id, inflection))
+            })
+            .expect("some entry lists inflections");
+        let either = Query::Or(vec![
+            Query::Match {
+                field: Some(FieldKind::Inflections),
+                value: inflection.clone(),
+                fuzzy: None,
+            },
+            Query::Word("dog".into()),
+        ]);
+        let found = hits(&Query::parse(&either.to_string()).unwrap());
+        assert!(found.iter().any(|hit| hit.lexeme_id() == inflected));
+        assert!(found.iter().any(|hit| hit.lexeme_id() == dog));
+        for hit in &found {
+            assert!(
+                normalize_key(hit.word()) == "dog"
+                    || mentions(Box::new(hit.all_inflections()), &inflection)
+            );
+        }
+
+        assert!(matches!(
+            lexicon.query("def:(", 10),
+            Err(LexemeError::InvalidQuery { .. })
+        ));
+    }
+
+    #[test]
+    fn fuzzy_candidates_narrow_scoring() {
+        let lexicon = Lexicon::embedded();
+        let exhaustive = SearchConfig {
+            candidate_budget: 0,
+            ..SearchConfig::default()
+        };
+        let full = lexicon.search_fuzzy_with_stats("dog", &exhaustive, 3);
+        assert_eq!(full.candidates, None);
+
+        let narrow = SearchConfig {
+            candidate_budget: 4,
+            ..SearchConfig::default()
+        };
+        let filtered = lexicon.search_fuzzy_with_stats("dog", &narrow, 3);
+        let scored = filtered.candidates.expect("pre-filtered");
+        assert!(scored > 0 && scored <= 8);
+        assert_eq!(filtered.results[0].lexeme_id, full.results[0].lexeme_id);
+
+        let cached = lexicon.search_fuzzy_with_stats("dog", &narrow, 3);
+        assert!(cached.cache_hit);
+        assert_eq!(cached.candidates, Some(scored));
+        lexicon.clear_caches();
+        assert!(!lexicon.search_fuzzy_with_stats("dog", &narrow, 3).cache_hit);
+    }
+
+    #[test]
+    fn full_text_search_applies_field_boosts() {
+        let lexicon = Lexicon::embedded();
+        let word_only = SearchConfig {
+            weight_word: 1.0,
+            weight_definitions: 0.0,
+            weight_synonyms: 0.0,
+            weight_text: 0.0,
+            weight_encyclopedia: 0.0,
+            min_score: 0.0,
+            ..SearchConfig::default()
+        };
+        let hits = lexicon.search_text("Dog", &word_only, 5);
+        assert!(hits[0].word.eq_ignore_ascii_case("dog"));
+        assert!(hits.windows(2).all(|pair| pair[0].score >= pair[1].score));
+
+        let defaults = lexicon.search_text("dog", &SearchConfig::default(), 20);
+        assert!(defaults.len() >= hits.len());
+        let disabled = SearchConfig {
+            weight_word: 0.0,
+            ..word_only
+        };
+        assert!(lexicon.search_text("dog", &disabled, 5).is_empty());
+        assert!(
+            lexicon
+                .search_text("the of", &SearchConfig::default(), 5)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn suffix_lookups_use_reversed_fst() {
+        let embedded = Lexicon::embedded();
+        let dog = embedded.get("dog").expect("dog in fst");
+        let hits = embedded.suffix("og", 16);
+        assert!(hits.contains(&("dog".to_string(), dog)));
+        assert!(hits.iter().all(|(word, _)| word.ends_with("og")));
+    }
+
+    #[test]
+    fn derived_fsts_match_prebuilt_artifacts() {
+        let embedded = Lexicon::embedded();
+        // `from_bytes` ships no optional artifacts, so each FST is rebuilt on first use.
+        let derived =
+            Lexicon::from_bytes(EMBEDDED_FST_BYTES.to_vec(), EMBEDDED_DATA_BYTES.to_vec()).unwrap();
+        for (name, rebuilt, prebuilt) in [
+            (
+                "normalized",
+                derived.normalized_map(),
+                embedded.normalized_map(),
+            ),
+            ("reversed", derived.reversed_map(), embedded.reversed_map()),
+            (
+                "inflections",
+                derived.inflections_map(),
+                embedded.inflections_map(),
+            ),
+        ] {
+            assert_eq!(
+                rebuilt.as_fst().as_bytes(),
+                prebuilt.as_fst().as_bytes(),
+                "{name} FST"
+            );
+        }
+    }
+
+    #[test]
+    fn trigram_candidates_match_full_scan() {
+        let indexed = Lexicon::embedded();
+        assert!(indexed.trigrams.is_some());
+        let scanned =
+            Lexicon::from_bytes(EMBEDDED_FST_BYTES.to_vec(), EMBEDDED_DATA_BYTES.to_vec()).unwrap();
+        assert!(scanned.trigrams.is_none());
+        for pattern in ["mail", "obj", "ïve", "d o", "zzzz", "og"] {
+            assert_eq!(
+                indexed.search_contains(pattern, 50),
+                scanned.search_contains(pattern, 50),
+                "pattern {pattern:?}"
+            );
+        }
+        let bytes = scanned.build_trigram_index();
+        let index = TrigramIndex::new(bytes.as_slice()).unwrap();
+        assert_eq!(index.candidates("og"), None);
+        let dog = indexed.get("dog").expect("dog in fst");
+        assert!(index.candidates("dog").unwrap().contains(&dog));
+    }
+
+    #[test]
+    fn facade_shares_default_lexicon() {
+        let id = LexemeIndex::get("dog").expect("dog in fst");
+        let entry = LexemeIndex::entry_by_id(id).expect("dog entry");
+        assert_eq!(
+            entry.word(),
+            LexemeIndex::lexicon().entry_by_id(id).unwrap().word()
+        );
+    }
+}
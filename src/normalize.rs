@@ -0,0 +1,197 @@
+use fst::MapBuilder;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// Separates the normalized form from the big-endian lexeme ID in normalized FST keys, so one
+/// normalized form can map to several lexemes while every key stays unique.
+pub const KEY_SEPARATOR: u8 = 0;
+
+/// Folds a surface form into its lookup key: NFKD with combining marks stripped, case-folded
+/// (see [`push_folded`]), punctuation and symbols dropped, and whitespace runs collapsed to a
+/// single space.
+///
+/// `"Naïve"` becomes `"naive"`, `"e-mail"` becomes `"email"`, `"Straße"` becomes `"strasse"`,
+/// and `" 3D  Object "` becomes `"3d object"`.
+pub fn normalize_key(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut pending_space = false;
+    for ch in input.nfkd() {
+        if is_combining_mark(ch) {
+            continue;
+        }
+        if ch.is_whitespace() {
+            pending_space = !out.is_empty();
+        } else if ch.is_alphanumeric() {
+            if pending_space {
+                out.push(' ');
+                pending_space = false;
+            }
+            push_folded(&mut out, ch);
+        }
+    }
+    out
+}
+
+/// Appends the Unicode full case folding of `ch` to `out`.
+///
+/// For NFKD output this is lowercasing plus the two folds lowercasing leaves out, `ß` → `ss` and
+/// final `ς` → `σ`; NFKD has already taken care of compatibility forms such as `ﬁ`, `ſ`, and `µ`.
+/// The full-text tokenizer and snippet highlighting fold with this too, so every index agrees.
+pub fn push_folded(out: &mut String, ch: char) {
+    for lower in ch.to_lowercase() {
+        match lower {
+            'ß' => out.push_str("ss"),
+            'ς' => out.push('σ'),
+            other => out.push(other),
+        }
+    }
+}
+
+/// Builds the normalized-key FST for `(word, lexeme_id)` rows.
+pub fn build_normalized_fst<'a, I>(rows: I) -> Result<Vec<u8>, fst::Error>
+where
+    I: IntoIterator<Item = (&'a str, u32)>,
+{
+    build_keyed_fst(rows.into_iter().map(|(word, id)| (word, id, id)))
+}
+
+/// Builds the inflection FST for `(inflected_form, lexeme_id, inflection_index)` rows.
+///
+/// Keys use the normalized-FST layout, so one form can point at several lexemes; each value is
+/// the position of the form in that lexeme's `all_inflections`. When two inflections of a lexeme
+/// fold to the same key, the earlier one wins.
+pub fn build_inflection_fst<'a, I>(rows: I) -> Result<Vec<u8>, fst::Error>
+where
+    I: IntoIterator<Item = (&'a str, u32, u32)>,
+{
+    build_keyed_fst(rows)
+}
+
+/// Spells `word` backwards by character (not byte), so multi-byte endings survive: `"naïve"`
+/// becomes `"evïan"`.
+pub fn reverse_key(word: &str) -> String {
+    word.chars().rev().collect()
+}
+
+/// Builds the reversed-word FST for `(word, lexeme_id)` rows, keyed by [`reverse_key`] so suffix
+/// queries become prefix scans.
+pub fn build_reversed_fst<'a, I>(rows: I) -> Result<Vec<u8>, fst::Error>
+where
+    I: IntoIterator<Item = (&'a str, u32)>,
+{
+    let mut keys: Vec<(String, u32)> = rows
+        .into_iter()
+        .map(|(word, id)| (reverse_key(word), id))
+        .collect();
+    keys.sort_unstable();
+    let mut builder = MapBuilder::memory();
+    for (key, id) in keys {
+        builder.insert(key, u64::from(id))?;
+    }
+    builder.into_inner()
+}
+
+fn build_keyed_fst<'a, I>(rows: I) -> Result<Vec<u8>, fst::Error>
+where
+    I: IntoIterator<Item = (&'a str, u32, u32)>,
+{
+    let mut keys: Vec<(Vec<u8>, u32)> = rows
+        .into_iter()
+        .filter_map(|(word, id, value)| {
+            let normalized = normalize_key(word);
+            (!normalized.is_empty()).then(|| {
+                let mut key = normalized.into_bytes();
+                key.push(KEY_SEPARATOR);
+                key.extend_from_slice(&id.to_be_bytes());
+                (key, value)
+            })
+        })
+        .collect();
+    keys.sort_unstable();
+    keys.dedup_by(|next, kept| next.0 == kept.0);
+    let mut builder = MapBuilder::memory();
+    for (key, value) in keys {
+        builder.insert(key, u64::from(value))?;
+    }
+    builder.into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fst::automaton::{Automaton, Str};
+    use fst::{IntoStreamer, Map, Streamer};
+
+    fn entries(bytes: Vec<u8>) -> Vec<(String, u32, u64)> {
+        let map = Map::new(bytes).unwrap();
+        let mut stream = map.stream();
+        let mut out = Vec::new();
+        while let Some((key, value)) = stream.next() {
+            let (form, id) = key.split_last_chunk::<4>().unwrap();
+            let (separator, form) = form.split_last().unwrap();
+            assert_eq!(*separator, KEY_SEPARATOR);
+            let form = String::from_utf8(form.to_vec()).unwrap();
+            out.push((form, u32::from_be_bytes(*id), value));
+        }
+        out
+    }
+
+    #[test]
+    fn keys_fold_case_accents_and_punctuation() {
+        assert_eq!(normalize_key(" Naïve  Café "), "naive cafe");
+        assert_eq!(normalize_key("E-Mail"), "email");
+        assert_eq!(normalize_key(" 3D \t Object "), "3d object");
+        assert_eq!(normalize_key("ﬁne"), "fine");
+        assert_eq!(normalize_key("Straße"), "strasse");
+        assert_eq!(normalize_key("STRASSE"), normalize_key("straẞe"));
+        assert_eq!(normalize_key("ΛΟΓΟΣ"), normalize_key("λόγος"));
+        assert_eq!(normalize_key("λόγος"), "λογοσ");
+        assert_eq!(normalize_key("--"), "");
+    }
+
+    #[test]
+    fn shared_forms_keep_one_key_per_lexeme() {
+        let fst = build_normalized_fst([("e-mail", 4), ("Email", 2), ("--", 3), ("email", 2)]);
+        assert_eq!(
+            entries(fst.unwrap()),
+            [("email".to_string(), 2, 2), ("email".to_string(), 4, 4)]
+        );
+    }
+
+    #[test]
+    fn reversed_keys_keep_multi_byte_endings() {
+        assert_eq!(reverse_key("naïve"), "evïan");
+        let map = Map::new(build_reversed_fst([("olive", 2), ("naïve", 1), ("dog", 3)]).unwrap())
+            .unwrap();
+        let ending = |suffix: &str| {
+            let prefix = reverse_key(suffix);
+            let mut stream = map.search(Str::new(&prefix).starts_with()).into_stream();
+            let mut ids = Vec::new();
+            while let Some((_, id)) = stream.next() {
+                ids.push(id);
+            }
+            ids
+        };
+        assert_eq!(ending("ïve"), [1]);
+        assert_eq!(ending("ve"), [2, 1]);
+        assert_eq!(ending("og"), [3]);
+        assert_eq!(ending("ive"), [2]);
+    }
+
+    #[test]
+    fn first_inflection_wins_within_a_lexeme() {
+        let fst = build_inflection_fst([("Went", 7, 0), ("went", 7, 1), ("went", 3, 2)]);
+        assert_eq!(
+            entries(fst.unwrap()),
+            [("went".to_string(), 3, 2), ("went".to_string(), 7, 0)]
+        );
+        let map = Map::new(build_normalized_fst([("dog", 1), ("doge", 2)]).unwrap()).unwrap();
+        let dogs = map
+            .range()
+            .ge("dog\0")
+            .lt("dog\x01")
+            .into_stream()
+            .into_values();
+        assert_eq!(dogs, [1]);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::RelationKind;
+use std::collections::HashMap;
+
+/// One directed relation link: `(from, to, relation)`.
+pub(crate) type Link = (u32, u32, RelationKind);
+
+/// Which lexemes link *to* each lexeme, one CSR table per relation, so a search can walk the
+/// neighbor tables backwards.
+pub(crate) struct IncomingNeighbors {
+    tables: Vec<IncomingTable>,
+}
+
+struct IncomingTable {
+    /// `offsets[id]..offsets[id + 1]` indexes `sources` for lexeme `id`.
+    offsets: Vec<u32>,
+    sources: Vec<u32>,
+}
+
+impl IncomingNeighbors {
+    /// Inverts the forward `links` of `count` lexemes.
+    pub fn build(count: usize, links: impl IntoIterator<Item = Link>) -> Self {
+        let mut pairs = vec![Vec::new(); RelationKind::all().len()];
+        for (from, to, relation) in links {
+            if (to as usize) < count && from != to {
+                pairs[slot(relation)].push((to, from));
+            }
+        }
+        let tables = pairs
+            .into_iter()
+            .map(|mut rows| {
+                rows.sort_unstable();
+                rows.dedup();
+                let mut offsets = vec![0u32; count + 1];
+                for (to, _) in &rows {
+                    offsets[*to as usize + 1] += 1;
+                }
+                for idx in 1..offsets.len() {
+                    offsets[idx] += offsets[idx - 1];
+                }
+                IncomingTable {
+                    offsets,
+                    sources: rows.into_iter().map(|(_, from)| from).collect(),
+                }
+            })
+            .collect();
+        Self { tables }
+    }
+
+    /// Lexemes whose `relation` list contains `lexeme_id`, in ID order.
+    pub fn sources(&self, lexeme_id: u32, relation: RelationKind) -> &[u32] {
+        let table = &self.tables[slot(relation)];
+        let idx = lexeme_id as usize;
+        match (table.offsets.get(idx), table.offsets.get(idx + 1)) {
+            (Some(&start), Some(&end)) => &table.sources[start as usize..end as usize],
+            _ => &[],
+        }
+    }
+}
+
+fn slot(relation: RelationKind) -> usize {
+    match relation {
+        RelationKind::Synonym => 0,
+        RelationKind::Antonym => 1,
+        RelationKind::Hypernym => 2,
+        RelationKind::Hyponym => 3,
+    }
+}
+
+/// Where a search side reached a lexeme from: the adjacent lexeme towards its own endpoint and
+/// the relation between them (`None` for the endpoint itself).
+type Visited = HashMap<u32, Option<(u32, RelationKind)>>;
+
+/// Bidirectional BFS for a shortest chain of links from `from` to `to`.
+///
+/// `forward(id)` lists the `(neighbor, relation)` links leaving `id` and `backward(id)` those
+/// arriving at it. Each round expands the smaller frontier by one whole level, so the first
+/// meeting yields a shortest path. Gives up (`None`) past `max_hops` edges or once the two sides
+/// have reached more than `max_visited` lexemes.
+pub(crate) fn shortest_links(
+    from: u32,
+    to: u32,
+    max_hops: usize,
+    max_visited: usize,
+    forward: impl Fn(u32) -> Vec<(u32, RelationKind)>,
+    backward: impl Fn(u32) -> Vec<(u32, RelationKind)>,
+) -> Option<Vec<Link>> {
+    if from == to {
+        return Some(Vec::new());
+    }
+    let mut ahead: Visited = HashMap::from([(from, None)]);
+    let mut behind: Visited = HashMap::from([(to, None)]);
+    let mut front = vec![from];
+    let mut back = vec![to];
+    for _ in 0..max_hops {
+        if front.is_empty() || back.is_empty() {
+            return None;
+        }
+        let (frontier, seen, other, step): (_, _, _, &dyn Fn(u32) -> Vec<(u32, RelationKind)>) =
+            if front.len() <= back.len() {
+                (&mut front, &mut ahead, &behind, &forward)
+            } else {
+                (&mut back, &mut behind, &ahead, &backward)
+            };
+        let mut next = Vec::new();
+        let mut meeting = None;
+        'level: for &node in frontier.iter() {
+            for (neighbor, relation) in step(node) {
+                if seen.contains_key(&neighbor) {
+                    continue;
+                }
+                seen.insert(neighbor, Some((node, relation)));
+                if other.contains_key(&neighbor) {
+                    meeting = Some(neighbor);
+                    break 'level;
+                }
+                next.push(neighbor);
+            }
+        }
+        if let Some(meeting) = meeting {
+            return Some(stitch(meeting, &ahead, &behind));
+        }
+        if ahead.len() + behind.len() > max_visited {
+            return None;
+        }
+        *frontier = next;
+    }
+    None
+}
+
+fn stitch(meeting: u32, ahead: &Visited, behind: &Visited) -> Vec<Link> {
+    let mut links = Vec::new();
+    let mut node = meeting;
+    while let Some(&Some((prev, relation))) = ahead.get(&node) {
+        links.push((prev, node, relation));
+        node = prev;
+    }
+    links.reverse();
+    let mut node = meeting;
+    while let Some(&Some((next, relation))) = behind.get(&node) {
+        links.push((node, next, relation));
+        node = next;
+    }
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use RelationKind::{Antonym, Hypernym, Hyponym, Synonym};
+
+    // 0 -syn-> 1 -hyper-> 2 -ant-> 3 -syn-> 4, a shortcut 0 -hypo-> 5 -syn-> 3, a self-loop on 4,
+    // and 6 on its own.
+    const LINKS: &[Link] = &[
+        (0, 1, Synonym),
+        (1, 2, Hypernym),
+        (2, 3, Antonym),
+        (3, 4, Synonym),
+        (0, 5, Hyponym),
+        (5, 3, Synonym),
+        (4, 4, Synonym),
+    ];
+
+    fn forward(id: u32) -> Vec<(u32, RelationKind)> {
+        LINKS
+            .iter()
+            .filter(|link| link.0 == id)
+            .map(|link| (link.1, link.2))
+            .collect()
+    }
+
+    fn search(from: u32, to: u32, max_hops: usize, max_visited: usize) -> Option<Vec<Link>> {
+        let incoming = IncomingNeighbors::build(7, LINKS.iter().copied());
+        let backward = |id: u32| {
+            RelationKind::all()
+                .iter()
+                .flat_map(|&relation| {
+                    incoming
+                        .sources(id, relation)
+                        .iter()
+                        .map(move |&source| (source, relation))
+                })
+                .collect()
+        };
+        shortest_links(from, to, max_hops, max_visited, forward, backward)
+    }
+
+    #[test]
+    fn incoming_tables_invert_links() {
+        let incoming = IncomingNeighbors::build(7, LINKS.iter().copied());
+        assert_eq!(incoming.sources(3, Synonym), [5]);
+        assert_eq!(incoming.sources(3, Antonym), [2]);
+        assert_eq!(incoming.sources(4, Synonym), [3]);
+        assert!(incoming.sources(0, Synonym).is_empty());
+        assert!(incoming.sources(99, Synonym).is_empty());
+
+        let dropped = IncomingNeighbors::build(2, [(0, 1, Synonym), (1, 9, Synonym)]);
+        assert_eq!(dropped.sources(1, Synonym), [0]);
+        let duplicated =
+            IncomingNeighbors::build(3, [(2, 1, Synonym), (0, 1, Synonym), (2, 1, Synonym)]);
+        assert_eq!(duplicated.sources(1, Synonym), [0, 2]);
+    }
+
+    #[test]
+    fn finds_the_shortest_directed_chain() {
+        assert_eq!(
+            search(0, 4, 6, usize::MAX),
+            Some(vec![(0, 5, Hyponym), (5, 3, Synonym), (3, 4, Synonym)])
+        );
+        assert_eq!(
+            search(1, 4, 6, usize::MAX),
+            Some(vec![(1, 2, Hypernym), (2, 3, Antonym), (3, 4, Synonym)])
+        );
+        assert_eq!(search(0, 1, 6, usize::MAX), Some(vec![(0, 1, Synonym)]));
+        assert_eq!(search(2, 2, 0, usize::MAX), Some(Vec::new()));
+        // Links are directed: nothing leads back to 0, and 6 is unconnected.
+        assert_eq!(search(4, 0, 6, usize::MAX), None);
+        assert_eq!(search(0, 6, 6, usize::MAX), None);
+    }
+
+    #[test]
+    fn gives_up_past_the_hop_and_visit_limits() {
+        assert_eq!(search(0, 4, 2, usize::MAX), None);
+        assert!(search(0, 4, 3, usize::MAX).is_some());
+        assert_eq!(search(0, 4, 6, 2), None);
+    }
+}
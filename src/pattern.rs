@@ -0,0 +1,246 @@
+use fst::Automaton;
+use regex_automata::dfa::Automaton as DfaAutomaton;
+use regex_automata::dfa::{StartKind, dense};
+use regex_automata::util::primitives::StateID;
+use regex_automata::util::start;
+use regex_automata::{Anchored, MatchKind};
+use std::error::Error;
+
+/// Caps the memory spent compiling a pattern so pathological regexes fail fast.
+const DFA_SIZE_LIMIT: usize = 16 * 1024 * 1024;
+
+/// How [`PatternQuery::pattern`] is interpreted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PatternSyntax {
+    /// Shell-style glob: `?` matches one character, `*` any run, `[abc]`/`[!abc]` a class.
+    #[default]
+    Glob,
+    /// Regular expression (regex-automata syntax), implicitly anchored at both ends.
+    Regex,
+}
+
+/// A whole-word pattern query with optional length and part-of-speech filters.
+#[derive(Debug, Clone, Default)]
+pub struct PatternQuery {
+    pub pattern: String,
+    pub syntax: PatternSyntax,
+    /// Minimum word length in characters.
+    pub min_len: Option<usize>,
+    /// Maximum word length in characters.
+    pub max_len: Option<usize>,
+    /// Only keep lexemes with this part of speech (case-insensitive, e.g. `noun`).
+    pub part_of_speech: Option<String>,
+}
+
+impl PatternQuery {
+    pub fn glob(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn regex(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: pattern.into(),
+            syntax: PatternSyntax::Regex,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn accepts_len(&self, word: &str) -> bool {
+        if self.min_len.is_none() && self.max_len.is_none() {
+            return true;
+        }
+        let len = word.chars().count();
+        self.min_len.is_none_or(|min| len >= min) && self.max_len.is_none_or(|max| len <= max)
+    }
+}
+
+/// An anchored DFA compiled from a glob or regex, usable as an `fst` automaton.
+pub(crate) struct PatternAutomaton {
+    dfa: dense::DFA<Vec<u32>>,
+    start: StateID,
+}
+
+impl PatternAutomaton {
+    pub(crate) fn new(pattern: &str, syntax: PatternSyntax) -> Result<Self, String> {
+        let regex = match syntax {
+            PatternSyntax::Glob => glob_to_regex(pattern),
+            PatternSyntax::Regex => pattern.to_owned(),
+        };
+        let dfa = dense::Builder::new()
+            .configure(
+                dense::Config::new()
+                    .start_kind(StartKind::Anchored)
+                    .match_kind(MatchKind::All)
+                    .dfa_size_limit(Some(DFA_SIZE_LIMIT))
+                    .determinize_size_limit(Some(DFA_SIZE_LIMIT)),
+            )
+            .build(&format!("(?:{regex})$"))
+            .map_err(|err| error_chain(&err))?;
+        let start = dfa
+            .start_state(&start::Config::new().anchored(Anchored::Yes))
+            .map_err(|err| err.to_string())?;
+        Ok(Self { dfa, start })
+    }
+}
+
+impl Automaton for PatternAutomaton {
+    type State = StateID;
+
+    fn start(&self) -> StateID {
+        self.start
+    }
+
+    fn is_match(&self, state: &StateID) -> bool {
+        self.dfa.is_match_state(self.dfa.next_eoi_state(*state))
+    }
+
+    fn can_match(&self, state: &StateID) -> bool {
+        !self.dfa.is_dead_state(*state) && !self.dfa.is_quit_state(*state)
+    }
+
+    fn accept(&self, state: &StateID, byte: u8) -> StateID {
+        self.dfa.next_state(*state, byte)
+    }
+}
+
+/// Joins an error with its sources; regex-automata keeps the syntax details in the source.
+fn error_chain(err: &dyn Error) -> String {
+    let mut message = err.to_string();
+    let mut source = err.source();
+    while let Some(inner) = source {
+        message.push_str(": ");
+        message.push_str(&inner.to_string());
+        source = inner.source();
+    }
+    message
+}
+
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::with_capacity(glob.len() * 2);
+    let mut chars = glob.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '?' => regex.push('.'),
+            '*' => regex.push_str(".*"),
+            '[' if glob_class_closes(chars.clone()) => {
+                regex.push('[');
+                if chars.next_if_eq(&'!').is_some() {
+                    regex.push('^');
+                }
+                for inner in chars.by_ref() {
+                    if inner == ']' {
+                        break;
+                    }
+                    if matches!(inner, '\\' | '[' | '&' | '~' | '^') {
+                        regex.push('\\');
+                    }
+                    regex.push(inner);
+                }
+                regex.push(']');
+            }
+            _ => {
+                if regex_meta(ch) {
+                    regex.push('\\');
+                }
+                regex.push(ch);
+            }
+        }
+    }
+    regex
+}
+
+/// Whether the rest of the glob closes a non-empty `[...]` class.
+fn glob_class_closes(mut rest: impl Iterator<Item = char>) -> bool {
+    let mut first = true;
+    for ch in rest.by_ref() {
+        match ch {
+            '!' if first => {}
+            ']' => return !first,
+            _ => first = false,
+        }
+    }
+    false
+}
+
+fn regex_meta(ch: char) -> bool {
+    matches!(
+        ch,
+        '\\' | '.'
+            | '+'
+            | '*'
+            | '?'
+            | '('
+            | ')'
+            | '|'
+            | '['
+            | ']'
+            | '{'
+            | '}'
+            | '^'
+            | '$'
+            | '#'
+            | '&'
+            | '-'
+            | '~'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fst::{IntoStreamer, Set};
+
+    fn matches(pattern: &str, syntax: PatternSyntax) -> Vec<String> {
+        let mut words = vec![
+            "a.b", "cat", "cot", "cut", "dog", "dogs", "e-mail", "naïve", "naive", "x[y]",
+        ];
+        words.sort_unstable();
+        let set = Set::from_iter(words).unwrap();
+        let automaton = PatternAutomaton::new(pattern, syntax).unwrap();
+        set.search(automaton).into_stream().into_strs().unwrap()
+    }
+
+    #[test]
+    fn globs_match_whole_words() {
+        assert_eq!(matches("c?t", PatternSyntax::Glob), ["cat", "cot", "cut"]);
+        assert_eq!(matches("c[ao]t", PatternSyntax::Glob), ["cat", "cot"]);
+        assert_eq!(matches("c[!ao]t", PatternSyntax::Glob), ["cut"]);
+        assert_eq!(matches("do*", PatternSyntax::Glob), ["dog", "dogs"]);
+        assert_eq!(matches("do", PatternSyntax::Glob), Vec::<String>::new());
+        assert_eq!(matches("na?ve", PatternSyntax::Glob), ["naive", "naïve"]);
+    }
+
+    #[test]
+    fn glob_literals_are_escaped() {
+        assert_eq!(matches("*-mail", PatternSyntax::Glob), ["e-mail"]);
+        assert_eq!(matches("a.b", PatternSyntax::Glob), ["a.b"]);
+        assert_eq!(matches("a?b", PatternSyntax::Glob), ["a.b"]);
+        assert_eq!(matches("x[y]", PatternSyntax::Glob), Vec::<String>::new());
+        assert_eq!(matches("x[y", PatternSyntax::Glob), Vec::<String>::new());
+        assert_eq!(glob_to_regex("x[y"), r"x\[y");
+        assert_eq!(glob_to_regex("[]"), r"\[\]");
+        assert_eq!(glob_to_regex("[!^a]"), r"[^\^a]");
+    }
+
+    #[test]
+    fn regexes_are_anchored_at_both_ends() {
+        assert_eq!(matches("do(g|gs)", PatternSyntax::Regex), ["dog", "dogs"]);
+        assert_eq!(matches("og", PatternSyntax::Regex), Vec::<String>::new());
+        assert_eq!(matches("c[^u]t", PatternSyntax::Regex), ["cat", "cot"]);
+        assert!(PatternAutomaton::new("(dog", PatternSyntax::Regex).is_err());
+    }
+
+    #[test]
+    fn length_bounds_count_characters() {
+        let mut query = PatternQuery::glob("*");
+        assert!(query.accepts_len(""));
+        query.min_len = Some(5);
+        query.max_len = Some(5);
+        assert!(query.accepts_len("naïve"));
+        assert!(!query.accepts_len("dog"));
+        assert!(!query.accepts_len("e-mails"));
+    }
+}
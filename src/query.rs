@@ -0,0 +1,575 @@
+use crate::FieldKind;
+use std::fmt;
+
+/// Similarity a `FIELD:VALUE~` match needs when no threshold follows the `~`.
+pub const DEFAULT_FUZZY_THRESHOLD: f32 = 0.8;
+
+/// A parsed structured query, e.g. `pos:noun def:"large animal" -syn:pet has:encyclopedia`.
+///
+/// Syntax, loosest binding first:
+///
+/// * `a OR b` — either side matches.
+/// * `a b` / `a AND b` — both sides match.
+/// * `-a` / `NOT a` — `a` does not match.
+/// * `( … )` — grouping.
+/// * `word:PATTERN` — the headword equals `PATTERN` after [`crate::normalize_key`] folding, or
+///   matches it as a glob when it contains `*`, `?`, or `[`; resolved against the lexeme FSTs.
+/// * `FIELD:VALUE` — case-insensitive substring of the field (or of any of its values), where
+///   `FIELD` is a [`FieldKind`] label or a short alias (`def`, `syn`, `ant`, `hyper`, `infl`,
+///   `deriv`, `coll`, `ex`, `ety`, `enc`). A trailing `~` (or `~0.7`) switches to a fuzzy match
+///   that needs that partial-ratio similarity ([`DEFAULT_FUZZY_THRESHOLD`] by default).
+/// * `pos:NOUN`, `has:FIELD`, `is:stopword` — entry facets.
+/// * a bare `VALUE` — substring (or fuzzy) match against the word and definitions.
+///
+/// Values containing spaces or syntax characters are written in double quotes, with `\"` and
+/// `\\` escapes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Query {
+    And(Vec<Query>),
+    Or(Vec<Query>),
+    Not(Box<Query>),
+    /// `word:PATTERN`.
+    Word(String),
+    /// `FIELD:VALUE` or a bare value (`field: None`, matching the word and definitions).
+    Match {
+        field: Option<FieldKind>,
+        value: String,
+        /// Minimum similarity for a fuzzy match; `None` matches substrings.
+        fuzzy: Option<f32>,
+    },
+    /// `pos:VALUE`, compared case-insensitively with the entry's parts of speech.
+    PartOfSpeech(String),
+    /// `has:FIELD`: the entry has a value for the field.
+    Has(FieldKind),
+    /// `is:stopword`.
+    Stopword,
+}
+
+impl Query {
+    /// Parses the query syntax described on [`Query`].
+    pub fn parse(input: &str) -> Result<Query, String> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let query = parser.or()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(query),
+            Some(Token::Close) => Err("unbalanced `)`".to_string()),
+            Some(token) => Err(format!("unexpected {token}")),
+        }
+    }
+
+    /// Values of the field matches that are not negated, i.e. the text a hit should show.
+    pub(crate) fn positive_values(&self) -> Vec<&str> {
+        let mut values = Vec::new();
+        self.collect_positive_values(&mut values);
+        values
+    }
+
+    fn collect_positive_values<'a>(&'a self, values: &mut Vec<&'a str>) {
+        match self {
+            Query::And(clauses) | Query::Or(clauses) => {
+                for clause in clauses {
+                    clause.collect_positive_values(values);
+                }
+            }
+            Query::Match { value, .. } => values.push(value),
+            Query::Not(_)
+            | Query::Word(_)
+            | Query::PartOfSpeech(_)
+            | Query::Has(_)
+            | Query::Stopword => {}
+        }
+    }
+}
+
+impl fmt::Display for Query {
+    /// Writes the query back in its canonical syntax, which parses to the same tree.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Query::And(clauses) => {
+                for (idx, clause) in clauses.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(" ")?;
+                    }
+                    if matches!(clause, Query::Or(_)) {
+                        write!(f, "({clause})")?;
+                    } else {
+                        write!(f, "{clause}")?;
+                    }
+                }
+                Ok(())
+            }
+            Query::Or(clauses) => {
+                for (idx, clause) in clauses.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(" OR ")?;
+                    }
+                    write!(f, "{clause}")?;
+                }
+                Ok(())
+            }
+            Query::Not(inner) => match inner.as_ref() {
+                Query::And(_) | Query::Or(_) => write!(f, "-({inner})"),
+                _ => write!(f, "-{inner}"),
+            },
+            Query::Word(pattern) => write!(f, "word:{}", Quoted(pattern)),
+            Query::Match {
+                field,
+                value,
+                fuzzy,
+            } => {
+                if let Some(field) = field {
+                    write!(f, "{field}:")?;
+                }
+                write!(f, "{}", Quoted(value))?;
+                match fuzzy {
+                    Some(threshold) if *threshold == DEFAULT_FUZZY_THRESHOLD => f.write_str("~"),
+                    Some(threshold) => write!(f, "~{threshold}"),
+                    None => Ok(()),
+                }
+            }
+            Query::PartOfSpeech(pos) => write!(f, "pos:{}", Quoted(pos)),
+            Query::Has(field) => write!(f, "has:{field}"),
+            Query::Stopword => f.write_str("is:stopword"),
+        }
+    }
+}
+
+/// Writes a value bare when it re-parses as one, and double-quoted otherwise.
+struct Quoted<'a>(&'a str);
+
+impl fmt::Display for Quoted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = self.0;
+        let bare = !value.is_empty()
+            && !value.starts_with('-')
+            && !matches!(value, "AND" | "OR" | "NOT")
+            && !value
+                .chars()
+                .any(|ch| ch.is_whitespace() || matches!(ch, '"' | '(' | ')' | ':' | '~' | '\\'));
+        if bare {
+            return f.write_str(value);
+        }
+        f.write_str("\"")?;
+        for ch in value.chars() {
+            if matches!(ch, '"' | '\\') {
+                f.write_str("\\")?;
+            }
+            write!(f, "{ch}")?;
+        }
+        f.write_str("\"")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    And,
+    Or,
+    Not,
+    /// `-` directly before a term or group.
+    Minus,
+    Term {
+        field: Option<String>,
+        value: String,
+        fuzzy: Option<f32>,
+    },
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Open => f.write_str("`(`"),
+            Token::Close => f.write_str("`)`"),
+            Token::And => f.write_str("`AND`"),
+            Token::Or => f.write_str("`OR`"),
+            Token::Not | Token::Minus => f.write_str("negation"),
+            Token::Term { value, .. } => write!(f, "term {value:?}"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut chars = input.chars().peekable();
+    let mut tokens = Vec::new();
+    while let Some(&ch) = chars.peek() {
+        match ch {
+            _ if ch.is_whitespace() => {
+                chars.next();
+            }
+            '(' => {
+                chars.next();
+                tokens.push(Token::Open);
+            }
+            ')' => {
+                chars.next();
+                tokens.push(Token::Close);
+            }
+            '-' => {
+                chars.next();
+                if chars.peek().is_none_or(|next| next.is_whitespace()) {
+                    return Err("`-` must be followed by a term".to_string());
+                }
+                tokens.push(Token::Minus);
+            }
+            _ => {
+                let (mut head, quoted) = read_value(&mut chars)?;
+                let mut field = None;
+                if !quoted && chars.peek() == Some(&':') {
+                    chars.next();
+                    if head.is_empty() {
+                        return Err("missing field name before `:`".to_string());
+                    }
+                    field = Some(head);
+                    head = read_value(&mut chars)?.0;
+                }
+                if head.is_empty() {
+                    return Err(match field {
+                        Some(field) => format!("missing value after `{field}:`"),
+                        None => "empty term".to_string(),
+                    });
+                }
+                let fuzzy = if chars.peek() == Some(&'~') {
+                    chars.next();
+                    Some(read_threshold(&mut chars)?)
+                } else {
+                    None
+                };
+                let keyword = match head.as_str() {
+                    "AND" => Some(Token::And),
+                    "OR" => Some(Token::Or),
+                    "NOT" => Some(Token::Not),
+                    _ => None,
+                };
+                match keyword {
+                    Some(keyword) if !quoted && field.is_none() && fuzzy.is_none() => {
+                        tokens.push(keyword)
+                    }
+                    _ => tokens.push(Token::Term {
+                        field,
+                        value: head,
+                        fuzzy,
+                    }),
+                }
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Reads a double-quoted string or a bare run up to whitespace or a syntax character; returns
+/// whether it was quoted.
+fn read_value(
+    chars: &mut std::iter::Peekable<std::str::Chars<'_>>,
+) -> Result<(String, bool), String> {
+    let mut value = String::new();
+    if chars.peek() == Some(&'"') {
+        chars.next();
+        loop {
+            match chars.next() {
+                Some('"') => return Ok((value, true)),
+                Some('\\') => match chars.next() {
+                    Some(escaped) => value.push(escaped),
+                    None => break,
+                },
+                Some(ch) => value.push(ch),
+                None => break,
+            }
+        }
+        return Err("unterminated quoted value".to_string());
+    }
+    while let Some(&ch) = chars.peek() {
+        if ch.is_whitespace() || matches!(ch, '(' | ')' | ':' | '~' | '"') {
+            break;
+        }
+        value.push(ch);
+        chars.next();
+    }
+    Ok((value, false))
+}
+
+fn read_threshold(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> Result<f32, String> {
+    let mut digits = String::new();
+    while let Some(&ch) = chars.peek() {
+        if !(ch.is_ascii_digit() || ch == '.') {
+            break;
+        }
+        digits.push(ch);
+        chars.next();
+    }
+    if digits.is_empty() {
+        return Ok(DEFAULT_FUZZY_THRESHOLD);
+    }
+    digits
+        .parse::<f32>()
+        .ok()
+        .filter(|threshold| (0.0..=1.0).contains(threshold))
+        .ok_or_else(|| format!("fuzzy threshold `~{digits}` must be between 0 and 1"))
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn or(&mut self) -> Result<Query, String> {
+        let mut clauses = vec![self.and()?];
+        while self.peek() == Some(&Token::Or) {
+            self.pos += 1;
+            clauses.push(self.and()?);
+        }
+        Ok(flatten(clauses, true))
+    }
+
+    fn and(&mut self) -> Result<Query, String> {
+        let mut clauses = Vec::new();
+        loop {
+            match self.peek() {
+                None | Some(Token::Close) | Some(Token::Or) => break,
+                Some(Token::And) => {
+                    if clauses.is_empty() {
+                        return Err("`AND` needs a term on both sides".to_string());
+                    }
+                    self.pos += 1;
+                    if matches!(self.peek(), None | Some(Token::Close) | Some(Token::Or)) {
+                        return Err("`AND` needs a term on both sides".to_string());
+                    }
+                }
+                Some(_) => clauses.push(self.unary()?),
+            }
+        }
+        if clauses.is_empty() {
+            let after_or = self.pos > 0 && self.tokens[self.pos - 1] == Token::Or;
+            return Err(match self.peek() {
+                Some(Token::Or) => "`OR` needs a term on both sides".to_string(),
+                _ if after_or => "`OR` needs a term on both sides".to_string(),
+                Some(Token::Close) => "empty group".to_string(),
+                _ => "empty query".to_string(),
+            });
+        }
+        Ok(flatten(clauses, false))
+    }
+
+    fn unary(&mut self) -> Result<Query, String> {
+        match self.peek().cloned() {
+            Some(Token::Minus) | Some(Token::Not) => {
+                self.pos += 1;
+                if matches!(self.peek(), None | Some(Token::Close) | Some(Token::Or)) {
+                    return Err("negation must be followed by a term".to_string());
+                }
+                Ok(Query::Not(Box::new(self.unary()?)))
+            }
+            Some(Token::Open) => {
+                self.pos += 1;
+                let inner = self.or()?;
+                if self.peek() != Some(&Token::Close) {
+                    return Err("unbalanced `(`".to_string());
+                }
+                self.pos += 1;
+                Ok(inner)
+            }
+            Some(Token::Term {
+                field,
+                value,
+                fuzzy,
+            }) => {
+                self.pos += 1;
+                term(field.as_deref(), value, fuzzy)
+            }
+            Some(token) => Err(format!("unexpected {token}")),
+            None => Err("empty query".to_string()),
+        }
+    }
+}
+
+/// Collapses single-clause groups and merges nested groups of the same kind.
+fn flatten(clauses: Vec<Query>, or: bool) -> Query {
+    if clauses.len() == 1 {
+        return clauses.into_iter().next().unwrap();
+    }
+    let mut flat = Vec::with_capacity(clauses.len());
+    for clause in clauses {
+        match clause {
+            Query::Or(inner) if or => flat.extend(inner),
+            Query::And(inner) if !or => flat.extend(inner),
+            clause => flat.push(clause),
+        }
+    }
+    if or {
+        Query::Or(flat)
+    } else {
+        Query::And(flat)
+    }
+}
+
+fn term(field: Option<&str>, value: String, fuzzy: Option<f32>) -> Result<Query, String> {
+    let Some(name) = field else {
+        return Ok(Query::Match {
+            field: None,
+            value,
+            fuzzy,
+        });
+    };
+    let facet = |query: Query| {
+        if fuzzy.is_some() {
+            Err(format!("`{name}:` does not take a fuzzy `~`"))
+        } else {
+            Ok(query)
+        }
+    };
+    match name.to_ascii_lowercase().as_str() {
+        "word" | "w" if fuzzy.is_none() => Ok(Query::Word(value)),
+        "pos" => facet(Query::PartOfSpeech(value)),
+        "has" => facet(Query::Has(field_named(&value)?)),
+        "is" if value.eq_ignore_ascii_case("stopword") => facet(Query::Stopword),
+        "is" => Err(format!("unknown `is:{value}` (expected `is:stopword`)")),
+        other => Ok(Query::Match {
+            field: Some(field_named(other)?),
+            value,
+            fuzzy,
+        }),
+    }
+}
+
+fn field_named(name: &str) -> Result<FieldKind, String> {
+    let field = match name.to_ascii_lowercase().as_str() {
+        "w" => FieldKind::Word,
+        "def" | "defs" | "definition" => FieldKind::Definitions,
+        "syn" | "synonym" => FieldKind::Synonyms,
+        "ant" | "antonym" => FieldKind::Antonyms,
+        "hyper" | "hypernym" => FieldKind::Hypernyms,
+        "infl" | "inflection" => FieldKind::Inflections,
+        "deriv" | "derivation" => FieldKind::Derivations,
+        "coll" | "collocation" => FieldKind::Collocations,
+        "ex" | "example" => FieldKind::Examples,
+        "ety" => FieldKind::Etymology,
+        "enc" => FieldKind::Encyclopedia,
+        other => other.parse()?,
+    };
+    Ok(field)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Query {
+        Query::parse(input).unwrap_or_else(|err| panic!("{input:?}: {err}"))
+    }
+
+    fn matching(field: Option<FieldKind>, value: &str, fuzzy: Option<f32>) -> Query {
+        Query::Match {
+            field,
+            value: value.into(),
+            fuzzy,
+        }
+    }
+
+    #[test]
+    fn parses_fields_facets_and_negation() {
+        assert_eq!(
+            parse(r#"pos:noun def:"large animal" -syn:pet has:encyclopedia"#),
+            Query::And(vec![
+                Query::PartOfSpeech("noun".into()),
+                matching(Some(FieldKind::Definitions), "large animal", None),
+                Query::Not(Box::new(matching(Some(FieldKind::Synonyms), "pet", None))),
+                Query::Has(FieldKind::Encyclopedia),
+            ])
+        );
+        assert_eq!(parse("W:dog*"), Query::Word("dog*".into()));
+        assert_eq!(parse("IS:Stopword"), Query::Stopword);
+        assert_eq!(
+            parse("infl:went~"),
+            matching(Some(FieldKind::Inflections), "went", Some(0.8))
+        );
+        assert_eq!(parse("\"OR\""), matching(None, "OR", None));
+        assert_eq!(parse(r#""say \"hi\"""#), matching(None, "say \"hi\"", None));
+    }
+
+    #[test]
+    fn or_binds_looser_than_and() {
+        assert_eq!(
+            parse("a b OR c AND d OR e"),
+            Query::Or(vec![
+                Query::And(vec![matching(None, "a", None), matching(None, "b", None)]),
+                Query::And(vec![matching(None, "c", None), matching(None, "d", None)]),
+                matching(None, "e", None),
+            ])
+        );
+        assert_eq!(
+            parse("(a (b c)) OR (d OR e)"),
+            Query::Or(vec![
+                Query::And(vec![
+                    matching(None, "a", None),
+                    matching(None, "b", None),
+                    matching(None, "c", None),
+                ]),
+                matching(None, "d", None),
+                matching(None, "e", None),
+            ])
+        );
+        assert_eq!(
+            parse("NOT -a"),
+            Query::Not(Box::new(Query::Not(Box::new(matching(None, "a", None)))))
+        );
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in [
+            r#"pos:noun def:"large animal" -syn:pet has:encyclopedia"#,
+            "(word:cat OR word:dog*) NOT is:stopword infl:went~0.7",
+            r#"-(a OR "b c") ex:"x\\y" "-dash" "AND" def:pet~"#,
+            "a OR b c OR -(d e)",
+            "pos:\"phrasal verb\" word:\"ice cream\"",
+        ] {
+            let parsed = parse(input);
+            let printed = parsed.to_string();
+            assert_eq!(parse(&printed), parsed, "{input:?} printed as {printed:?}");
+        }
+        assert_eq!(parse("def:pet~").to_string(), "definitions:pet~");
+        assert_eq!(parse("def:pet~0.5").to_string(), "definitions:pet~0.5");
+        assert_eq!(parse("(a OR b) c").to_string(), "(a OR b) c");
+        assert_eq!(parse("-(a b)").to_string(), "-(a b)");
+    }
+
+    #[test]
+    fn syntax_errors_are_reported() {
+        for (input, message) in [
+            ("", "empty query"),
+            ("   ", "empty query"),
+            ("()", "empty group"),
+            ("pos:", "missing value after `pos:`"),
+            (":dog", "missing field name"),
+            ("(dog", "unbalanced `(`"),
+            ("dog)", "unbalanced `)`"),
+            ("dog OR", "`OR` needs a term"),
+            ("OR dog", "`OR` needs a term"),
+            ("AND dog", "`AND` needs a term"),
+            ("dog AND", "`AND` needs a term"),
+            ("dog -", "`-` must be followed"),
+            ("NOT", "negation must be followed"),
+            ("\"dog", "unterminated"),
+            ("def:x~2", "between 0 and 1"),
+            ("pos:noun~", "does not take a fuzzy"),
+            ("is:common", "is:stopword"),
+            ("color:red", "color"),
+            ("has:wings", "wings"),
+        ] {
+            let err = Query::parse(input).expect_err(input);
+            assert!(err.contains(message), "{input:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn positive_values_skip_negations_and_facets() {
+        let query = parse("dog def:pet -syn:wolf pos:noun (ex:bark OR word:cat)");
+        assert_eq!(query.positive_values(), ["dog", "pet", "bark"]);
+    }
+}
@@ -0,0 +1,108 @@
+use crate::fulltext::tokenize;
+use std::cmp::Ordering;
+
+/// How much of a description one definition accounts for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Overlap {
+    /// Share (0–1) of the description's term weight found in the definition.
+    pub coverage: f32,
+    /// Share (0–1) of the definition's tokens that are description terms; breaks coverage ties
+    /// in favour of the tighter definition.
+    pub precision: f32,
+}
+
+/// Orders overlaps by coverage, then precision (greater is better).
+pub fn compare_overlap(a: &Overlap, b: &Overlap) -> Ordering {
+    a.coverage
+        .total_cmp(&b.coverage)
+        .then(a.precision.total_cmp(&b.precision))
+}
+
+/// Compares `definition` against the weighted description `terms` (distinct tokens, as produced
+/// by the full-text tokenizer). Returns `None` when they share no term.
+pub fn definition_overlap(terms: &[(String, f32)], definition: &str) -> Option<Overlap> {
+    let total: f32 = terms.iter().map(|(_, weight)| weight).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut seen = vec![false; terms.len()];
+    let mut tokens = 0usize;
+    let mut matched = 0usize;
+    tokenize(definition, |token| {
+        tokens += 1;
+        if let Some(idx) = terms.iter().position(|(term, _)| term == token) {
+            seen[idx] = true;
+            matched += 1;
+        }
+    });
+    if matched == 0 {
+        return None;
+    }
+    let covered: f32 = terms
+        .iter()
+        .zip(&seen)
+        .filter(|(_, hit)| **hit)
+        .map(|((_, weight), _)| weight)
+        .sum();
+    Some(Overlap {
+        coverage: covered / total,
+        precision: matched as f32 / tokens as f32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(weighted: &[(&str, f32)]) -> Vec<(String, f32)> {
+        weighted
+            .iter()
+            .map(|(term, weight)| (term.to_string(), *weight))
+            .collect()
+    }
+
+    #[test]
+    fn overlap_weights_coverage_by_term() {
+        let terms = terms(&[("domesticated", 3.0), ("mammal", 1.0)]);
+        let full = definition_overlap(&terms, "A domesticated carnivorous Mammal").unwrap();
+        assert_eq!(full.coverage, 1.0);
+        assert_eq!(full.precision, 2.0 / 3.0);
+
+        let rare = definition_overlap(&terms, "domesticated cattle").unwrap();
+        let common = definition_overlap(&terms, "a small mammal").unwrap();
+        assert_eq!(rare.coverage, 0.75);
+        assert_eq!(common.coverage, 0.25);
+        assert_eq!(common.precision, 0.5);
+    }
+
+    #[test]
+    fn repeated_terms_count_once_for_coverage() {
+        let terms = terms(&[("pet", 1.0), ("dog", 1.0)]);
+        let overlap = definition_overlap(&terms, "pet pet pet").unwrap();
+        assert_eq!(overlap.coverage, 0.5);
+        assert_eq!(overlap.precision, 1.0);
+    }
+
+    #[test]
+    fn overlaps_rank_by_coverage_then_precision() {
+        let overlap = |coverage, precision| Overlap {
+            coverage,
+            precision,
+        };
+        let order = compare_overlap(&overlap(0.5, 1.0), &overlap(0.75, 0.1));
+        assert_eq!(order, Ordering::Less);
+        let order = compare_overlap(&overlap(0.5, 0.5), &overlap(0.5, 0.25));
+        assert_eq!(order, Ordering::Greater);
+        let order = compare_overlap(&overlap(0.5, 0.5), &overlap(0.5, 0.5));
+        assert_eq!(order, Ordering::Equal);
+    }
+
+    #[test]
+    fn no_shared_terms_is_none() {
+        let terms = terms(&[("pet", 1.0)]);
+        assert_eq!(definition_overlap(&terms, "a wild animal"), None);
+        assert_eq!(definition_overlap(&terms, ""), None);
+        assert_eq!(definition_overlap(&[], "pet"), None);
+        assert_eq!(definition_overlap(&[("pet".into(), 0.0)], "pet"), None);
+    }
+}
@@ -0,0 +1,245 @@
+use rapidfuzz::distance::{jaro_winkler, levenshtein};
+use rapidfuzz::fuzz;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+/// String similarity used to score one field in fuzzy search. Every scorer returns `0.0..=1.0`.
+///
+/// Serializes as its [`FuzzyScorer::label`] (`"partial-ratio"`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FuzzyScorer {
+    /// Indel similarity of the whole strings (RapidFuzz `fuzz::ratio`).
+    #[default]
+    Ratio,
+    /// Best [`FuzzyScorer::Ratio`] of the shorter string against equally long windows of the
+    /// longer one, so a short query can fully match inside a paragraph.
+    PartialRatio,
+    /// Ratio over the shared and differing word sets; a query whose words all appear in the
+    /// field scores 1.0 regardless of order or extra words.
+    TokenSetRatio,
+    /// Ratio after sorting the words of both strings, ignoring word order.
+    TokenSortRatio,
+    /// Jaro-Winkler similarity, which favors shared prefixes.
+    JaroWinkler,
+    /// Levenshtein distance normalized by the longer length.
+    Levenshtein,
+}
+
+impl FuzzyScorer {
+    pub const ALL: [FuzzyScorer; 6] = [
+        FuzzyScorer::Ratio,
+        FuzzyScorer::PartialRatio,
+        FuzzyScorer::TokenSetRatio,
+        FuzzyScorer::TokenSortRatio,
+        FuzzyScorer::JaroWinkler,
+        FuzzyScorer::Levenshtein,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FuzzyScorer::Ratio => "ratio",
+            FuzzyScorer::PartialRatio => "partial-ratio",
+            FuzzyScorer::TokenSetRatio => "token-set-ratio",
+            FuzzyScorer::TokenSortRatio => "token-sort-ratio",
+            FuzzyScorer::JaroWinkler => "jaro-winkler",
+            FuzzyScorer::Levenshtein => "levenshtein",
+        }
+    }
+
+    /// Scores `value` against `query`; an empty value scores 0.
+    ///
+    /// The token scorers split on non-alphanumeric characters and lowercase each word; the
+    /// others compare the raw characters.
+    pub fn score(self, query: &str, value: &str) -> f32 {
+        if value.is_empty() {
+            return 0.0;
+        }
+        let score = match self {
+            FuzzyScorer::Ratio => fuzz::ratio(query.chars(), value.chars()),
+            FuzzyScorer::PartialRatio => partial_ratio(query, value),
+            FuzzyScorer::TokenSetRatio => token_set_ratio(query, value),
+            FuzzyScorer::TokenSortRatio => {
+                let mut left = words(query);
+                let mut right = words(value);
+                left.sort_unstable();
+                right.sort_unstable();
+                fuzz::ratio(left.join(" ").chars(), right.join(" ").chars())
+            }
+            FuzzyScorer::JaroWinkler => {
+                jaro_winkler::normalized_similarity(query.chars(), value.chars())
+            }
+            FuzzyScorer::Levenshtein => {
+                levenshtein::normalized_similarity(query.chars(), value.chars())
+            }
+        };
+        score as f32
+    }
+}
+
+impl fmt::Display for FuzzyScorer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.label())
+    }
+}
+
+impl FromStr for FuzzyScorer {
+    type Err = String;
+
+    /// Parses a [`FuzzyScorer::label`]; `_` may stand in for `-` and case is ignored.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let wanted = value.trim().to_ascii_lowercase().replace('_', "-");
+        FuzzyScorer::ALL
+            .into_iter()
+            .find(|scorer| scorer.label() == wanted)
+            .ok_or_else(|| {
+                let known: Vec<&str> = FuzzyScorer::ALL.iter().map(|s| s.label()).collect();
+                format!(
+                    "unknown scorer {value:?} (expected one of {})",
+                    known.join(", ")
+                )
+            })
+    }
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Slides the shorter string over the longer one. Windows that start on a character the shorter
+/// string lacks are skipped: dropping that character and extending the window by one never lowers
+/// the ratio, so the next window scores at least as well.
+fn partial_ratio(query: &str, value: &str) -> f64 {
+    let query: Vec<char> = query.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+    let (short, long) = if query.len() <= value.len() {
+        (query, value)
+    } else {
+        (value, query)
+    };
+    if short.is_empty() {
+        return 0.0;
+    }
+    if short.len() == long.len() {
+        return fuzz::ratio(short.iter().copied(), long.iter().copied());
+    }
+    let mut alphabet = short.clone();
+    alphabet.sort_unstable();
+    alphabet.dedup();
+    let scorer = fuzz::RatioBatchComparator::new(short.iter().copied());
+    let mut best: f64 = 0.0;
+    let last = long.len() - short.len();
+    for start in 0..=last {
+        if start < last && alphabet.binary_search(&long[start]).is_err() {
+            continue;
+        }
+        best = best.max(scorer.similarity(long[start..start + short.len()].iter().copied()));
+        if best >= 1.0 {
+            break;
+        }
+    }
+    best
+}
+
+fn token_set_ratio(query: &str, value: &str) -> f64 {
+    let left: BTreeSet<String> = words(query).into_iter().collect();
+    let right: BTreeSet<String> = words(value).into_iter().collect();
+    if left.is_empty() || right.is_empty() {
+        return 0.0;
+    }
+    let join = |set: Vec<&String>| {
+        set.iter()
+            .map(|word| word.as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let shared = join(left.intersection(&right).collect());
+    let only_left = join(left.difference(&right).collect());
+    let only_right = join(right.difference(&left).collect());
+    if !shared.is_empty() && (only_left.is_empty() || only_right.is_empty()) {
+        return 1.0;
+    }
+    let combine = |rest: &str| {
+        if shared.is_empty() {
+            rest.to_owned()
+        } else {
+            format!("{shared} {rest}")
+        }
+    };
+    let with_left = combine(&only_left);
+    let with_right = combine(&only_right);
+    let mut best = fuzz::ratio(with_left.chars(), with_right.chars());
+    if !shared.is_empty() {
+        best = best
+            .max(fuzz::ratio(shared.chars(), with_left.chars()))
+            .max(fuzz::ratio(shared.chars(), with_right.chars()));
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scorers_compare_as_documented() {
+        assert!(FuzzyScorer::Ratio.score("dog", "a loyal dog") < 1.0);
+        assert_eq!(FuzzyScorer::Ratio.score("dog", "dog"), 1.0);
+        assert_eq!(FuzzyScorer::PartialRatio.score("dog", "a loyal dog"), 1.0);
+        assert_eq!(
+            FuzzyScorer::TokenSetRatio.score("Canine domestic", "a domestic canine"),
+            1.0
+        );
+        assert!(FuzzyScorer::TokenSetRatio.score("wild canine", "a domestic canine") < 1.0);
+        assert_eq!(FuzzyScorer::TokenSortRatio.score("b a", "a, b"), 1.0);
+        assert!(FuzzyScorer::JaroWinkler.score("dog", "dogs") > 0.9);
+        assert!((FuzzyScorer::Levenshtein.score("dog", "dig") - 2.0 / 3.0).abs() < 1e-6);
+        for scorer in FuzzyScorer::ALL {
+            assert_eq!(scorer.score("dog", ""), 0.0, "{scorer}");
+            let score = scorer.score("dog", "hot dogs");
+            assert!((0.0..=1.0).contains(&score), "{scorer}: {score}");
+        }
+    }
+
+    #[test]
+    fn partial_ratio_matches_every_window() {
+        let brute = |short: &str, long: &str| {
+            let long: Vec<char> = long.chars().collect();
+            let len = short.chars().count();
+            (0..=long.len() - len)
+                .map(|start| fuzz::ratio(short.chars(), long[start..start + len].iter().copied()))
+                .fold(0.0, f64::max)
+        };
+        for (short, long) in [
+            ("dog", "a loyal dgo"),
+            ("naïve", "a rather naive view"),
+            ("xyz", "abcdef"),
+            ("mail", "e-mails"),
+        ] {
+            assert_eq!(
+                partial_ratio(short, long),
+                brute(short, long),
+                "{short} in {long}"
+            );
+            assert_eq!(partial_ratio(long, short), partial_ratio(short, long));
+        }
+        assert_eq!(partial_ratio("", "dog"), 0.0);
+    }
+
+    #[test]
+    fn labels_round_trip() {
+        for scorer in FuzzyScorer::ALL {
+            assert_eq!(scorer.to_string().parse::<FuzzyScorer>(), Ok(scorer));
+            let json = serde_json::to_string(&scorer).unwrap();
+            assert_eq!(json, format!("\"{}\"", scorer.label()));
+        }
+        assert_eq!(" Jaro_Winkler ".parse(), Ok(FuzzyScorer::JaroWinkler));
+        let err = "cosine".parse::<FuzzyScorer>().unwrap_err();
+        assert!(err.contains("token-set-ratio"), "{err}");
+    }
+}
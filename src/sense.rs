@@ -0,0 +1,165 @@
+use crate::LexemeError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Stable address of one sense: its lexeme ID plus its 1-based position in
+/// [`LexemeEntry::senses`](crate::LexemeEntry::senses).
+///
+/// IDs stay valid for as long as the dataset does, so they can be stored outside the lexicon.
+/// They round-trip through [`fmt::Display`] and [`FromStr`] as `<lexeme_id>#<ordinal>` (`8#2`);
+/// the word-based form (`dog#n.2`) comes from [`SenseRef::key`](crate::SenseRef::key) and is
+/// resolved with [`Lexicon::resolve_sense`](crate::Lexicon::resolve_sense).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SenseId {
+    pub lexeme_id: u32,
+    /// 1-based position of the sense within its entry.
+    pub ordinal: u32,
+}
+
+impl SenseId {
+    pub const fn new(lexeme_id: u32, ordinal: u32) -> Self {
+        Self { lexeme_id, ordinal }
+    }
+}
+
+impl fmt::Display for SenseId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}#{}", self.lexeme_id, self.ordinal)
+    }
+}
+
+impl FromStr for SenseId {
+    type Err = LexemeError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (lexeme, selector) = split_reference(value)?;
+        let lexeme_id = lexeme
+            .parse()
+            .map_err(|_| invalid(value, "expected a numeric lexeme ID before `#`"))?;
+        match selector {
+            SenseSelector { pos: None, ordinal } => Ok(SenseId::new(lexeme_id, ordinal)),
+            SenseSelector { pos: Some(_), .. } => Err(invalid(
+                value,
+                "use `<lexeme_id>#<ordinal>` without a part of speech",
+            )),
+        }
+    }
+}
+
+/// The part after `#` in a sense reference: an optional part-of-speech tag and the ordinal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SenseSelector<'a> {
+    pub pos: Option<&'a str>,
+    pub ordinal: u32,
+}
+
+/// Splits `word#N` / `word#pos.N` into the word (or lexeme ID) and the sense selector.
+pub(crate) fn split_reference(reference: &str) -> Result<(&str, SenseSelector<'_>), LexemeError> {
+    let (lexeme, selector) = reference
+        .rsplit_once('#')
+        .filter(|(lexeme, _)| !lexeme.trim().is_empty())
+        .ok_or_else(|| invalid(reference, "expected `word#N` or `word#pos.N`"))?;
+    let (pos, ordinal) = match selector.rsplit_once('.') {
+        Some((pos, ordinal)) if !pos.is_empty() => (Some(pos), ordinal),
+        Some(_) => return Err(invalid(reference, "missing part of speech before `.`")),
+        None => (None, selector),
+    };
+    let ordinal = ordinal
+        .parse::<u32>()
+        .ok()
+        .filter(|ordinal| *ordinal > 0)
+        .ok_or_else(|| invalid(reference, "sense numbers start at 1"))?;
+    Ok((lexeme.trim(), SenseSelector { pos, ordinal }))
+}
+
+/// Short tag for a part of speech in sense keys: `n`, `v`, `adj`, `adv`, or the lowercased name
+/// with spaces replaced by `-` for anything else.
+pub(crate) fn pos_tag(pos: &str) -> String {
+    match pos.trim().to_lowercase().as_str() {
+        "noun" => "n".to_owned(),
+        "verb" => "v".to_owned(),
+        "adjective" => "adj".to_owned(),
+        "adverb" => "adv".to_owned(),
+        other => other.split_whitespace().collect::<Vec<_>>().join("-"),
+    }
+}
+
+/// Whether `tag` (a [`pos_tag`] or the full name, any case) names `pos`.
+pub(crate) fn pos_matches(tag: &str, pos: &str) -> bool {
+    tag.eq_ignore_ascii_case(&pos_tag(pos)) || tag.eq_ignore_ascii_case(pos.trim())
+}
+
+fn invalid(reference: &str, message: &str) -> LexemeError {
+    LexemeError::InvalidSense {
+        reference: reference.to_owned(),
+        message: message.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_split_into_word_and_selector() {
+        let (word, selector) = split_reference("dog#2").unwrap();
+        assert_eq!(
+            (word, selector),
+            (
+                "dog",
+                SenseSelector {
+                    pos: None,
+                    ordinal: 2
+                }
+            )
+        );
+        let (word, selector) = split_reference(" hot dog #n.1").unwrap();
+        let expected = SenseSelector {
+            pos: Some("n"),
+            ordinal: 1,
+        };
+        assert_eq!((word, selector), ("hot dog", expected));
+        let (word, selector) = split_reference("c##phrasal verb.3").unwrap();
+        assert_eq!(
+            (word, selector.pos, selector.ordinal),
+            ("c#", Some("phrasal verb"), 3)
+        );
+
+        for bad in [
+            "dog", "#1", " #1", "dog#", "dog#0", "dog#n.0", "dog#.1", "dog#x", "dog#-1",
+        ] {
+            assert!(
+                matches!(
+                    split_reference(bad),
+                    Err(LexemeError::InvalidSense { reference, .. }) if reference == bad
+                ),
+                "{bad:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn pos_tags_abbreviate_common_parts_of_speech() {
+        assert_eq!(pos_tag("Noun"), "n");
+        assert_eq!(pos_tag(" verb "), "v");
+        assert_eq!(pos_tag("adjective"), "adj");
+        assert_eq!(pos_tag("ADVERB"), "adv");
+        assert_eq!(pos_tag("Phrasal  Verb"), "phrasal-verb");
+        assert!(pos_matches("N", "noun"));
+        assert!(pos_matches("Noun", "noun"));
+        assert!(pos_matches("phrasal-verb", "phrasal verb"));
+        assert!(!pos_matches("v", "noun"));
+    }
+
+    #[test]
+    fn sense_ids_round_trip() {
+        let id = SenseId::new(8, 2);
+        assert_eq!(id.to_string(), "8#2");
+        assert_eq!("8#2".parse::<SenseId>().unwrap(), id);
+        assert!(SenseId::new(8, 1) < id && id < SenseId::new(9, 1));
+        for bad in ["dog#2", "8#n.2", "8#0", "8", "-1#1"] {
+            assert!(bad.parse::<SenseId>().is_err(), "{bad:?}");
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use crate::FieldKind;
+use crate::fulltext::tokenize;
+use crate::normalize::normalize_key;
+use rapidfuzz::distance::levenshtein;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// Longest snippet, in characters (not counting the `…` markers), before a passage is cut down
+/// around its first match.
+const SNIPPET_CHARS: usize = 160;
+
+/// Minimum Levenshtein similarity for a passage word to count as a misspelled query term.
+const TYPO_SIMILARITY: f64 = 0.8;
+
+/// A passage from the best-matching field of a search hit, cut down around the query terms it
+/// contains.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snippet {
+    pub field: FieldKind,
+    /// The passage with whitespace collapsed and `…` where it was cut.
+    pub text: String,
+    /// Byte ranges of `text` holding a query term, in order and non-overlapping.
+    pub highlights: Vec<Range<usize>>,
+}
+
+impl Snippet {
+    /// Splits `text` into consecutive `(piece, highlighted)` segments for rendering.
+    pub fn segments(&self) -> Vec<(&str, bool)> {
+        let mut segments = Vec::with_capacity(self.highlights.len() * 2 + 1);
+        let mut cursor = 0;
+        for span in &self.highlights {
+            if span.start > cursor {
+                segments.push((&self.text[cursor..span.start], false));
+            }
+            segments.push((&self.text[span.clone()], true));
+            cursor = span.end;
+        }
+        if cursor < self.text.len() {
+            segments.push((&self.text[cursor..], false));
+        }
+        segments
+    }
+}
+
+/// Tokenizes `values` the way the full-text index does, yielding the distinct terms to highlight.
+pub fn highlight_terms<'a>(values: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for value in values {
+        tokenize(value, |token| {
+            if !terms.iter().any(|term| term == token) {
+                terms.push(token.to_owned());
+            }
+        });
+    }
+    terms
+}
+
+/// Picks the passage matching the most distinct `terms` (then the most words; earlier passages
+/// win ties) and cuts it down around its first match.
+///
+/// A passage word matches a term when it equals it, starts with it (terms of three or more
+/// characters, so "bark" marks "barked"), or is a close misspelling of it. When no passage
+/// matches, the first one is returned without highlights.
+pub fn best_snippet<'a>(
+    terms: &[String],
+    passages: impl IntoIterator<Item = (FieldKind, &'a str)>,
+) -> Option<Snippet> {
+    let mut best: Option<(usize, Snippet)> = None;
+    for (field, passage) in passages {
+        let text = passage.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() {
+            continue;
+        }
+        let (distinct, highlights) = find_terms(&text, terms);
+        let better = best.as_ref().is_none_or(|(best_distinct, snippet)| {
+            (distinct, highlights.len()) > (*best_distinct, snippet.highlights.len())
+        });
+        if better {
+            best = Some((
+                distinct,
+                Snippet {
+                    field,
+                    text,
+                    highlights,
+                },
+            ));
+        }
+    }
+    let (_, snippet) = best?;
+    let (text, highlights) = window(&snippet.text, snippet.highlights);
+    Some(Snippet {
+        text,
+        highlights,
+        ..snippet
+    })
+}
+
+/// Returns the number of distinct terms found in `text` and the byte ranges of matching words.
+fn find_terms(text: &str, terms: &[String]) -> (usize, Vec<Range<usize>>) {
+    let mut seen = vec![false; terms.len()];
+    let mut highlights = Vec::new();
+    let mut start = None;
+    for (idx, ch) in text.char_indices().chain([(text.len(), ' ')]) {
+        if ch.is_alphanumeric() {
+            start.get_or_insert(idx);
+            continue;
+        }
+        let Some(word_start) = start.take() else {
+            continue;
+        };
+        let word = normalize_key(&text[word_start..idx]);
+        if let Some(term) = terms.iter().position(|term| word_matches(&word, term)) {
+            seen[term] = true;
+            highlights.push(word_start..idx);
+        }
+    }
+    (seen.iter().filter(|hit| **hit).count(), highlights)
+}
+
+fn word_matches(word: &str, term: &str) -> bool {
+    if word == term || (term.len() >= 3 && word.starts_with(term)) {
+        return true;
+    }
+    let (word_chars, term_chars) = (word.chars().count(), term.chars().count());
+    word_chars >= 4
+        && term_chars >= 4
+        && levenshtein::normalized_similarity(word.chars(), term.chars()) >= TYPO_SIMILARITY
+}
+
+/// Cuts `text` to about [`SNIPPET_CHARS`] characters centred on the first highlight, snapping
+/// to word boundaries, and shifts the highlights that survive.
+fn window(text: &str, highlights: Vec<Range<usize>>) -> (String, Vec<Range<usize>>) {
+    let offsets: Vec<usize> = text.char_indices().map(|(idx, _)| idx).collect();
+    if offsets.len() <= SNIPPET_CHARS {
+        return (text.to_owned(), highlights);
+    }
+    let focus = highlights.first().map_or(0, |span| {
+        offsets.partition_point(|offset| *offset < span.start)
+    });
+    let mut start = focus
+        .saturating_sub(SNIPPET_CHARS / 2)
+        .min(offsets.len() - SNIPPET_CHARS);
+    if start > 0
+        && let Some(space) = text[offsets[start]..].find(' ')
+    {
+        let after_space = offsets.partition_point(|offset| *offset <= offsets[start] + space);
+        if after_space <= focus {
+            start = after_space;
+        }
+    }
+    let byte_start = offsets[start];
+    let mut byte_end = offsets
+        .get(start + SNIPPET_CHARS)
+        .copied()
+        .unwrap_or(text.len());
+    if byte_end < text.len()
+        && let Some(space) = text[byte_start..byte_end].rfind(' ')
+        && space > 0
+    {
+        byte_end = byte_start + space;
+    }
+
+    let mut out = String::with_capacity(byte_end - byte_start + 6);
+    if byte_start > 0 {
+        out.push('…');
+    }
+    let shift = out.len();
+    out.push_str(&text[byte_start..byte_end]);
+    if byte_end < text.len() {
+        out.push('…');
+    }
+    let highlights = highlights
+        .into_iter()
+        .filter(|span| span.start >= byte_start && span.end <= byte_end)
+        .map(|span| span.start - byte_start + shift..span.end - byte_start + shift)
+        .collect();
+    (out, highlights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marked(snippet: &Snippet) -> Vec<&str> {
+        snippet
+            .highlights
+            .iter()
+            .map(|span| &snippet.text[span.clone()])
+            .collect()
+    }
+
+    #[test]
+    fn terms_are_tokenized_and_deduplicated() {
+        assert_eq!(
+            highlight_terms(["The dog", "DOG house", "naïve"]),
+            ["dog", "house", "naive"]
+        );
+    }
+
+    #[test]
+    fn words_match_exactly_by_prefix_or_with_typos() {
+        assert!(word_matches("dog", "dog"));
+        assert!(word_matches("barked", "bark"));
+        assert!(!word_matches("dogs", "do"));
+        assert!(word_matches("mailman", "mailmen"));
+        assert!(!word_matches("cat", "cot"));
+        let terms = highlight_terms(["naive bark"]);
+        let snippet = best_snippet(&terms, [(FieldKind::Examples, "A Naïve  dog barked.")]);
+        assert_eq!(marked(&snippet.unwrap()), ["Naïve", "barked"]);
+    }
+
+    #[test]
+    fn best_passage_has_the_most_distinct_terms() {
+        let terms = highlight_terms(["bark mailman"]);
+        let snippet = best_snippet(
+            &terms,
+            [
+                (FieldKind::Definitions, "bark bark bark"),
+                (FieldKind::Examples, "the dog barked at the mailman"),
+                (FieldKind::Encyclopedia, "mailman and bark"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(snippet.field, FieldKind::Examples);
+        assert_eq!(marked(&snippet), ["barked", "mailman"]);
+        assert_eq!(
+            snippet.segments(),
+            [
+                ("the dog ", false),
+                ("barked", true),
+                (" at the ", false),
+                ("mailman", true),
+            ]
+        );
+
+        let unmatched = best_snippet(&terms, [(FieldKind::Word, " "), (FieldKind::Text, "a  b")]);
+        let unmatched = unmatched.unwrap();
+        assert_eq!(
+            (unmatched.field, unmatched.text.as_str()),
+            (FieldKind::Text, "a b")
+        );
+        assert!(unmatched.highlights.is_empty());
+        assert_eq!(best_snippet(&terms, []), None);
+    }
+
+    #[test]
+    fn long_passages_are_cut_around_the_first_match() {
+        let filler = "lorem ipsum ".repeat(30);
+        let passage = format!("{filler}the gravitational constant {filler}");
+        let terms = highlight_terms(["gravitatonal"]);
+        let snippet = best_snippet(
+            &terms,
+            [
+                (FieldKind::Definitions, "unrelated"),
+                (FieldKind::Encyclopedia, passage.as_str()),
+            ],
+        )
+        .unwrap();
+        assert_eq!(snippet.field, FieldKind::Encyclopedia);
+        assert!(snippet.text.starts_with('…') && snippet.text.ends_with('…'));
+        assert!(snippet.text.chars().count() <= SNIPPET_CHARS + 2);
+        assert_eq!(marked(&snippet), ["gravitational"]);
+        let centre = snippet.text[..snippet.highlights[0].start].chars().count();
+        assert!((60..=100).contains(&centre), "match at char {centre}");
+
+        let opening = format!("gravitational {filler}");
+        let snippet = best_snippet(&terms, [(FieldKind::Text, opening.as_str())]).unwrap();
+        assert!(!snippet.text.starts_with('…') && snippet.text.ends_with('…'));
+        assert_eq!(marked(&snippet), ["gravitational"]);
+        assert_eq!(snippet.highlights[0].start, 0);
+    }
+}
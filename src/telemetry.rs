@@ -169,6 +169,7 @@ impl Telemetry {
                     max_nodes: 256,
                     max_edges: 512,
                     relations: Vec::new(),
+                    sense_level: false,
                 },
             )?;
             if traversal.nodes.len() < 2 {
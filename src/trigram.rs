@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+const TRIGRAM_MAGIC: [u8; 8] = *b"OGLTRI01";
+const HEADER_LEN: usize = 16;
+const RECORD_LEN: usize = 16;
+
+/// Packs every distinct three-character window of `text` into a sorted list of keys.
+///
+/// A key holds three Unicode scalar values (21 bits each), so keys never collide.
+pub fn trigram_keys(text: &str) -> Vec<u64> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut keys: Vec<u64> = chars
+        .windows(3)
+        .map(|window| {
+            (u64::from(window[0]) << 42) | (u64::from(window[1]) << 21) | u64::from(window[2])
+        })
+        .collect();
+    keys.sort_unstable();
+    keys.dedup();
+    keys
+}
+
+/// Serializes the trigram index for `(word, lexeme_id)` rows.
+pub fn build_trigram_index<'a, I>(rows: I) -> Vec<u8>
+where
+    I: IntoIterator<Item = (&'a str, u32)>,
+{
+    let mut pairs: Vec<(u64, u32)> = rows
+        .into_iter()
+        .flat_map(|(word, id)| trigram_keys(word).into_iter().map(move |key| (key, id)))
+        .collect();
+    pairs.sort_unstable();
+    pairs.dedup();
+
+    let mut records: Vec<(u64, u32, u32)> = Vec::new();
+    let mut postings = Vec::new();
+    let mut idx = 0;
+    while idx < pairs.len() {
+        let key = pairs[idx].0;
+        let offset = postings.len();
+        let mut previous = 0u32;
+        while idx < pairs.len() && pairs[idx].0 == key {
+            let id = pairs[idx].1;
+            write_varint(&mut postings, id - previous);
+            previous = id;
+            idx += 1;
+        }
+        records.push((key, offset as u32, (postings.len() - offset) as u32));
+    }
+
+    let mut out = Vec::with_capacity(HEADER_LEN + records.len() * RECORD_LEN + postings.len());
+    out.extend_from_slice(&TRIGRAM_MAGIC);
+    out.extend_from_slice(&(records.len() as u32).to_le_bytes());
+    out.extend_from_slice(&0u32.to_le_bytes());
+    for (key, offset, len) in records {
+        out.extend_from_slice(&key.to_le_bytes());
+        out.extend_from_slice(&offset.to_le_bytes());
+        out.extend_from_slice(&len.to_le_bytes());
+    }
+    out.extend_from_slice(&postings);
+    out
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        out.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Read-only view over a serialized trigram index, used to generate substring-search candidates.
+///
+/// Layout (little-endian throughout, so it can be read in place at any alignment):
+///
+/// ```text
+/// magic "OGLTRI01" | u32 trigram count | u32 reserved
+/// count × (u64 trigram key, u32 postings offset, u32 postings length), sorted by key
+/// postings: per trigram, ascending lexeme IDs as LEB128 deltas
+/// ```
+#[allow(dead_code)]
+pub struct TrigramIndex<B> {
+    bytes: B,
+    count: usize,
+}
+
+#[allow(dead_code)]
+impl<B: AsRef<[u8]>> TrigramIndex<B> {
+    pub fn new(bytes: B) -> Result<Self, String> {
+        let raw = bytes.as_ref();
+        if raw.len() < HEADER_LEN || raw[..8] != TRIGRAM_MAGIC {
+            return Err("missing trigram index header".to_string());
+        }
+        let count = u32::from_le_bytes(raw[8..12].try_into().unwrap()) as usize;
+        let postings_start = HEADER_LEN + count * RECORD_LEN;
+        if raw.len() < postings_start {
+            return Err("truncated trigram table".to_string());
+        }
+        Ok(Self { bytes, count })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.bytes.as_ref()
+    }
+
+    /// Returns the lexeme IDs whose words contain every trigram of `pattern`, in ascending order.
+    ///
+    /// `None` means the pattern is shorter than three characters and the index cannot help;
+    /// callers must still verify candidates, since trigram hits need not be contiguous.
+    pub fn candidates(&self, pattern: &str) -> Option<Vec<u32>> {
+        let keys = trigram_keys(pattern);
+        if keys.is_empty() {
+            return None;
+        }
+        let mut lists = Vec::with_capacity(keys.len());
+        for key in keys {
+            match self.postings(key) {
+                Some(list) => lists.push(list),
+                None => return Some(Vec::new()),
+            }
+        }
+        lists.sort_by_key(|list| list.len());
+        let mut candidates = decode_postings(lists[0]);
+        for list in &lists[1..] {
+            let other = decode_postings(list);
+            candidates.retain(|id| other.binary_search(id).is_ok());
+            if candidates.is_empty() {
+                break;
+            }
+        }
+        Some(candidates)
+    }
+
+    /// Ranks lexeme IDs by how many distinct trigrams of any of `patterns` their words share,
+    /// most overlap first (ties go to the lower ID), and keeps the top `limit`.
+    ///
+    /// Unlike [`TrigramIndex::candidates`] a word need not contain every trigram, which suits
+    /// fuzzy matching where typos break some of them.
+    pub fn overlapping(&self, patterns: &[&str], limit: usize) -> Vec<u32> {
+        let mut keys: Vec<u64> = patterns.iter().flat_map(|p| trigram_keys(p)).collect();
+        keys.sort_unstable();
+        keys.dedup();
+        let mut counts: HashMap<u32, u32> = HashMap::new();
+        for key in keys {
+            if let Some(list) = self.postings(key) {
+                for id in decode_postings(list) {
+                    *counts.entry(id).or_default() += 1;
+                }
+            }
+        }
+        let mut ranked: Vec<(u32, u32)> = counts.into_iter().collect();
+        ranked.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked.truncate(limit);
+        ranked.into_iter().map(|(id, _)| id).collect()
+    }
+
+    fn postings(&self, key: u64) -> Option<&[u8]> {
+        let raw = self.bytes.as_ref();
+        let record = |idx: usize| {
+            let start = HEADER_LEN + idx * RECORD_LEN;
+            &raw[start..start + RECORD_LEN]
+        };
+        let (mut low, mut high) = (0, self.count);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let rec = record(mid);
+            let rec_key = u64::from_le_bytes(rec[..8].try_into().unwrap());
+            match rec_key.cmp(&key) {
+                std::cmp::Ordering::Less => low = mid + 1,
+                std::cmp::Ordering::Greater => high = mid,
+                std::cmp::Ordering::Equal => {
+                    let offset = u32::from_le_bytes(rec[8..12].try_into().unwrap()) as usize;
+                    let len = u32::from_le_bytes(rec[12..16].try_into().unwrap()) as usize;
+                    let start = HEADER_LEN + self.count * RECORD_LEN + offset;
+                    return raw.get(start..start + len);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[allow(dead_code)]
+fn decode_postings(bytes: &[u8]) -> Vec<u32> {
+    let mut ids = Vec::new();
+    let mut current = 0u32;
+    let mut value = 0u32;
+    let mut shift = 0;
+    for &byte in bytes {
+        value |= u32::from(byte & 0x7f).checked_shl(shift).unwrap_or(0);
+        if byte & 0x80 == 0 {
+            current = current.wrapping_add(value);
+            ids.push(current);
+            value = 0;
+            shift = 0;
+        } else {
+            shift += 7;
+        }
+    }
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> TrigramIndex<Vec<u8>> {
+        let words = ["dog", "doghouse", "e-mail", "hotdog", "mail", "naïve", "zz"];
+        TrigramIndex::new(build_trigram_index(words.iter().copied().zip(0..))).unwrap()
+    }
+
+    #[test]
+    fn keys_cover_distinct_character_windows() {
+        assert!(trigram_keys("do").is_empty());
+        assert_eq!(trigram_keys("dog").len(), 1);
+        assert_eq!(trigram_keys("aaaa").len(), 1);
+        assert_eq!(trigram_keys("naïve").len(), 3);
+        assert!(trigram_keys("xïve").contains(&trigram_keys("ïve")[0]));
+    }
+
+    #[test]
+    fn candidates_contain_every_trigram() {
+        let index = index();
+        assert_eq!(index.candidates("og"), None);
+        assert_eq!(index.candidates("dog"), Some(vec![0, 1, 3]));
+        assert_eq!(index.candidates("-ma"), Some(vec![2]));
+        assert_eq!(index.candidates("mail"), Some(vec![2, 4]));
+        assert_eq!(index.candidates("ïve"), Some(vec![5]));
+        assert_eq!(index.candidates("cat"), Some(Vec::new()));
+    }
+
+    #[test]
+    fn overlapping_ranks_by_shared_trigrams() {
+        let index = index();
+        assert_eq!(index.overlapping(&["doghous"], 2), [1, 0]);
+        assert_eq!(index.overlapping(&["dgo"], 5), Vec::<u32>::new());
+        assert_eq!(index.overlapping(&["mail", "dog"], 10), [2, 4, 0, 1, 3]);
+    }
+
+    #[test]
+    fn postings_round_trip_large_gaps() {
+        let rows = [("abc", 3), ("abc", 200), ("abc", 70_000), ("abd", 1)];
+        let index = TrigramIndex::new(build_trigram_index(rows)).unwrap();
+        assert_eq!(index.candidates("abc"), Some(vec![3, 200, 70_000]));
+        assert_eq!(index.candidates("abd"), Some(vec![1]));
+    }
+
+    #[test]
+    fn rejects_bad_headers() {
+        assert!(TrigramIndex::new(Vec::new()).is_err());
+        assert!(TrigramIndex::new(b"NOTATRIGRAMINDEX".to_vec()).is_err());
+        let mut truncated = build_trigram_index([("dog", 0)]);
+        truncated.truncate(HEADER_LEN + 4);
+        assert!(TrigramIndex::new(truncated).is_err());
+    }
+}
@@ -3,7 +3,11 @@ use crate::telemetry::{
     SectionKind, SessionProgress, SpotlightLexeme, Telemetry, TrendingLexeme, VoteDirection,
     describe_ratio, generate_session_id,
 };
-use crate::{LexemeEntry, LexemeIndex, RelationKind, SearchConfig};
+use crate::{
+    DEFAULT_SUGGEST_EDITS, FieldKind, LexemeEntry, LexemeError, LexemeIndex, PathOptions,
+    PatternQuery, PatternSyntax, RelationKind, SearchBreakdown, SearchConfig, SearchCursor,
+    SearchFacets, SearchFilters, SearchResult, SenseId, SenseRef, Snippet,
+};
 use askama::Template;
 use axum::{
     Json, Router,
@@ -27,7 +31,7 @@ use tokio::net::TcpListener;
 use tokio::signal;
 use tower_http::compression::CompressionLayer;
 use tower_http::trace::{DefaultMakeSpan, DefaultOnResponse, TraceLayer};
-use tracing::info;
+use tracing::{error, info};
 
 type SharedState = Arc<AppState>;
 const MAX_PREFIX_LEVEL: usize = 4;
@@ -38,6 +42,14 @@ const SITEMAP_BUCKETS: [&str; 27] = [
 ];
 const TYPEAHEAD_DEFAULT_LIMIT: usize = 12;
 const TYPEAHEAD_MAX_LIMIT: usize = 50;
+const PATTERN_DEFAULT_LIMIT: usize = 50;
+const PATTERN_MAX_LIMIT: usize = 500;
+const PATH_DEFAULT_HOPS: usize = 6;
+const PATH_MAX_HOPS: usize = 10;
+/// Lexemes `/api/path` may reach from both ends before giving up.
+const PATH_MAX_VISITED: usize = 50_000;
+/// Maximum "did you mean" candidates attached to not-found lookups.
+const SUGGESTION_LIMIT: usize = 5;
 const SESSION_COOKIE: &str = "opengloss_session";
 struct SessionHandle {
     id: String,
@@ -225,6 +237,8 @@ pub struct WebConfig {
     pub theme: WebTheme,
     pub base_url: String,
     pub telemetry_path: Option<PathBuf>,
+    /// Search settings requests start from before applying their own tuning parameters.
+    pub default_search: SearchConfig,
 }
 
 impl Default for WebConfig {
@@ -235,6 +249,7 @@ impl Default for WebConfig {
             theme: WebTheme::default(),
             base_url: "http://127.0.0.1:8080".to_string(),
             telemetry_path: Some(PathBuf::from("data/telemetry/telemetry-log.jsonl")),
+            default_search: SearchConfig::default(),
         }
     }
 }
@@ -267,7 +282,7 @@ pub async fn serve(config: WebConfig) -> Result<(), WebError> {
         Telemetry::ephemeral()
     };
     let state = Arc::new(AppState {
-        default_search: SearchConfig::default(),
+        default_search: config.default_search.clone(),
         theme: config.theme,
         base_url: config.base_url.clone(),
         telemetry,
@@ -292,6 +307,8 @@ pub async fn serve(config: WebConfig) -> Result<(), WebError> {
 struct ApiError {
     status: StatusCode,
     message: String,
+    /// "Did you mean" candidates attached to not-found errors for word lookups.
+    suggestions: Vec<SuggestionPayload>,
 }
 
 impl ApiError {
@@ -299,6 +316,7 @@ impl ApiError {
         Self {
             status: StatusCode::BAD_REQUEST,
             message: message.into(),
+            suggestions: Vec::new(),
         }
     }
 
@@ -306,13 +324,49 @@ impl ApiError {
         Self {
             status: StatusCode::NOT_FOUND,
             message: message.into(),
+            suggestions: Vec::new(),
+        }
+    }
+
+    /// Attaches edit-distance suggestions for `word` to the error.
+    fn with_suggestions_for(mut self, word: &str) -> Self {
+        self.suggestions = LexemeIndex::suggest(word, DEFAULT_SUGGEST_EDITS, SUGGESTION_LIMIT)
+            .into_iter()
+            .map(|suggestion| SuggestionPayload {
+                word: suggestion.word,
+                lexeme_id: suggestion.lexeme_id,
+                distance: suggestion.distance,
+            })
+            .collect();
+        self
+    }
+}
+
+impl From<LexemeError> for ApiError {
+    fn from(err: LexemeError) -> Self {
+        if let LexemeError::InvalidPattern { .. }
+        | LexemeError::InvalidQuery { .. }
+        | LexemeError::InvalidCursor { .. }
+        | LexemeError::InvalidSense { .. } = err
+        {
+            return Self::bad_request(err.to_string());
+        }
+        error!(error = %err, "failed to read OpenGloss data");
+        Self {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: format!("Data store error: {err}"),
+            suggestions: Vec::new(),
         }
     }
 }
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
-        let payload = json!({ "error": self.message });
+        let payload = if self.suggestions.is_empty() {
+            json!({ "error": self.message })
+        } else {
+            json!({ "error": self.message, "suggestions": self.suggestions })
+        };
         (self.status, Json(payload)).into_response()
     }
 }
@@ -324,10 +378,15 @@ fn build_router(state: SharedState, _openapi: bool) -> Router {
         .route("/index", get(prefix_index_html))
         .route("/lexeme", get(lexeme_html))
         .route("/lexeme/:id", get(lexeme_html_by_id))
+        .route("/lexeme/:id/sense/:n", get(sense_permalink))
         .route("/search", get(search_html))
         .route("/api/lexeme", get(api_lexeme))
         .route("/api/search", get(api_search))
         .route("/api/typeahead", get(api_typeahead))
+        .route("/api/pattern", get(api_pattern))
+        .route("/api/reverse", get(api_reverse))
+        .route("/api/sense", get(api_sense))
+        .route("/api/path", get(api_path))
         .route("/api/feedback/rate", post(api_rate_section))
         .route("/api/feedback/report", post(api_report_issue))
         .route("/api/telemetry/relation-click", post(api_relation_click))
@@ -482,6 +541,8 @@ fn render_search_card(chrome: &Chrome, intro: &str, streak_badge: &str) -> Strin
               <select name="mode" class="form-select w-full md:w-auto px-3 py-2 rounded border border-slate-300">
                 <option value="substring" selected>Contains text</option>
                 <option value="fuzzy">Best match</option>
+                <option value="fulltext">Full text</option>
+                <option value="query">Query</option>
               </select>
               <button type="submit" class="{button_class} w-full md:w-auto">Search</button>
             </div>
@@ -977,6 +1038,27 @@ async fn lexeme_html_by_id(
     session.into_response(html)
 }
 
+/// Stable per-sense URL; lands on the sense's anchor within the lexeme page.
+async fn sense_permalink(
+    State(state): State<SharedState>,
+    Path((id, ordinal)): Path<(u32, u32)>,
+) -> Response {
+    match LexemeIndex::sense(SenseId::new(id, ordinal)) {
+        Some(sense) => Redirect::temporary(&format!(
+            "/lexeme/{}#sense-{}",
+            sense.lexeme_id(),
+            sense.ordinal()
+        ))
+        .into_response(),
+        None => Html(render_error_page(
+            state.theme,
+            format!("No sense #{ordinal} for lexeme #{id}"),
+            &[],
+        ))
+        .into_response(),
+    }
+}
+
 async fn lexeme_html_inner(
     state: SharedState,
     session_id: &str,
@@ -1041,10 +1123,14 @@ async fn lexeme_html_inner(
             Html(
                 template
                     .render()
-                    .unwrap_or_else(|err| render_error_page(state.theme, err.to_string())),
+                    .unwrap_or_else(|err| render_error_page(state.theme, err.to_string(), &[])),
             )
         }
-        Err(err) => Html(render_error_page(state.theme, err.message)),
+        Err(err) => Html(render_error_page(
+            state.theme,
+            err.message,
+            &err.suggestions,
+        )),
     }
 }
 
@@ -1054,31 +1140,48 @@ async fn search_html(
     Query(params): Query<SearchParams>,
 ) -> impl IntoResponse {
     let session = SessionHandle::from_headers(&headers);
-    match parse_search_params(&params) {
-        Ok((query, limit, mode)) => {
-            let payload = match mode {
-                SearchModeParam::Fuzzy => {
-                    SearchResponsePayload::fuzzy(&query, &state.default_search, limit)
-                }
-                SearchModeParam::Substring => SearchResponsePayload::substring(&query, limit),
-            };
+    let parsed = parse_search_params(&params).and_then(|(query, limit, mode)| {
+        let config = search_config(&state.default_search, &params)?;
+        // The page's facet sidebar needs counts even before a filter is chosen.
+        SearchResponsePayload::for_mode(
+            mode,
+            &query,
+            &config,
+            params.cursor.as_deref(),
+            params.explain.unwrap_or(false),
+            true,
+            limit,
+        )
+    });
+    match parsed {
+        Ok(payload) => {
             let chrome = Chrome::new(state.theme);
             let json_ld = search_page_json_ld(&payload, &state.base_url);
             let footer_html = shared_footer_html(&chrome);
+            let tuning = params.tuning_query();
+            let facet_chips = search_facet_chips(&payload, &tuning);
+            let next_href = payload.next_cursor.as_ref().map(|cursor| {
+                format!(
+                    "{}&cursor={cursor}",
+                    search_href(&payload, &payload.filters, &tuning)
+                )
+            });
             let template = SearchTemplate {
                 chrome,
                 payload: &payload,
+                facet_chips,
+                next_href,
                 json_ld,
                 typeahead_header: typeahead_header_html(),
                 footer_html,
             };
             let html = template
                 .render()
-                .unwrap_or_else(|err| render_error_page(state.theme, err.to_string()));
+                .unwrap_or_else(|err| render_error_page(state.theme, err.to_string(), &[]));
             session.into_response(Html(html))
         }
         Err(err) => {
-            let html = render_error_page(state.theme, err.message);
+            let html = render_error_page(state.theme, err.message, &err.suggestions);
             session.into_response(Html(html))
         }
     }
@@ -1099,7 +1202,20 @@ async fn prefix_index_html(
         .take(letters)
         .collect::<String>();
     let normalized = display_prefix.to_lowercase();
-    let mut payload = build_index_payload(LexemeIndex::all_words(), letters, &normalized);
+    let cursor = match params.cursor.as_deref().map(str::parse::<SearchCursor>) {
+        Some(Err(err)) => {
+            let html = render_error_page(state.theme, err.to_string(), &[]);
+            return session.into_response(Html(html));
+        }
+        Some(Ok(cursor)) => Some(cursor),
+        None => None,
+    };
+    let mut payload = build_index_payload(
+        LexemeIndex::all_words(),
+        letters,
+        &normalized,
+        cursor.as_ref(),
+    );
     payload.prefix = display_prefix;
     let chrome = Chrome::new(state.theme);
     let json_ld = defined_term_set_json_ld(&state.base_url);
@@ -1114,7 +1230,7 @@ async fn prefix_index_html(
     };
     let html = template
         .render()
-        .unwrap_or_else(|err| render_error_page(state.theme, err.to_string()));
+        .unwrap_or_else(|err| render_error_page(state.theme, err.to_string(), &[]));
     session.into_response(Html(html))
 }
 
@@ -1128,12 +1244,16 @@ async fn api_search(
     Query(params): Query<SearchParams>,
 ) -> Result<Json<SearchResponsePayload>, ApiError> {
     let (query, limit, mode) = parse_search_params(&params)?;
-    let payload = match mode {
-        SearchModeParam::Fuzzy => {
-            SearchResponsePayload::fuzzy(&query, &state.default_search, limit)
-        }
-        SearchModeParam::Substring => SearchResponsePayload::substring(&query, limit),
-    };
+    let config = search_config(&state.default_search, &params)?;
+    let payload = SearchResponsePayload::for_mode(
+        mode,
+        &query,
+        &config,
+        params.cursor.as_deref(),
+        params.explain.unwrap_or(false),
+        params.facets.unwrap_or(false),
+        limit,
+    )?;
     Ok(Json(payload))
 }
 
@@ -1153,7 +1273,8 @@ async fn api_typeahead(
         .clamp(1, TYPEAHEAD_MAX_LIMIT);
     let mode = params.mode.unwrap_or(TypeaheadMode::Prefix);
     let mut suggestions = match mode {
-        TypeaheadMode::Prefix => LexemeIndex::prefix(&query, limit),
+        TypeaheadMode::Prefix => LexemeIndex::prefix_normalized(&query, limit),
+        TypeaheadMode::Suffix => LexemeIndex::suffix(&query, limit),
         TypeaheadMode::Substring => LexemeIndex::search_contains(&query, limit),
     };
     if mode == TypeaheadMode::Prefix && suggestions.len() < limit && query.len() >= 3 {
@@ -1178,6 +1299,162 @@ async fn api_typeahead(
     }))
 }
 
+async fn api_pattern(
+    Query(params): Query<PatternParams>,
+) -> Result<Json<PatternResponse>, ApiError> {
+    let pattern = params
+        .q
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| ApiError::bad_request("Query parameter `q` is required"))?
+        .to_string();
+    let limit = params
+        .limit
+        .unwrap_or(PATTERN_DEFAULT_LIMIT)
+        .clamp(1, PATTERN_MAX_LIMIT);
+    let syntax = params.syntax.unwrap_or(PatternSyntaxParam::Glob);
+    let query = PatternQuery {
+        pattern,
+        syntax: match syntax {
+            PatternSyntaxParam::Glob => PatternSyntax::Glob,
+            PatternSyntaxParam::Regex => PatternSyntax::Regex,
+        },
+        min_len: params.length.or(params.min_len),
+        max_len: params.length.or(params.max_len),
+        part_of_speech: params
+            .pos
+            .map(|pos| pos.trim().to_string())
+            .filter(|pos| !pos.is_empty()),
+    };
+    let results = LexemeIndex::search_pattern(&query, limit)?
+        .into_iter()
+        .map(|(word, lexeme_id)| TypeaheadSuggestion { word, lexeme_id })
+        .collect();
+    Ok(Json(PatternResponse {
+        pattern: query.pattern,
+        syntax,
+        results,
+    }))
+}
+
+async fn api_reverse(
+    Query(params): Query<ReverseParams>,
+) -> Result<Json<ReverseResponse>, ApiError> {
+    let description = params
+        .q
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| ApiError::bad_request("Query parameter `q` is required"))?
+        .to_string();
+    let limit = params.limit.unwrap_or(10).clamp(1, 100);
+    let results = LexemeIndex::reverse_lookup(&description, limit)
+        .into_iter()
+        .map(|hit| ReverseHitPayload {
+            lexeme_id: hit.lexeme_id,
+            word: hit.word,
+            score: hit.score,
+            definition: hit.definition,
+        })
+        .collect();
+    Ok(Json(ReverseResponse {
+        query: description,
+        limit,
+        results,
+    }))
+}
+
+async fn api_sense(Query(params): Query<SenseParams>) -> Result<Json<SenseResponse>, ApiError> {
+    let id = match (params.sense.as_deref().map(str::trim), params.id, params.n) {
+        (Some(reference), _, _) if !reference.is_empty() => LexemeIndex::resolve_sense(reference)?
+            .ok_or_else(|| ApiError::not_found(format!("No sense found for {reference:?}")))?,
+        (_, Some(id), Some(ordinal)) => SenseId::new(id, ordinal),
+        _ => {
+            return Err(ApiError::bad_request(
+                "Provide `sense` (e.g. dog#n.2) or both `id` and `n`",
+            ));
+        }
+    };
+    let sense = LexemeIndex::sense(id)
+        .ok_or_else(|| ApiError::not_found(format!("No sense found for sense ID {id}")))?;
+    let word = LexemeIndex::entry_by_id(id.lexeme_id)
+        .map(|entry| entry.word().to_string())
+        .unwrap_or_default();
+    Ok(Json(SenseResponse {
+        word,
+        sense_id: id.to_string(),
+        permalink: format!("/lexeme/{}/sense/{}", id.lexeme_id, id.ordinal),
+        sense: SensePayload::from_sense(&sense),
+    }))
+}
+
+async fn api_path(Query(params): Query<PathParams>) -> Result<Json<PathResponse>, ApiError> {
+    let endpoint = |word: &Option<String>, id: Option<u32>, name: &str| {
+        if word.as_deref().is_none_or(|w| w.trim().is_empty()) && id.is_none() {
+            return Err(ApiError::bad_request(format!(
+                "Provide `{name}` (a word) or `{name}_id`"
+            )));
+        }
+        entry_from_params(&LexemeParams {
+            word: word.clone(),
+            id,
+        })
+    };
+    let from = endpoint(&params.from, params.from_id, "from")?;
+    let to = endpoint(&params.to, params.to_id, "to")?;
+    let relations = params
+        .relations
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|relation| !relation.is_empty())
+        .map(str::parse::<RelationKind>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| ApiError::bad_request(format!("Invalid `relations`: {err}")))?;
+    let max_hops = params
+        .max_hops
+        .unwrap_or(PATH_DEFAULT_HOPS)
+        .clamp(1, PATH_MAX_HOPS);
+    let options = PathOptions {
+        max_hops,
+        relations,
+        max_visited: PATH_MAX_VISITED,
+    };
+    let edges = LexemeIndex::shortest_path(from.lexeme_id(), to.lexeme_id(), &options).ok_or_else(
+        || {
+            ApiError::not_found(format!(
+                "No relation path from {:?} to {:?} within {max_hops} hops",
+                from.word(),
+                to.word()
+            ))
+        },
+    )?;
+    let word = |id: u32| {
+        LexemeIndex::entry_by_id(id)
+            .map(|entry| entry.word().to_string())
+            .unwrap_or_default()
+    };
+    let edges = edges
+        .iter()
+        .map(|edge| PathEdgePayload {
+            from: edge.from,
+            from_word: word(edge.from),
+            to: edge.to,
+            to_word: word(edge.to),
+            relation: edge.relation.to_string(),
+        })
+        .collect::<Vec<_>>();
+    Ok(Json(PathResponse {
+        from: from.lexeme_id(),
+        to: to.lexeme_id(),
+        max_hops,
+        hops: edges.len(),
+        edges,
+    }))
+}
+
 async fn api_rate_section(
     State(state): State<SharedState>,
     Json(payload): Json<RateSectionPayload>,
@@ -1303,12 +1580,103 @@ struct SearchParams {
     q: Option<String>,
     limit: Option<usize>,
     mode: Option<SearchModeParam>,
+    /// Fuzzy scorer spec, e.g. `partial-ratio` or `token-set-ratio,word:ratio`.
+    scorer: Option<String>,
+    /// `next_cursor` of the previous page (substring and fuzzy modes only).
+    cursor: Option<String>,
+    /// Comma-separated fields to search, e.g. `word,definitions`; the rest get weight 0.
+    field: Option<String>,
+    weight_word: Option<f32>,
+    weight_definitions: Option<f32>,
+    weight_synonyms: Option<f32>,
+    weight_text: Option<f32>,
+    weight_encyclopedia: Option<f32>,
+    weight_collocations: Option<f32>,
+    weight_examples: Option<f32>,
+    weight_inflections: Option<f32>,
+    weight_derivations: Option<f32>,
+    weight_antonyms: Option<f32>,
+    weight_hypernyms: Option<f32>,
+    weight_etymology: Option<f32>,
+    min_score: Option<f32>,
+    /// Include per-field score breakdowns (fuzzy mode only).
+    explain: Option<bool>,
+    /// Count facets over every substring match even without filters.
+    facets: Option<bool>,
+    /// Facet filters (substring and fuzzy modes only).
+    pos: Option<String>,
+    stopword: Option<bool>,
+    encyclopedia: Option<bool>,
+    etymology: Option<bool>,
+    min_senses: Option<usize>,
+    max_senses: Option<usize>,
+}
+
+impl SearchParams {
+    /// The `weight_*` parameters, by field.
+    fn weights(&self) -> [(FieldKind, Option<f32>); 12] {
+        [
+            (FieldKind::Word, self.weight_word),
+            (FieldKind::Definitions, self.weight_definitions),
+            (FieldKind::Synonyms, self.weight_synonyms),
+            (FieldKind::Text, self.weight_text),
+            (FieldKind::Encyclopedia, self.weight_encyclopedia),
+            (FieldKind::Collocations, self.weight_collocations),
+            (FieldKind::Examples, self.weight_examples),
+            (FieldKind::Inflections, self.weight_inflections),
+            (FieldKind::Derivations, self.weight_derivations),
+            (FieldKind::Antonyms, self.weight_antonyms),
+            (FieldKind::Hypernyms, self.weight_hypernyms),
+            (FieldKind::Etymology, self.weight_etymology),
+        ]
+    }
+
+    /// Re-encodes the tuning parameters (`scorer`, `field`, `weight_*`, `min_score`, `explain`)
+    /// as `&key=value` pairs, so links to other pages of the same search keep them.
+    fn tuning_query(&self) -> String {
+        let mut query = String::new();
+        for (key, value) in [("scorer", &self.scorer), ("field", &self.field)] {
+            if let Some(value) = value {
+                query.push_str(&format!("&{key}={}", encode_component(value)));
+            }
+        }
+        for (field, weight) in self.weights() {
+            if let Some(weight) = weight {
+                query.push_str(&format!("&weight_{field}={weight}"));
+            }
+        }
+        if let Some(min_score) = self.min_score {
+            query.push_str(&format!("&min_score={min_score}"));
+        }
+        if self.explain == Some(true) {
+            query.push_str("&explain=true");
+        }
+        query
+    }
+
+    fn filters(&self) -> SearchFilters {
+        SearchFilters {
+            part_of_speech: self
+                .pos
+                .as_deref()
+                .map(str::trim)
+                .filter(|pos| !pos.is_empty())
+                .map(str::to_string),
+            stopword: self.stopword,
+            has_encyclopedia: self.encyclopedia,
+            has_etymology: self.etymology,
+            min_senses: self.min_senses,
+            max_senses: self.max_senses,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
 struct IndexParams {
     letters: Option<usize>,
     prefix: Option<String>,
+    /// `next_cursor` of the previous page of words.
+    cursor: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1322,12 +1690,62 @@ struct TypeaheadParams {
 #[serde(rename_all = "lowercase")]
 enum TypeaheadMode {
     Prefix,
+    Suffix,
     Substring,
 }
 
+#[derive(Debug, Deserialize)]
+struct PatternParams {
+    q: Option<String>,
+    syntax: Option<PatternSyntaxParam>,
+    /// Exact length in characters; overrides `min_len`/`max_len`.
+    length: Option<usize>,
+    min_len: Option<usize>,
+    max_len: Option<usize>,
+    pos: Option<String>,
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReverseParams {
+    /// Description of the meaning to look up.
+    q: Option<String>,
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SenseParams {
+    /// Word-based reference such as `dog#2` or `dog#n.2`.
+    sense: Option<String>,
+    /// Lexeme ID, used with `n` when `sense` is absent.
+    id: Option<u32>,
+    /// 1-based sense ordinal within the lexeme.
+    n: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PathParams {
+    from: Option<String>,
+    from_id: Option<u32>,
+    to: Option<String>,
+    to_id: Option<u32>,
+    /// Comma-separated relation kinds to follow; all when absent.
+    relations: Option<String>,
+    max_hops: Option<usize>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+enum PatternSyntaxParam {
+    Glob,
+    Regex,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct SensePayload {
     lexeme_id: u32,
+    ordinal: u32,
+    key: String,
     sense_index: i32,
     part_of_speech: Option<String>,
     definition: Option<String>,
@@ -1373,6 +1791,37 @@ struct SearchHitPayload {
     lexeme_id: u32,
     word: String,
     score: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    snippet: Option<Snippet>,
+}
+
+impl SearchHitPayload {
+    fn from_result(result: SearchResult) -> Self {
+        Self {
+            lexeme_id: result.lexeme_id,
+            word: result.word,
+            score: Some(result.score),
+            snippet: result.snippet,
+        }
+    }
+
+    /// The snippet as escaped HTML with matches wrapped in `<mark>`.
+    fn snippet_html(&self) -> String {
+        let Some(snippet) = &self.snippet else {
+            return String::new();
+        };
+        snippet
+            .segments()
+            .into_iter()
+            .map(|(text, highlighted)| {
+                if highlighted {
+                    format!("<mark>{}</mark>", xml_escape(text))
+                } else {
+                    xml_escape(text)
+                }
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1380,7 +1829,26 @@ struct SearchResponsePayload {
     query: String,
     mode: SearchModeParam,
     limit: usize,
+    #[serde(default, skip_serializing_if = "SearchFilters::is_empty")]
+    filters: SearchFilters,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    facets: Option<SearchFacets>,
     results: Vec<SearchHitPayload>,
+    /// Pass back as `cursor` to fetch the next page; absent on the last page.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<String>,
+    /// Per-field score contributions for each result (`explain=true`, fuzzy mode).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    breakdowns: Vec<SearchBreakdown>,
+}
+
+/// A clickable facet on the search page; following `href` toggles the filter.
+#[derive(Debug, Clone)]
+struct FacetChip {
+    label: String,
+    count: usize,
+    href: String,
+    active: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -1412,6 +1880,8 @@ struct IndexPagePayload<'a> {
     max_display: usize,
     levels: Vec<PrefixLevelPayload>,
     words: Vec<WordLinkPayload<'a>>,
+    /// Link to the next page of words, when more match the prefix.
+    next_href: Option<String>,
 }
 
 struct SenseBlock<'a> {
@@ -1448,6 +1918,62 @@ struct TypeaheadResponse {
     suggestions: Vec<TypeaheadSuggestion>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+struct PatternResponse {
+    pattern: String,
+    syntax: PatternSyntaxParam,
+    results: Vec<TypeaheadSuggestion>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ReverseResponse {
+    query: String,
+    limit: usize,
+    results: Vec<ReverseHitPayload>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ReverseHitPayload {
+    lexeme_id: u32,
+    word: String,
+    score: f32,
+    definition: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PathResponse {
+    from: u32,
+    to: u32,
+    max_hops: usize,
+    hops: usize,
+    edges: Vec<PathEdgePayload>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PathEdgePayload {
+    from: u32,
+    from_word: String,
+    to: u32,
+    to_word: String,
+    relation: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SenseResponse {
+    word: String,
+    sense_id: String,
+    permalink: String,
+    #[serde(flatten)]
+    sense: SensePayload,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SuggestionPayload {
+    word: String,
+    lexeme_id: u32,
+    distance: u32,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct TypeaheadSuggestion {
     word: String,
@@ -1562,27 +2088,37 @@ struct PosChip<'a> {
     css_class: &'static str,
 }
 
+impl SensePayload {
+    fn from_sense(sense: &SenseRef<'_>) -> Self {
+        Self {
+            lexeme_id: sense.lexeme_id(),
+            ordinal: sense.ordinal(),
+            key: sense.key(),
+            sense_index: sense.sense_index(),
+            part_of_speech: sense.part_of_speech().map(|s| s.to_string()),
+            definition: sense.definition().map(|s| s.to_string()),
+            synonyms: collect_iter(sense.synonyms()),
+            antonyms: collect_iter(sense.antonyms()),
+            hypernyms: collect_iter(sense.hypernyms()),
+            hyponyms: collect_iter(sense.hyponyms()),
+            examples: collect_iter(sense.examples()),
+        }
+    }
+}
+
 impl LexemePayload {
     fn from_entry(entry: &LexemeEntry<'_>) -> Self {
         let mut pos_counts: BTreeMap<String, usize> = BTreeMap::new();
         let senses = entry
             .senses()
-            .map(|sense| SensePayload {
-                lexeme_id: sense.lexeme_id(),
-                sense_index: sense.sense_index(),
-                part_of_speech: sense.part_of_speech().map(|s| {
-                    let value = s.to_string();
-                    *pos_counts.entry(value.clone()).or_insert(0) += 1;
-                    value
-                }),
-                definition: sense.definition().map(|s| s.to_string()),
-                synonyms: collect_iter(sense.synonyms()),
-                antonyms: collect_iter(sense.antonyms()),
-                hypernyms: collect_iter(sense.hypernyms()),
-                hyponyms: collect_iter(sense.hyponyms()),
-                examples: collect_iter(sense.examples()),
-            })
+            .map(|sense| SensePayload::from_sense(&sense))
             .collect::<Vec<_>>();
+        for pos in senses
+            .iter()
+            .filter_map(|sense| sense.part_of_speech.as_ref())
+        {
+            *pos_counts.entry(pos.clone()).or_insert(0) += 1;
+        }
 
         let unspecified = senses
             .iter()
@@ -1624,13 +2160,80 @@ impl LexemePayload {
 }
 
 impl SearchResponsePayload {
-    fn substring(query: &str, limit: usize) -> Self {
-        let results = LexemeIndex::search_contains(query, limit)
+    fn for_mode(
+        mode: SearchModeParam,
+        query: &str,
+        config: &SearchConfig,
+        cursor: Option<&str>,
+        explain: bool,
+        facets: bool,
+        limit: usize,
+    ) -> Result<Self, ApiError> {
+        if !config.filters.is_empty()
+            && matches!(mode, SearchModeParam::FullText | SearchModeParam::Query)
+        {
+            return Err(ApiError::bad_request(
+                "Search filters are only available for substring and fuzzy search",
+            ));
+        }
+        if explain && !matches!(mode, SearchModeParam::Fuzzy) {
+            return Err(ApiError::bad_request(
+                "`explain` is only available for fuzzy search",
+            ));
+        }
+        if matches!(mode, SearchModeParam::Fuzzy | SearchModeParam::FullText)
+            && config.total_weight() <= 0.0
+        {
+            return Err(ApiError::bad_request(
+                "All search weights are zero; nothing to search",
+            ));
+        }
+        let cursor = cursor
+            .map(str::parse::<SearchCursor>)
+            .transpose()?
+            .map(|cursor| match (mode, cursor.is_ranked()) {
+                (SearchModeParam::Substring, false) | (SearchModeParam::Fuzzy, true) => Ok(cursor),
+                _ => Err(ApiError::bad_request(
+                    "`cursor` must come from a previous substring or fuzzy search in the same mode",
+                )),
+            })
+            .transpose()?;
+        Ok(match mode {
+            SearchModeParam::Fuzzy => Self::fuzzy(query, config, cursor.as_ref(), explain, limit),
+            SearchModeParam::FullText => Self::full_text(query, config, limit),
+            SearchModeParam::Substring => {
+                let facets = facets || !config.filters.is_empty();
+                Self::substring(query, &config.filters, cursor.as_ref(), facets, limit)
+            }
+            SearchModeParam::Query => Self::structured(query, limit)?,
+        })
+    }
+
+    fn substring(
+        query: &str,
+        filters: &SearchFilters,
+        cursor: Option<&SearchCursor>,
+        facets: bool,
+        limit: usize,
+    ) -> Self {
+        let (page, facets) = if facets {
+            let (page, facets) =
+                LexemeIndex::search_contains_filtered(query, filters, cursor, limit);
+            (page, Some(facets))
+        } else {
+            (
+                LexemeIndex::search_contains_page(query, cursor, limit),
+                None,
+            )
+        };
+        let results = page
+            .items
             .into_iter()
             .map(|(word, lexeme_id)| SearchHitPayload {
                 lexeme_id,
                 word,
                 score: None,
+                snippet: None,
             })
             .collect();
 
@@ -1638,26 +2241,78 @@ impl SearchResponsePayload {
             query: query.to_string(),
             mode: SearchModeParam::Substring,
             limit,
+            filters: filters.clone(),
+            facets,
             results,
+            next_cursor: page.next_cursor.map(|cursor| cursor.to_string()),
+            breakdowns: Vec::new(),
         }
     }
 
-    fn fuzzy(query: &str, config: &SearchConfig, limit: usize) -> Self {
-        let results = LexemeIndex::search_fuzzy(query, config, limit)
+    fn fuzzy(
+        query: &str,
+        config: &SearchConfig,
+        cursor: Option<&SearchCursor>,
+        explain: bool,
+        limit: usize,
+    ) -> Self {
+        let summary = LexemeIndex::search_fuzzy_page(query, config, cursor, limit);
+        let breakdowns = if explain {
+            LexemeIndex::explain_search(query, config, &summary.results)
+        } else {
+            Vec::new()
+        };
+        let results = summary
+            .results
             .into_iter()
-            .map(|row| SearchHitPayload {
-                lexeme_id: row.lexeme_id,
-                word: row.word,
-                score: Some(row.score),
-            })
+            .map(SearchHitPayload::from_result)
             .collect();
         Self {
             query: query.to_string(),
             mode: SearchModeParam::Fuzzy,
             limit,
+            filters: config.filters.clone(),
+            facets: Some(summary.facets),
+            results,
+            next_cursor: summary.next_cursor.map(|cursor| cursor.to_string()),
+            breakdowns,
+        }
+    }
+
+    fn full_text(query: &str, config: &SearchConfig, limit: usize) -> Self {
+        let results = LexemeIndex::search_text(query, config, limit)
+            .into_iter()
+            .map(SearchHitPayload::from_result)
+            .collect();
+        Self {
+            query: query.to_string(),
+            mode: SearchModeParam::FullText,
+            limit,
+            filters: SearchFilters::default(),
+            facets: None,
             results,
+            next_cursor: None,
+            breakdowns: Vec::new(),
         }
     }
+
+    /// Runs `query` through the structured query language (`mode=query`).
+    fn structured(query: &str, limit: usize) -> Result<Self, ApiError> {
+        let results = LexemeIndex::query(query, limit)?
+            .into_iter()
+            .map(SearchHitPayload::from_result)
+            .collect();
+        Ok(Self {
+            query: query.to_string(),
+            mode: SearchModeParam::Query,
+            limit,
+            filters: SearchFilters::default(),
+            facets: None,
+            results,
+            next_cursor: None,
+            breakdowns: Vec::new(),
+        })
+    }
 }
 
 fn collect_iter<'a, I>(iter: I) -> Vec<String>
@@ -1669,7 +2324,7 @@ where
 
 fn entry_from_params(params: &LexemeParams) -> Result<LexemeEntry<'static>, ApiError> {
     if let Some(id) = params.id {
-        return LexemeIndex::entry_by_id(id)
+        return LexemeIndex::try_entry_by_id(id)?
             .ok_or_else(|| ApiError::not_found(format!("No entry found for lexeme #{id}")));
     }
     if let Some(word) = params
@@ -1678,8 +2333,20 @@ fn entry_from_params(params: &LexemeParams) -> Result<LexemeEntry<'static>, ApiE
         .map(|w| w.trim())
         .filter(|w| !w.is_empty())
     {
-        return LexemeIndex::entry_by_word(word)
-            .ok_or_else(|| ApiError::not_found(format!("No entry found for word {word:?}")));
+        if let Some(entry) = LexemeIndex::try_entry_by_word(word)? {
+            return Ok(entry);
+        }
+        // Fall back to case-, accent-, and punctuation-insensitive matching ("Dog", "naive"),
+        // then to the lexeme an inflected form belongs to ("ran" -> "run").
+        if let Some(&(id, _)) = LexemeIndex::lemmatize(word).first()
+            && let Some(entry) = LexemeIndex::try_entry_by_id(id)?
+        {
+            return Ok(entry);
+        }
+        return Err(
+            ApiError::not_found(format!("No entry found for word {word:?}"))
+                .with_suggestions_for(word),
+        );
     }
     Err(ApiError::bad_request(
         "Provide either `word` or `id` query parameters.",
@@ -1700,7 +2367,178 @@ fn parse_search_params(
     Ok((query.to_string(), limit, mode))
 }
 
-fn render_error_page(theme: WebTheme, message: impl Into<String>) -> String {
+/// Applies the request's tuning (`scorer`, `field`, `weight_*`, `min_score`) and facet filters
+/// on top of the server's default search settings.
+///
+/// Like the CLI's `--field`, `field` zeroes the weight of every unlisted field; listed fields
+/// keep their default weight, or 1.0 when the default is 0. `weight_*` then overrides single
+/// fields.
+fn search_config(defaults: &SearchConfig, params: &SearchParams) -> Result<SearchConfig, ApiError> {
+    let mut config = defaults.clone();
+    config.filters = params.filters();
+    if let Some(fields) = params.field.as_deref() {
+        let selected = fields
+            .split(',')
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+            .map(str::parse::<FieldKind>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| ApiError::bad_request(format!("Invalid `field`: {err}")))?;
+        for field in FieldKind::ALL {
+            if !selected.contains(&field) {
+                config.set_weight(field, 0.0);
+            } else if config.weight(field) <= 0.0 {
+                config.set_weight(field, 1.0);
+            }
+        }
+    }
+    for (field, weight) in params.weights() {
+        if let Some(weight) = weight {
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(ApiError::bad_request(format!(
+                    "Invalid `weight_{field}`: weights must be non-negative numbers"
+                )));
+            }
+            config.set_weight(field, weight);
+        }
+    }
+    if let Some(min_score) = params.min_score {
+        if !(0.0..=1.0).contains(&min_score) {
+            return Err(ApiError::bad_request(
+                "Invalid `min_score`: expected a number between 0 and 1",
+            ));
+        }
+        config.min_score = min_score;
+    }
+    if let Some(spec) = params.scorer.as_deref() {
+        config
+            .apply_scorer_spec(spec)
+            .map_err(|err| ApiError::bad_request(format!("Invalid `scorer`: {err}")))?;
+    }
+    Ok(config)
+}
+
+/// `/search` URL for the payload's query, mode, and limit under `filters`, followed by the
+/// request's `tuning` parameters.
+fn search_href(payload: &SearchResponsePayload, filters: &SearchFilters, tuning: &str) -> String {
+    let mut href = format!(
+        "/search?q={}&mode={}&limit={}",
+        encode_component(&payload.query),
+        payload.mode.query_value(),
+        payload.limit
+    );
+    if let Some(pos) = filters.part_of_speech.as_deref() {
+        href.push_str(&format!("&pos={}", encode_component(pos)));
+    }
+    for (key, value) in [
+        ("stopword", filters.stopword),
+        ("encyclopedia", filters.has_encyclopedia),
+        ("etymology", filters.has_etymology),
+    ] {
+        if let Some(value) = value {
+            href.push_str(&format!("&{key}={value}"));
+        }
+    }
+    for (key, value) in [
+        ("min_senses", filters.min_senses),
+        ("max_senses", filters.max_senses),
+    ] {
+        if let Some(value) = value {
+            href.push_str(&format!("&{key}={value}"));
+        }
+    }
+    href.push_str(tuning);
+    href
+}
+
+/// Filter chips for the search page: one per part of speech, entry flag, and sense count in
+/// the facets, each linking to the same search with that filter toggled.
+fn search_facet_chips(payload: &SearchResponsePayload, tuning: &str) -> Vec<FacetChip> {
+    let Some(facets) = payload.facets.as_ref() else {
+        return Vec::new();
+    };
+    let current = &payload.filters;
+    let chip = |label: String, count: usize, active: bool, toggled: SearchFilters| FacetChip {
+        label,
+        count,
+        href: search_href(payload, &toggled, tuning),
+        active,
+    };
+    let mut chips = Vec::new();
+
+    let mut pos: Vec<(&String, &usize)> = facets.parts_of_speech.iter().collect();
+    pos.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    for (label, &count) in pos {
+        let active = current.part_of_speech.as_deref() == Some(label.as_str());
+        let toggled = SearchFilters {
+            part_of_speech: (!active).then(|| label.clone()),
+            ..current.clone()
+        };
+        chips.push(chip(label.clone(), count, active, toggled));
+    }
+
+    let active = current.has_encyclopedia == Some(true);
+    let toggled = SearchFilters {
+        has_encyclopedia: (!active).then_some(true),
+        ..current.clone()
+    };
+    if facets.with_encyclopedia > 0 || active {
+        chips.push(chip(
+            "encyclopedia".to_string(),
+            facets.with_encyclopedia,
+            active,
+            toggled,
+        ));
+    }
+    let active = current.has_etymology == Some(true);
+    let toggled = SearchFilters {
+        has_etymology: (!active).then_some(true),
+        ..current.clone()
+    };
+    if facets.with_etymology > 0 || active {
+        chips.push(chip(
+            "etymology".to_string(),
+            facets.with_etymology,
+            active,
+            toggled,
+        ));
+    }
+    let active = current.stopword == Some(false);
+    let toggled = SearchFilters {
+        stopword: (!active).then_some(false),
+        ..current.clone()
+    };
+    if facets.stopwords > 0 || active {
+        chips.push(chip(
+            "no stopwords".to_string(),
+            facets.total - facets.stopwords,
+            active,
+            toggled,
+        ));
+    }
+
+    for (&senses, &count) in &facets.sense_counts {
+        let active = current.min_senses == Some(senses) && current.max_senses == Some(senses);
+        let toggled = SearchFilters {
+            min_senses: (!active).then_some(senses),
+            max_senses: (!active).then_some(senses),
+            ..current.clone()
+        };
+        let label = if senses == 1 {
+            "1 sense".to_string()
+        } else {
+            format!("{senses} senses")
+        };
+        chips.push(chip(label, count, active, toggled));
+    }
+    chips
+}
+
+fn render_error_page(
+    theme: WebTheme,
+    message: impl Into<String>,
+    suggestions: &[SuggestionPayload],
+) -> String {
     let chrome = Chrome::new(theme);
     let (css_tag, js_tag) = match theme {
         WebTheme::Tailwind => (
@@ -1712,11 +2550,30 @@ fn render_error_page(theme: WebTheme, message: impl Into<String>) -> String {
             r#"<script src="https://cdn.jsdelivr.net/npm/bootstrap@5.3.8/dist/js/bootstrap.bundle.min.js" integrity="sha384-FKyoEForCGlyvwx9Hj09JcYn3nv7wiPVlz7YYwJrWVcXK/BmnVDxM+D2scQbITxI" crossorigin="anonymous"></script>"#,
         ),
     };
-    let message = message.into();
-    let footer_html = shared_footer_html(&chrome);
-    format!(
-        r#"<!DOCTYPE html>
-<html lang="en">
+    let message = xml_escape(&message.into());
+    let suggestions_html = if suggestions.is_empty() {
+        String::new()
+    } else {
+        let links: Vec<String> = suggestions
+            .iter()
+            .map(|suggestion| {
+                format!(
+                    r#"<a href="/lexeme/{id}">{word}</a>"#,
+                    id = suggestion.lexeme_id,
+                    word = xml_escape(&suggestion.word),
+                )
+            })
+            .collect();
+        format!(
+            r#"<p class="{lede_class}">Did you mean {links}?</p>"#,
+            lede_class = chrome.lede_class,
+            links = links.join(", "),
+        )
+    };
+    let footer_html = shared_footer_html(&chrome);
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
   <head>
     <meta charset="utf-8" />
     <meta name="viewport" content="width=device-width, initial-scale=1" />
@@ -1729,6 +2586,7 @@ fn render_error_page(theme: WebTheme, message: impl Into<String>) -> String {
       <div class="{card_class}">
         <h1 class="{headline_class}">Something went wrong</h1>
         <p class="{lede_class}">{message}</p>
+        {suggestions_html}
         <a href="/" class="{button_class}">Back to home</a>
       </div>
       {footer_html}
@@ -1744,6 +2602,7 @@ fn render_error_page(theme: WebTheme, message: impl Into<String>) -> String {
         lede_class = chrome.lede_class,
         button_class = chrome.button_class,
         message = message,
+        suggestions_html = suggestions_html,
         footer_html = footer_html,
     )
 }
@@ -1752,9 +2611,18 @@ fn build_index_payload<'a>(
     words: &'a [(String, u32)],
     letters: usize,
     prefix: &str,
+    cursor: Option<&SearchCursor>,
 ) -> IndexPagePayload<'a> {
     let levels = build_prefix_levels(words, letters, prefix);
-    let (word_rows, total_matches) = filter_words_by_prefix(words, prefix);
+    let (word_rows, total_matches, next_cursor) = filter_words_by_prefix(words, prefix, cursor);
+    let next_href = next_cursor.map(|cursor| {
+        format!(
+            "/index?letters={}&prefix={}&cursor={}#words",
+            letters,
+            encode_component(prefix),
+            cursor
+        )
+    });
     IndexPagePayload {
         letters,
         prefix: prefix.to_string(),
@@ -1762,6 +2630,7 @@ fn build_index_payload<'a>(
         max_display: MAX_WORDS_DISPLAY,
         levels,
         words: word_rows,
+        next_href,
     }
 }
 
@@ -1827,36 +2696,40 @@ fn take_prefix(word: &str, length: usize) -> Option<String> {
     Some(prefix.to_lowercase())
 }
 
+/// Returns the page of up to [`MAX_WORDS_DISPLAY`] words starting with `prefix` after
+/// `cursor`, the total number of matches, and the cursor for the following page.
 fn filter_words_by_prefix<'a>(
     words: &'a [(String, u32)],
     prefix: &str,
-) -> (Vec<WordLinkPayload<'a>>, usize) {
+    cursor: Option<&SearchCursor>,
+) -> (Vec<WordLinkPayload<'a>>, usize, Option<SearchCursor>) {
+    let after = cursor.and_then(SearchCursor::key);
     let mut rows = Vec::new();
     let mut total = 0;
-    if prefix.is_empty() {
-        for (word, lexeme_id) in words.iter().take(MAX_WORDS_DISPLAY) {
+    let mut more = false;
+    for (word, lexeme_id) in words {
+        if !prefix.is_empty() && !word.to_lowercase().starts_with(prefix) {
+            continue;
+        }
+        total += 1;
+        if after.is_some_and(|key| word.as_bytes() <= key) {
+            continue;
+        }
+        if rows.len() < MAX_WORDS_DISPLAY {
             rows.push(WordLinkPayload {
                 word: word.as_str(),
                 lexeme_id: *lexeme_id,
                 href: lexeme_path(word),
             });
-        }
-        total = words.len();
-        return (rows, total);
-    }
-    for (word, lexeme_id) in words {
-        if word.to_lowercase().starts_with(prefix) {
-            total += 1;
-            if rows.len() < MAX_WORDS_DISPLAY {
-                rows.push(WordLinkPayload {
-                    word: word.as_str(),
-                    lexeme_id: *lexeme_id,
-                    href: lexeme_path(word),
-                });
-            }
+        } else {
+            more = true;
         }
     }
-    (rows, total)
+    let next_cursor = rows
+        .last()
+        .filter(|_| more)
+        .map(|row| SearchCursor::after_key(row.word.as_bytes()));
+    (rows, total, next_cursor)
 }
 
 fn encode_component(value: &str) -> String {
@@ -1963,6 +2836,8 @@ fn typeahead_header_html() -> String {
           <select name="mode" class="px-3 py-2 rounded border border-slate-300">
             <option value="substring" selected>Contains text</option>
             <option value="fuzzy">Best match</option>
+            <option value="fulltext">Full text</option>
+            <option value="query">Query</option>
           </select>
           <button type="submit" class="inline-flex items-center justify-center rounded-full bg-slate-900 text-white px-4 py-2 font-semibold shadow hover:bg-slate-800 transition">🔍</button>
         </form>
@@ -2639,12 +3514,13 @@ fn pos_chip_class(label: &str) -> &'static str {
           <h2 class="text-xl font-semibold mb-3">Senses ({{ sense_count }})</h2>
           <div class="space-y-5">
             {% for sense in senses %}
-            <article class="bg-white shadow rounded p-5">
+            <article id="sense-{{ sense.payload.ordinal }}" class="bg-white shadow rounded p-5">
               <p class="text-sm text-slate-500 mb-2">
                 Sense #{{ sense.payload.sense_index }}
                 {% if sense.payload.part_of_speech.is_some() %}
                   • {{ sense.payload.part_of_speech.as_ref().unwrap() }}
                 {% endif %}
+                • <a href="/lexeme/{{ payload.lexeme_id }}/sense/{{ sense.payload.ordinal }}" title="Permalink to {{ sense.payload.key }}">{{ sense.payload.key }}</a>
               </p>
               <div class="font-semibold mb-3 prose prose-slate max-w-none rich-text" style="font-size: 1.05rem; line-height: 1.6; color: #0f172a;">
                 {% if sense.definition_html.is_some() %}
@@ -2821,8 +3697,15 @@ struct LexemeTemplate<'a> {
         <div>
           <p class="{{ chrome.eyebrow_class }}">Mode: {{ payload.mode }}</p>
           <h1 class="{{ chrome.headline_class }}">Search results for “{{ payload.query }}”</h1>
-          <p class="{{ chrome.lede_class }}">{{ payload.results.len() }} matches (limit {{ payload.limit }}).</p>
+          <p class="{{ chrome.lede_class }}">{{ payload.results.len() }} matches (limit {{ payload.limit }}){% if payload.facets.is_some() %}, {{ payload.facets.as_ref().unwrap().total }} in total{% endif %}.</p>
         </div>
+        {% if facet_chips.len() > 0 %}
+        <nav class="flex flex-wrap gap-2" aria-label="Filter results">
+          {% for chip in facet_chips %}
+          <a href="{{ chip.href }}" class="px-3 py-1 rounded-full border text-sm {% if chip.active %}bg-slate-900 text-white{% else %}bg-white text-slate-900{% endif %} shadow-sm hover:shadow">{% if chip.active %}✕ {% endif %}{{ chip.label }} <span class="text-xs {% if chip.active %}text-slate-300{% else %}text-slate-500{% endif %}">({{ chip.count }})</span></a>
+          {% endfor %}
+        </nav>
+        {% endif %}
         {% if payload.results.len() == 0 %}
           <p>No results found.</p>
         {% else %}
@@ -2840,6 +3723,9 @@ struct LexemeTemplate<'a> {
               <tr class="{{ chrome.table_row_class }}">
                 <td class="px-4 py-2">
                   <a href="/lexeme?word={{ hit.word }}" class="text-blue-700 hover:underline">{{ hit.word }}</a>
+                  {% if hit.snippet.is_some() %}
+                  <p class="text-sm text-slate-600 mt-1"><span class="text-xs uppercase text-slate-400">{{ hit.snippet.as_ref().unwrap().field }}</span> {{ hit.snippet_html()|safe }}</p>
+                  {% endif %}
                 </td>
                 <td class="px-4 py-2">
                   {% if hit.score.is_some() %}
@@ -2855,6 +3741,24 @@ struct LexemeTemplate<'a> {
           </table>
         </div>
         {% endif %}
+        {% if next_href.is_some() %}
+        <p><a href="{{ next_href.as_ref().unwrap() }}" class="text-blue-700 hover:underline">Next page →</a></p>
+        {% endif %}
+        {% if payload.breakdowns.len() > 0 %}
+        <section id="breakdowns">
+          <h2 class="text-xl font-semibold mb-2">Score breakdown</h2>
+          {% for breakdown in payload.breakdowns %}
+          <details class="bg-white shadow rounded p-3 mb-2">
+            <summary><span class="font-semibold">{{ breakdown.word }}</span> <span class="text-sm text-slate-500">{{ "{:.3}"|format(breakdown.total_score) }}</span></summary>
+            <ul class="text-sm text-slate-600 mt-2">
+              {% for field in breakdown.fields %}
+              <li>{{ field.field }} ({{ field.scorer }}): {{ "{:.3}"|format(field.score) }} × {{ field.weight }}{% if field.sample.is_some() %} — “{{ field.sample.as_ref().unwrap() }}”{% endif %}</li>
+              {% endfor %}
+            </ul>
+          </details>
+          {% endfor %}
+        </section>
+        {% endif %}
       </div>
       {{ footer_html|safe }}
     </main>
@@ -2865,6 +3769,8 @@ struct LexemeTemplate<'a> {
 struct SearchTemplate<'a> {
     chrome: Chrome,
     payload: &'a SearchResponsePayload,
+    facet_chips: Vec<FacetChip>,
+    next_href: Option<String>,
     json_ld: String,
     typeahead_header: String,
     footer_html: String,
@@ -2916,7 +3822,7 @@ struct SearchTemplate<'a> {
         <section id="words">
           <h2 class="text-xl font-semibold mb-2">Words{% if payload.prefix.len() > 0 %} matching “{{ payload.prefix }}”{% endif %}</h2>
           <p class="text-sm text-slate-500">
-            Showing {{ payload.words.len() }} of {{ payload.total_matches }} results{% if payload.total_matches > payload.max_display %} (first {{ payload.max_display }} shown){% endif %}.
+            Showing {{ payload.words.len() }} of {{ payload.total_matches }} results{% if payload.total_matches > payload.max_display %} ({{ payload.max_display }} per page){% endif %}.
           </p>
           {% if payload.words.len() == 0 %}
             <p>No words matched this prefix.</p>
@@ -2930,6 +3836,9 @@ struct SearchTemplate<'a> {
             {% endfor %}
           </div>
           {% endif %}
+          {% if payload.next_href.is_some() %}
+          <p class="mt-4"><a href="{{ payload.next_href.as_ref().unwrap() }}" class="text-blue-700 hover:underline">Next page →</a></p>
+          {% endif %}
         </section>
       </div>
       {{ footer_html|safe }}
@@ -2953,13 +3862,17 @@ enum SearchModeParam {
     #[default]
     Substring,
     Fuzzy,
+    FullText,
+    Query,
 }
 
 impl SearchModeParam {
     fn query_value(&self) -> &'static str {
         match self {
             SearchModeParam::Fuzzy => "fuzzy",
+            SearchModeParam::FullText => "fulltext",
             SearchModeParam::Substring => "substring",
+            SearchModeParam::Query => "query",
         }
     }
 }
@@ -2968,12 +3881,14 @@ impl fmt::Display for SearchModeParam {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             SearchModeParam::Fuzzy => write!(f, "Fuzzy"),
+            SearchModeParam::FullText => write!(f, "Full text"),
             SearchModeParam::Substring => write!(f, "Substring"),
+            SearchModeParam::Query => write!(f, "Query"),
         }
     }
 }
 
-#[cfg(all(test, feature = "web"))]
+#[cfg(all(test, feature = "web", feature = "embedded-data"))]
 mod tests {
     use super::*;
     use axum::{
@@ -3018,6 +3933,301 @@ mod tests {
         assert_eq!(payload.word.to_lowercase(), "dog");
     }
 
+    #[tokio::test]
+    async fn api_lexeme_falls_back_to_normalized_word() {
+        let router = test_router();
+        let response = router
+            .oneshot(
+                Request::get("/api/lexeme?word=Dog")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload: LexemePayload = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(payload.word.to_lowercase(), "dog");
+    }
+
+    #[tokio::test]
+    async fn api_lexeme_falls_back_to_lemma() {
+        let (base, form) = LexemeIndex::all_words()
+            .iter()
+            .find_map(|(_, id)| {
+                let entry = LexemeIndex::entry_by_id(*id)?;
+                let form = entry.all_inflections().find(|form| {
+                    form.chars().all(|ch| ch.is_ascii_alphabetic())
+                        && LexemeIndex::get_normalized(form).is_empty()
+                })?;
+                Some((*id, form.to_owned()))
+            })
+            .expect("some inflection is not a headword");
+        let router = test_router();
+        let response = router
+            .oneshot(
+                Request::get(format!("/api/lexeme?word={form}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload: LexemePayload = serde_json::from_slice(&bytes).unwrap();
+        let lemmas = LexemeIndex::lemmatize(&form);
+        assert!(lemmas.iter().any(|(id, _)| *id == base));
+        assert!(lemmas.iter().any(|(id, _)| *id == payload.lexeme_id));
+    }
+
+    #[tokio::test]
+    async fn api_lexeme_miss_includes_suggestions() {
+        let router = test_router();
+        let response = router
+            .oneshot(
+                Request::get("/api/lexeme?word=dgo")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        let suggestions = payload["suggestions"].as_array().expect("suggestions");
+        assert!(suggestions.iter().any(|s| s["word"] == "dog"));
+    }
+
+    #[tokio::test]
+    async fn lexeme_html_miss_links_suggestions() {
+        let router = test_router();
+        let response = router
+            .oneshot(
+                Request::get("/lexeme?word=dgo")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(html.contains("Did you mean"));
+        assert!(html.contains(">dog</a>"));
+    }
+
+    #[tokio::test]
+    async fn api_pattern_matches_glob_with_length() {
+        let router = test_router();
+        let response = router
+            .oneshot(
+                Request::get("/api/pattern?q=d?g&length=3")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload: PatternResponse = serde_json::from_slice(&bytes).unwrap();
+        assert!(payload.results.iter().any(|hit| hit.word == "dog"));
+        assert!(
+            payload
+                .results
+                .iter()
+                .all(|hit| hit.word.chars().count() == 3)
+        );
+    }
+
+    #[tokio::test]
+    async fn api_pattern_rejects_invalid_regex() {
+        let router = test_router();
+        let response = router
+            .oneshot(
+                Request::get("/api/pattern?q=(dog&syntax=regex")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn api_reverse_finds_words_from_description() {
+        let router = test_router();
+        let response = router
+            .oneshot(
+                Request::get("/api/reverse?q=domesticated%20mammal%20kept%20as%20a%20pet&limit=3")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload: ReverseResponse = serde_json::from_slice(&bytes).unwrap();
+        assert!(!payload.results.is_empty() && payload.results.len() <= 3);
+        for hit in &payload.results {
+            let mut overlaps = false;
+            crate::fulltext::tokenize(&hit.definition, |token| {
+                overlaps |= ["domesticated", "mammal", "kept", "pet"].contains(&token)
+            });
+            assert!(overlaps, "{hit:?}");
+        }
+
+        let response = test_router()
+            .oneshot(Request::get("/api/reverse?q=").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn api_sense_resolves_keys_and_permalinks() {
+        let entry = LexemeIndex::entry_by_word("dog").expect("dog entry");
+        let sense = entry.senses().last().expect("dog sense");
+        let key = sense.key();
+        let response = test_router()
+            .oneshot(
+                Request::get(format!("/api/sense?sense={}", key.replace('#', "%23")))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload: SenseResponse = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(payload.word, entry.word());
+        assert_eq!(payload.sense_id, sense.id().to_string());
+        assert_eq!(payload.sense.ordinal, sense.ordinal());
+        assert_eq!(payload.sense.key, key);
+        assert_eq!(payload.sense.definition.as_deref(), sense.definition());
+
+        let response = test_router()
+            .oneshot(
+                Request::get(payload.permalink.as_str())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::TEMPORARY_REDIRECT);
+        let location = response.headers()[header::LOCATION].to_str().unwrap();
+        assert_eq!(
+            location,
+            format!("/lexeme/{}#sense-{}", entry.lexeme_id(), sense.ordinal())
+        );
+
+        let past_end = sense.ordinal() + 1;
+        for (uri, status) in [
+            (
+                format!("/api/sense?id={}&n={past_end}", entry.lexeme_id()),
+                StatusCode::NOT_FOUND,
+            ),
+            (
+                "/api/sense?sense=dog%230".to_string(),
+                StatusCode::BAD_REQUEST,
+            ),
+        ] {
+            let response = test_router()
+                .oneshot(Request::get(uri.as_str()).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), status, "{uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn api_path_links_two_words() {
+        let dog = LexemeIndex::get("dog").expect("dog in fst");
+        let options = crate::GraphOptions {
+            max_depth: 2,
+            relations: RelationKind::all().to_vec(),
+            ..crate::GraphOptions::default()
+        };
+        let reachable = LexemeIndex::traverse_graph(dog, &options).expect("graph");
+        let farthest = reachable
+            .nodes
+            .iter()
+            .max_by_key(|node| node.depth)
+            .filter(|node| node.depth > 0)
+            .expect("dog has related lexemes");
+
+        let response = test_router()
+            .oneshot(
+                Request::get(format!("/api/path?from=dog&to_id={}", farthest.lexeme_id))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload: PathResponse = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!((payload.from, payload.to), (dog, farthest.lexeme_id));
+        assert_eq!(payload.hops, farthest.depth);
+        assert_eq!(payload.edges.len(), payload.hops);
+        assert!(payload.hops <= payload.max_hops);
+        assert_eq!(payload.edges[0].from, dog);
+        assert_eq!(payload.edges[payload.hops - 1].to, farthest.lexeme_id);
+        assert!(
+            payload
+                .edges
+                .windows(2)
+                .all(|pair| pair[0].to == pair[1].from)
+        );
+        for edge in &payload.edges {
+            let relation: RelationKind = edge.relation.parse().unwrap();
+            let entry = LexemeIndex::entry_by_id(edge.from).expect("path node");
+            assert_eq!(entry.word(), edge.from_word);
+            assert!(entry.neighbor_ids(relation).contains(&edge.to), "{edge:?}");
+        }
+
+        if farthest.depth > 1 {
+            let response = test_router()
+                .oneshot(
+                    Request::get(format!(
+                        "/api/path?from=dog&to_id={}&max_hops={}",
+                        farthest.lexeme_id,
+                        farthest.depth - 1
+                    ))
+                    .body(Body::empty())
+                    .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+
+        for uri in [
+            "/api/path?from=dog&to=dog&relations=cousin",
+            "/api/path?from=dog",
+        ] {
+            let response = test_router()
+                .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{uri}");
+        }
+    }
+
     #[tokio::test]
     async fn api_search_dog() {
         let router = test_router();
@@ -3038,6 +4248,237 @@ mod tests {
         assert!(!payload.results.is_empty());
     }
 
+    #[tokio::test]
+    async fn api_search_applies_scorer() {
+        let response = test_router()
+            .oneshot(
+                Request::get("/api/search?q=dog&mode=fuzzy&scorer=partial_ratio,word:ratio")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let response = test_router()
+            .oneshot(
+                Request::get("/api/search?q=dog&mode=fuzzy&scorer=cosine")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn api_search_full_text() {
+        let router = test_router();
+        let response = router
+            .oneshot(
+                Request::get("/api/search?q=dog&mode=fulltext&limit=5")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload: SearchResponsePayload = serde_json::from_slice(&bytes).unwrap();
+        assert!(matches!(payload.mode, SearchModeParam::FullText));
+        assert!(!payload.results.is_empty());
+        assert!(payload.results.iter().all(|hit| hit.score.is_some()));
+        assert!(payload.results.iter().any(|hit| hit.snippet.is_some()));
+
+        let response = test_router()
+            .oneshot(
+                Request::get("/search?q=dog&mode=fulltext")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(html.contains("<mark>"));
+    }
+
+    #[tokio::test]
+    async fn api_search_filters_and_facets() {
+        let response = test_router()
+            .oneshot(
+                Request::get("/api/search?q=dog&mode=fuzzy&pos=noun&limit=5")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload: SearchResponsePayload = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(payload.filters.part_of_speech.as_deref(), Some("noun"));
+        let facets = payload.facets.expect("fuzzy search returns facets");
+        assert_eq!(facets.parts_of_speech.get("noun"), Some(&facets.total));
+
+        let response = test_router()
+            .oneshot(
+                Request::get("/search?q=dog&encyclopedia=true")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(html.contains("aria-label=\"Filter results\""));
+        assert!(html.contains("/search?q=dog&amp;mode=substring&amp;limit=10&amp;pos="));
+
+        let response = test_router()
+            .oneshot(
+                Request::get("/api/search?q=dog&mode=fulltext&pos=noun")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn api_search_pages_with_cursor() {
+        let fetch = |uri: String| async move {
+            let response = test_router()
+                .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert!(response.status().is_success());
+            let bytes = body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<SearchResponsePayload>(&bytes).unwrap()
+        };
+        let first = fetch("/api/search?q=o&mode=substring&limit=2".to_string()).await;
+        assert!(first.facets.is_none());
+        let cursor = first.next_cursor.expect("more substring matches");
+        let second = fetch(format!(
+            "/api/search?q=o&mode=substring&limit=2&cursor={cursor}"
+        ))
+        .await;
+        assert!(second.results[0].word > first.results[1].word);
+        let counted = fetch("/api/search?q=o&mode=substring&limit=2&facets=true".to_string()).await;
+        let facets = counted.facets.expect("facets=true counts facets");
+        assert!(facets.total > counted.results.len());
+        assert_eq!(counted.results[1].word, first.results[1].word);
+
+        let first = fetch("/api/search?q=dog&mode=fuzzy&limit=1".to_string()).await;
+        let cursor = first.next_cursor.expect("more fuzzy matches");
+        let second = fetch(format!(
+            "/api/search?q=dog&mode=fuzzy&limit=1&cursor={cursor}"
+        ))
+        .await;
+        assert_ne!(second.results[0].lexeme_id, first.results[0].lexeme_id);
+
+        let response = test_router()
+            .oneshot(
+                Request::get(format!("/api/search?q=dog&mode=substring&cursor={cursor}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn api_search_accepts_tuning_and_explain() {
+        let response = test_router()
+            .oneshot(
+                Request::get(
+                    "/api/search?q=dog&mode=fuzzy&field=word&weight_word=2&min_score=0&explain=true&limit=3",
+                )
+                .body(Body::empty())
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload: SearchResponsePayload = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(payload.results.len(), 3);
+        assert_eq!(payload.breakdowns.len(), 3);
+        for breakdown in &payload.breakdowns {
+            assert_eq!(breakdown.fields.len(), 1);
+            assert_eq!(breakdown.fields[0].field, FieldKind::Word);
+            assert_eq!(breakdown.fields[0].weight, 2.0);
+        }
+
+        for uri in [
+            "/api/search?q=dog&mode=fuzzy&field=pronunciation",
+            "/api/search?q=dog&mode=fuzzy&field=word&weight_word=0",
+            "/api/search?q=dog&mode=substring&explain=true",
+            "/api/search?q=dog&mode=fuzzy&min_score=NaN",
+            "/api/search?q=dog&mode=fuzzy&min_score=inf",
+            "/api/search?q=dog&mode=fuzzy&min_score=1.5",
+        ] {
+            let response = test_router()
+                .oneshot(Request::get(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{uri}");
+        }
+
+        let profile: SearchConfig =
+            serde_json::from_str(r#"{"weight_word": 0.5, "scorer_text": "partial-ratio"}"#)
+                .unwrap();
+        assert_eq!(profile.weight_word, 0.5);
+        assert_eq!(profile.scorer_text, crate::FuzzyScorer::PartialRatio);
+        assert_eq!(
+            profile.weight_definitions,
+            SearchConfig::default().weight_definitions
+        );
+    }
+
+    #[tokio::test]
+    async fn api_search_structured_query() {
+        let response = test_router()
+            .oneshot(
+                Request::get(
+                    "/api/search?q=pos%3Anoun%20has%3Aencyclopedia%20-word%3Aanimal&mode=query",
+                )
+                .body(Body::empty())
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload: SearchResponsePayload = serde_json::from_slice(&bytes).unwrap();
+        assert!(matches!(payload.mode, SearchModeParam::Query));
+        assert!(!payload.results.is_empty());
+        assert!(payload.results.iter().all(|hit| hit.word != "animal"));
+
+        let response = test_router()
+            .oneshot(
+                Request::get("/api/search?q=def%3A%28&mode=query")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
     #[tokio::test]
     async fn api_typeahead_prefix() {
         let router = test_router();
@@ -3058,6 +4499,27 @@ mod tests {
         assert!(!payload.suggestions.is_empty());
     }
 
+    #[tokio::test]
+    async fn api_typeahead_suffix() {
+        let router = test_router();
+        let response = router
+            .oneshot(
+                Request::get("/api/typeahead?q=og&mode=suffix&limit=5")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert!(response.status().is_success());
+        let bytes = body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let payload: TypeaheadResponse = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(payload.mode, TypeaheadMode::Suffix);
+        assert!(payload.suggestions.iter().any(|s| s.word == "dog"));
+        assert!(payload.suggestions.iter().all(|s| s.word.ends_with("og")));
+    }
+
     #[tokio::test]
     async fn api_typeahead_prefix_falls_back_to_substring() {
         let router = test_router();